@@ -0,0 +1,78 @@
+#![feature(test, futures_api, pin, arbitrary_self_types)]
+
+use futures::channel::oneshot;
+use futures::executor::{block_on, LocalPool};
+use futures::future::FutureExt;
+use futures::task::{self, Poll, Wake, LocalWaker};
+use std::sync::Arc;
+use std::thread;
+use test::Bencher;
+
+fn notify_noop() -> LocalWaker {
+    struct Noop;
+
+    impl Wake for Noop {
+        fn wake(_: &Arc<Self>) {}
+    }
+
+    task::local_waker_from_nonlocal(Arc::new(Noop))
+}
+
+fn noop_cx(f: impl FnOnce(&mut task::Context)) {
+    let pool = LocalPool::new();
+    let mut spawn = pool.spawner();
+    let waker = notify_noop();
+    let cx = &mut task::Context::new(&waker, &mut spawn);
+    f(cx)
+}
+
+/// Send before the receiver is polled
+#[bench]
+fn send_then_recv(b: &mut Bencher) {
+    noop_cx(|cx| {
+        b.iter(|| {
+            // 1000 iterations to avoid measuring overhead of initialization
+            // Result should be divided by 1000
+            for i in 0..1000 {
+                let (tx, mut rx) = oneshot::channel();
+                tx.send(i).unwrap();
+                assert_eq!(Poll::Ready(Ok(i)), rx.poll_unpin(cx));
+            }
+        })
+    })
+}
+
+/// Poll the receiver, which registers its task, before sending
+#[bench]
+fn poll_send_recv(b: &mut Bencher) {
+    noop_cx(|cx| {
+        b.iter(|| {
+            for i in 0..1000 {
+                let (tx, mut rx) = oneshot::channel();
+                assert_eq!(Poll::Pending, rx.poll_unpin(cx));
+                tx.send(i).unwrap();
+                assert_eq!(Poll::Ready(Ok(i)), rx.poll_unpin(cx));
+            }
+        })
+    })
+}
+
+/// Send from another thread while the receiver is blocked on
+#[bench]
+fn send_across_threads(b: &mut Bencher) {
+    b.iter(|| {
+        let (txs, rxs): (Vec<_>, Vec<_>) = (0..100)
+            .map(|_| oneshot::channel::<usize>())
+            .unzip();
+
+        let sender = thread::spawn(move || {
+            for (i, tx) in txs.into_iter().enumerate() {
+                tx.send(i).unwrap();
+            }
+        });
+        for (i, rx) in rxs.into_iter().enumerate() {
+            assert_eq!(block_on(rx), Ok(i));
+        }
+        sender.join().unwrap();
+    })
+}
@@ -4,6 +4,7 @@
 //! asynchronous tasks.
 
 #![feature(pin, arbitrary_self_types, futures_api)]
+#![cfg_attr(feature = "alloc", feature(alloc))]
 
 #![no_std]
 
@@ -19,8 +20,20 @@ macro_rules! if_std {
     )*)
 }
 
+macro_rules! if_alloc {
+    ($($i:item)*) => ($(
+        #[cfg(feature = "alloc")]
+        $i
+    )*)
+}
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+if_alloc! {
+    pub mod oneshot;
+}
+
 if_std! {
-    mod lock;
     pub mod mpsc;
-    pub mod oneshot;
 }
@@ -78,7 +78,8 @@
 // happens-before semantics required for the acquire / release semantics used
 // by the queue structure.
 
-use futures_core::stream::Stream;
+use futures_core::never::Never;
+use futures_core::stream::{FusedStream, Stream};
 use futures_core::task::{self, Waker, Poll};
 use std::any::Any;
 use std::error::Error;
@@ -111,6 +112,11 @@ pub struct Sender<T> {
     // True if the sender might be blocked. This is an optimization to avoid
     // having to lock the mutex most of the time.
     maybe_parked: bool,
+
+    // True if the sender was unparked by the receiver but hasn't sent a
+    // message since. Dropping the sender in this state passes the wakeup on
+    // to the next parked sender.
+    is_unparked: bool,
 }
 
 // We never project PinMut<Sender> to `PinMut<T>`
@@ -131,6 +137,8 @@ impl AssertKinds for UnboundedSender<u32> {}
 #[derive(Debug)]
 pub struct Receiver<T> {
     inner: Arc<Inner<T>>,
+    // Set once the final `None` has been returned
+    is_terminated: bool,
 }
 
 /// The receiving end of an unbounded mpsc channel.
@@ -186,6 +194,12 @@ impl Error for SendError {
     }
 }
 
+impl From<Never> for SendError {
+    fn from(never: Never) -> SendError {
+        never.into_any()
+    }
+}
+
 impl SendError {
     /// Returns true if this error is a result of the channel being full.
     pub fn is_full(&self) -> bool {
@@ -288,6 +302,14 @@ struct Inner<T> {
     // Atomic, FIFO queue used to send parked task handles to the receiver.
     parked_queue: Queue<Arc<Mutex<SenderTask>>>,
 
+    // Number of task handles in `parked_queue`. While there are any, senders
+    // park behind them instead of taking freed up buffer slots first.
+    num_parked: AtomicUsize,
+
+    // Number of wakeups given to senders that were dropped before sending.
+    // The receiver passes each of them on to the next parked sender.
+    num_forwarded: AtomicUsize,
+
     // Number of senders in existence
     num_senders: AtomicUsize,
 
@@ -337,6 +359,9 @@ const MAX_BUFFER: usize = MAX_CAPACITY >> 1;
 struct SenderTask {
     task: Option<Waker>,
     is_parked: bool,
+    // Set when the sender is dropped while still in the parked queue, so that
+    // the receiver skips it and wakes up the next sender instead.
+    is_dropped: bool,
 }
 
 impl SenderTask {
@@ -344,6 +369,7 @@ impl SenderTask {
         SenderTask {
             task: None,
             is_parked: false,
+            is_dropped: false,
         }
     }
 
@@ -364,6 +390,10 @@ impl SenderTask {
 /// guaranteed slot in the channel capacity, and on top of that there are
 /// `buffer` "first come, first serve" slots available to all senders.
 ///
+/// When the channel is full, senders are blocked and woken up one at a time,
+/// in the order in which they blocked, as messages are received. A sender
+/// that is ready to send again queues up behind the senders already waiting.
+///
 /// The [`Receiver`](Receiver) returned implements the
 /// [`Stream`](futures_core::stream::Stream) trait, while [`Sender`](Sender) implements
 /// `Sink`.
@@ -395,6 +425,8 @@ fn channel2<T>(buffer: Option<usize>) -> (Sender<T>, Receiver<T>) {
         state: AtomicUsize::new(INIT_STATE),
         message_queue: Queue::new(),
         parked_queue: Queue::new(),
+        num_parked: AtomicUsize::new(0),
+        num_forwarded: AtomicUsize::new(0),
         num_senders: AtomicUsize::new(1),
         recv_task: Mutex::new(ReceiverTask {
             unparked: false,
@@ -406,10 +438,12 @@ fn channel2<T>(buffer: Option<usize>) -> (Sender<T>, Receiver<T>) {
         inner: inner.clone(),
         sender_task: Arc::new(Mutex::new(SenderTask::new())),
         maybe_parked: false,
+        is_unparked: false,
     };
 
     let rx = Receiver {
         inner,
+        is_terminated: false,
     };
 
     (tx, rx)
@@ -475,14 +509,20 @@ impl<T> Sender<T> {
             }),
         };
 
+        // The wakeup this sender may have received has now been used.
+        self.is_unparked = false;
+
         // If the channel has reached capacity, then the sender task needs to
         // be parked. This will send the task handle on the parked task queue.
+        // The sender also parks if other senders are already waiting for
+        // capacity, so that it doesn't get ahead of them: parked senders are
+        // woken up one at a time in the order they parked.
         //
         // However, when `do_send` is called while dropping the `Sender`,
         // `task::current()` can't be called safely. In this case, in order to
         // maintain internal consistency, a blank message is pushed onto the
         // parked task queue.
-        if park_self {
+        if park_self || self.inner.num_parked.load(SeqCst) > 0 {
             self.park(cx);
         }
 
@@ -629,6 +669,7 @@ impl<T> Sender<T> {
 
         // Send handle over queue
         let t = self.sender_task.clone();
+        self.inner.num_parked.fetch_add(1, SeqCst);
         self.inner.parked_queue.push(t);
 
         // Check to make sure we weren't closed after we sent our task on the
@@ -686,6 +727,7 @@ impl<T> Sender<T> {
 
             if !task.is_parked {
                 self.maybe_parked = false;
+                self.is_unparked = true;
                 return Poll::Ready(())
             }
 
@@ -778,6 +820,7 @@ impl<T> Clone for Sender<T> {
                     inner: self.inner.clone(),
                     sender_task: Arc::new(Mutex::new(SenderTask::new())),
                     maybe_parked: false,
+                    is_unparked: false,
                 };
             }
 
@@ -788,6 +831,26 @@ impl<T> Clone for Sender<T> {
 
 impl<T> Drop for Sender<T> {
     fn drop(&mut self) {
+        // If this sender is still waiting in the parked queue, mark it as
+        // dropped so that the receiver wakes up the next sender in its place.
+        // If it has already been woken up, but didn't send a message, the
+        // wakeup is handed back to the receiver to pass on.
+        let mut forward = self.is_unparked;
+        if self.maybe_parked {
+            let mut task = self.sender_task.lock().unwrap();
+            if task.is_parked {
+                task.is_dropped = true;
+                task.task = None;
+            } else {
+                forward = true;
+            }
+        }
+
+        if forward {
+            self.inner.num_forwarded.fetch_add(1, SeqCst);
+            self.signal();
+        }
+
         // Ordering between variables don't matter here
         let prev = self.inner.num_senders.fetch_sub(1, SeqCst);
 
@@ -835,6 +898,7 @@ impl<T> Receiver<T> {
         loop {
             match unsafe { self.inner.parked_queue.pop() } {
                 PopResult::Data(task) => {
+                    self.inner.num_parked.fetch_sub(1, SeqCst);
                     task.lock().unwrap().notify();
                 }
                 PopResult::Empty => break,
@@ -851,6 +915,9 @@ impl<T> Receiver<T> {
     pub fn try_next(&mut self) -> Result<Option<T>, TryRecvError> {
         match self.next_message() {
             Poll::Ready(msg) => {
+                if msg.is_none() {
+                    self.is_terminated = true;
+                }
                 Ok(msg)
             },
             Poll::Pending => Err(TryRecvError { _inner: () }),
@@ -858,6 +925,12 @@ impl<T> Receiver<T> {
     }
 
     fn next_message(&mut self) -> Poll<Option<T>> {
+        // Pass on wakeups of senders that were dropped without using them
+        let forwarded = self.inner.num_forwarded.swap(0, SeqCst);
+        for _ in 0..forwarded {
+            self.unpark_one();
+        }
+
         // Pop off a message
         loop {
             match unsafe { self.inner.message_queue.pop() } {
@@ -893,12 +966,18 @@ impl<T> Receiver<T> {
         }
     }
 
-    // Unpark a single task handle if there is one pending in the parked queue
+    // Unpark the longest waiting task handle if there is one pending in the
+    // parked queue. Handles of senders that have been dropped are skipped.
     fn unpark_one(&mut self) {
         loop {
             match unsafe { self.inner.parked_queue.pop() } {
                 PopResult::Data(task) => {
-                    task.lock().unwrap().notify();
+                    self.inner.num_parked.fetch_sub(1, SeqCst);
+                    let mut task = task.lock().unwrap();
+                    if task.is_dropped {
+                        continue;
+                    }
+                    task.notify();
                     return;
                 }
                 PopResult::Empty => {
@@ -980,6 +1059,7 @@ impl<T> Stream for Receiver<T> {
                         TryPark::Closed => {
                             // The channel is closed, there will be no further
                             // messages.
+                            self.is_terminated = true;
                             return Poll::Ready(None);
                         }
                         TryPark::NotEmpty => {
@@ -992,11 +1072,20 @@ impl<T> Stream for Receiver<T> {
                 }
             };
             // Return the message
+            if msg.is_none() {
+                self.is_terminated = true;
+            }
             return Poll::Ready(msg);
         }
     }
 }
 
+impl<T> FusedStream for Receiver<T> {
+    fn is_terminated(&self) -> bool {
+        self.is_terminated
+    }
+}
+
 impl<T> Drop for Receiver<T> {
     fn drop(&mut self) {
         // Drain the channel of all pending messages
@@ -1026,6 +1115,12 @@ impl<T> UnboundedReceiver<T> {
     }
 }
 
+impl<T> FusedStream for UnboundedReceiver<T> {
+    fn is_terminated(&self) -> bool {
+        self.0.is_terminated()
+    }
+}
+
 impl<T> Stream for UnboundedReceiver<T> {
     type Item = T;
 
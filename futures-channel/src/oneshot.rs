@@ -1,16 +1,15 @@
 //! A channel for sending a single message between asynchronous tasks.
 
-use futures_core::future::Future;
+use futures_core::future::{Future, FusedFuture};
+use futures_core::never::Never;
 use futures_core::task::{self, Poll, Waker};
-use std::marker::Unpin;
-use std::mem::PinMut;
-use std::sync::Arc;
-use std::sync::atomic::AtomicBool;
-use std::sync::atomic::Ordering::SeqCst;
-use std::error::Error;
-use std::fmt;
-
-use crate::lock::Lock;
+use alloc::sync::Arc;
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::marker::Unpin;
+use core::mem::PinMut;
+use core::sync::atomic::AtomicUsize;
+use core::sync::atomic::Ordering::{AcqRel, Acquire, SeqCst};
 
 /// A future for a value that will be provided by another asynchronous task.
 ///
@@ -19,6 +18,8 @@ use crate::lock::Lock;
 #[derive(Debug)]
 pub struct Receiver<T> {
     inner: Arc<Inner<T>>,
+    // Set once the value or the cancellation has been returned
+    is_terminated: bool,
 }
 
 /// A means of transmitting a single value to another task.
@@ -35,41 +36,54 @@ impl<T> Unpin for Sender<T> {}
 
 /// Internal state of the `Receiver`/`Sender` pair above. This is all used as
 /// the internal synchronization between the two for send/recv operations.
-#[derive(Debug)]
+///
+/// All coordination happens through `state`, which is a set of the flags
+/// defined below. The flags determine which half may access each of the
+/// `UnsafeCell`s, so that no locks are needed:
+///
+/// - `data` is written by the `Sender` before it sets `COMPLETE`, and only
+///   read by the `Receiver` once it has observed `COMPLETE`. `COMPLETE` can't
+///   be set after `CLOSED`, in which case the `Sender` takes its value back.
+/// - `rx_task` is only written by the `Receiver` while `RX_TASK_SET` is
+///   unset, and only read by the `Sender` if setting `COMPLETE` observed
+///   `RX_TASK_SET`. The `tx_task` slot works the same way, with
+///   `TX_TASK_SET`, `CLOSED` and the halves swapped.
+///
+/// Both `COMPLETE` and `CLOSED` are never unset, so once a half observes the
+/// other half's final flag, it doesn't touch its task slot anymore.
 struct Inner<T> {
-    /// Indicates whether this oneshot is complete yet. This is filled in both
-    /// by `Sender::drop` and by `Receiver::drop`, and both sides interpret it
-    /// appropriately.
-    ///
-    /// For `Receiver`, if this is `true`, then it's guaranteed that `data` is
-    /// unlocked and ready to be inspected.
-    ///
-    /// For `Sender` if this is `true` then the oneshot has gone away and it
-    /// can return ready from `poll_cancel`.
-    complete: AtomicBool,
+    /// The flags below, describing the state of the channel.
+    state: AtomicUsize,
 
     /// The actual data being transferred as part of this `Receiver`. This is
-    /// filled in by `Sender::complete` and read by `Receiver::poll`.
-    ///
-    /// Note that this is protected by `Lock`, but it is in theory safe to
-    /// replace with an `UnsafeCell` as it's actually protected by `complete`
-    /// above. I wouldn't recommend doing this, however, unless someone is
-    /// supremely confident in the various atomic orderings here and there.
-    data: Lock<Option<T>>,
+    /// filled in by `Sender::send` and read by `Receiver::poll`.
+    data: UnsafeCell<Option<T>>,
 
     /// Field to store the task which is blocked in `Receiver::poll`.
-    ///
-    /// This is filled in when a oneshot is polled but not ready yet. Note that
-    /// the `Lock` here, unlike in `data` above, is important to resolve races.
-    /// Both the `Receiver` and the `Sender` halves understand that if they
-    /// can't acquire the lock then some important interference is happening.
-    rx_task: Lock<Option<Waker>>,
+    rx_task: UnsafeCell<Option<Waker>>,
 
     /// Like `rx_task` above, except for the task blocked in
-    /// `Sender::poll_cancel`. Additionally, `Lock` cannot be `UnsafeCell`.
-    tx_task: Lock<Option<Waker>>,
+    /// `Sender::poll_cancel`.
+    tx_task: UnsafeCell<Option<Waker>>,
 }
 
+// The `Receiver` has stored a task in `rx_task`.
+const RX_TASK_SET: usize = 0b0001;
+
+// The `Sender` is done, either because it stored a value in `data` or because
+// it was dropped.
+const COMPLETE: usize = 0b0010;
+
+// The `Receiver` was closed or dropped.
+const CLOSED: usize = 0b0100;
+
+// The `Sender` has stored a task in `tx_task`.
+const TX_TASK_SET: usize = 0b1000;
+
+// The `UnsafeCell`s are only accessed as described on `Inner`.
+unsafe impl<T: Send> Send for Inner<T> {}
+unsafe impl<T: Send> Sync for Inner<T> {}
+
 /// Creates a new one-shot channel for sending values across asynchronous tasks.
 ///
 /// This function is similar to Rust's channel constructor found in the standard
@@ -105,6 +119,7 @@ pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
     let inner = Arc::new(Inner::new());
     let receiver = Receiver {
         inner: inner.clone(),
+        is_terminated: false,
     };
     let sender = Sender {
         inner,
@@ -115,74 +130,86 @@ pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
 impl<T> Inner<T> {
     fn new() -> Inner<T> {
         Inner {
-            complete: AtomicBool::new(false),
-            data: Lock::new(None),
-            rx_task: Lock::new(None),
-            tx_task: Lock::new(None),
+            state: AtomicUsize::new(0),
+            data: UnsafeCell::new(None),
+            rx_task: UnsafeCell::new(None),
+            tx_task: UnsafeCell::new(None),
         }
     }
 
     fn send(&self, t: T) -> Result<(), T> {
-        if self.complete.load(SeqCst) {
+        // Fast path: the receiver is gone already, so don't bother storing
+        // the value.
+        if self.state.load(Acquire) & CLOSED != 0 {
             return Err(t)
         }
 
-        // Note that this lock acquisition may fail if the receiver
-        // is closed and sets the `complete` flag to true, whereupon
-        // the receiver may call `poll()`.
-        if let Some(mut slot) = self.data.try_lock() {
-            assert!(slot.is_none());
-            *slot = Some(t);
-            drop(slot);
-
-            // If the receiver called `close()` between the check at the
-            // start of the function, and the lock being released, then
-            // the receiver may not be around to receive it, so try to
-            // pull it back out.
-            if self.complete.load(SeqCst) {
-                // If lock acquisition fails, then receiver is actually
-                // receiving it, so we're good.
-                if let Some(mut slot) = self.data.try_lock() {
-                    if let Some(t) = slot.take() {
-                        return Err(t);
-                    }
-                }
-            }
+        // The receiver doesn't read `data` before `COMPLETE` is set.
+        unsafe { *self.data.get() = Some(t) };
+
+        if self.complete() {
             Ok(())
         } else {
-            // Must have been closed
-            Err(t)
+            // The receiver was closed in the meantime, so it'll never read
+            // the value and we can take it back.
+            Err(unsafe { (*self.data.get()).take().unwrap() })
         }
     }
 
+    /// Sets `COMPLETE` and wakes up the receiver, unless the receiver was
+    /// closed first, in which case `false` is returned.
+    fn complete(&self) -> bool {
+        let mut state = self.state.load(Acquire);
+        loop {
+            if state & COMPLETE != 0 {
+                return true
+            }
+            if state & CLOSED != 0 {
+                return false
+            }
+            match self.state.compare_exchange_weak(
+                state, state | COMPLETE, AcqRel, Acquire,
+            ) {
+                Ok(_) => break,
+                Err(actual) => state = actual,
+            }
+        }
+
+        // The receiver stored its task before we set `COMPLETE`, and won't
+        // touch it anymore now.
+        if state & RX_TASK_SET != 0 {
+            if let Some(task) = unsafe { &*self.rx_task.get() } {
+                task.wake();
+            }
+        }
+        true
+    }
+
     fn poll_cancel(&self, cx: &mut task::Context) -> Poll<()> {
         // Fast path up first, just read the flag and see if our other half is
-        // gone. This flag is set both in our destructor and the oneshot
-        // destructor, but our destructor hasn't run yet so if it's set then the
-        // oneshot is gone.
-        if self.complete.load(SeqCst) {
+        // gone.
+        let state = self.state.load(Acquire);
+        if state & CLOSED != 0 {
             return Poll::Ready(())
         }
 
-        // If our other half is not gone then we need to park our current task
-        // and move it into the `notify_cancel` slot to get notified when it's
-        // actually gone.
-        //
-        // If `try_lock` fails, then the `Receiver` is in the process of using
-        // it, so we can deduce that it's now in the process of going away and
-        // hence we're canceled. If it succeeds then we just store our handle.
-        //
-        // Crucially we then check `oneshot_gone` *again* before we return.
-        // While we were storing our handle inside `notify_cancel` the
-        // `Receiver` may have been dropped. The first thing it does is set the
-        // flag, and if it fails to acquire the lock it assumes that we'll see
-        // the flag later on. So... we then try to see the flag later on!
-        let handle = cx.waker().clone();
-        match self.tx_task.try_lock() {
-            Some(mut p) => *p = Some(handle),
-            None => return Poll::Ready(()),
+        // If a task is stored already, take back ownership of the slot before
+        // replacing it. If the receiver was closed in the meantime it may be
+        // waking that task right now, so leave it alone; we won't store
+        // another task once we've seen `CLOSED`.
+        if state & TX_TASK_SET != 0 {
+            let state = self.state.fetch_and(!TX_TASK_SET, AcqRel);
+            if state & CLOSED != 0 {
+                return Poll::Ready(())
+            }
         }
-        if self.complete.load(SeqCst) {
+
+        unsafe { *self.tx_task.get() = Some(cx.waker().clone()) };
+
+        // Crucially we check `CLOSED` again as part of publishing the task. If
+        // the receiver was closed before, it didn't see our task and won't wake
+        // it.
+        if self.state.fetch_or(TX_TASK_SET, AcqRel) & CLOSED != 0 {
             Poll::Ready(())
         } else {
             Poll::Pending
@@ -190,60 +217,43 @@ impl<T> Inner<T> {
     }
 
     fn is_canceled(&self) -> bool {
-        self.complete.load(SeqCst)
+        self.state.load(SeqCst) & CLOSED != 0
     }
 
     fn drop_tx(&self) {
         // Flag that we're a completed `Sender` and try to wake up a receiver.
         // Whether or not we actually stored any data will get picked up and
-        // translated to either an item or cancellation.
-        //
-        // Note that if we fail to acquire the `rx_task` lock then that means
-        // we're in one of two situations:
-        //
-        // 1. The receiver is trying to block in `poll`
-        // 2. The receiver is being dropped
-        //
-        // In the first case it'll check the `complete` flag after it's done
-        // blocking to see if it succeeded. In the latter case we don't need to
-        // wake up anyone anyway. So in both cases it's ok to ignore the `None`
-        // case of `try_lock` and bail out.
-        //
-        // The first case crucially depends on `Lock` using `SeqCst` ordering
-        // under the hood. If it instead used `Release` / `Acquire` ordering,
-        // then it would not necessarily synchronize with `inner.complete`
-        // and deadlock might be possible, as was observed in
-        // https://github.com/rust-lang-nursery/futures-rs/pull/219.
-        self.complete.store(true, SeqCst);
-        if let Some(mut slot) = self.rx_task.try_lock() {
-            if let Some(task) = slot.take() {
-                drop(slot);
-                task.wake();
-            }
-        }
+        // translated to either an item or cancellation. If `send` was called,
+        // `COMPLETE` is set already and this does nothing.
+        self.complete();
     }
 
-    fn close_rx(&self) {
-        // Flag our completion and then attempt to wake up the sender if it's
-        // blocked. See comments in `drop` below for more info
-        self.complete.store(true, SeqCst);
-        if let Some(mut handle) = self.tx_task.try_lock() {
-            if let Some(task) = handle.take() {
-                drop(handle);
-                task.wake()
+    /// Sets `CLOSED`, returning the previous state, and wakes up the sender
+    /// if it's blocked in `poll_cancel`.
+    fn close_rx(&self) -> usize {
+        let state = self.state.fetch_or(CLOSED, AcqRel);
+
+        // The sender won't touch its task once it has seen `CLOSED`, and
+        // doesn't need a wakeup if it's done already.
+        if state & (CLOSED | COMPLETE) == 0 && state & TX_TASK_SET != 0 {
+            if let Some(task) = unsafe { &*self.tx_task.get() } {
+                task.wake();
             }
         }
+        state
     }
 
     fn try_recv(&self) -> Result<Option<T>, Canceled> {
-        // If we're complete, either `::close_rx` or `::drop_tx` was called.
+        // If we're complete, the sender either sent a value or was dropped.
         // We can assume a successful send if data is present.
-        if self.complete.load(SeqCst) {
-            if let Some(mut slot) = self.data.try_lock() {
-                if let Some(data) = slot.take() {
-                    return Ok(Some(data));
-                }
+        let state = self.state.load(Acquire);
+        if state & COMPLETE != 0 {
+            match self.take_data() {
+                Some(data) => Ok(Some(data)),
+                None => Err(Canceled),
             }
+        } else if state & CLOSED != 0 {
+            // The sender can't complete after we closed.
             Err(Canceled)
         } else {
             Ok(None)
@@ -253,72 +263,65 @@ impl<T> Inner<T> {
     fn recv(&self, cx: &mut task::Context) -> Poll<Result<T, Canceled>> {
         // Check to see if some data has arrived. If it hasn't then we need to
         // block our task.
-        //
-        // Note that the acquisition of the `rx_task` lock might fail below, but
-        // the only situation where this can happen is during `Sender::drop`
-        // when we are indeed completed already. If that's happening then we
-        // know we're completed so keep going.
-        let done = if self.complete.load(SeqCst) {
-            true
-        } else {
-            let task = cx.waker().clone();
-            match self.rx_task.try_lock() {
-                Some(mut slot) => { *slot = Some(task); false },
-                None => true,
+        let mut state = self.state.load(Acquire);
+        if state & COMPLETE == 0 && state & CLOSED == 0 {
+            // Take back ownership of a previously stored task, unless the
+            // sender completed and may be waking it right now.
+            if state & RX_TASK_SET != 0 {
+                state = self.state.fetch_and(!RX_TASK_SET, AcqRel);
             }
-        };
-
-        // If we're `done` via one of the paths above, then look at the data and
-        // figure out what the answer is. If, however, we stored `rx_task`
-        // successfully above we need to check again if we're completed in case
-        // a message was sent while `rx_task` was locked and couldn't notify us
-        // otherwise.
-        //
-        // If we're not done, and we're not complete, though, then we've
-        // successfully blocked our task and we return `Pending`.
-        if done || self.complete.load(SeqCst) {
-            // If taking the lock fails, the sender will realise that the we're
-            // `done` when it checks the `complete` flag on the way out, and
-            // will treat the send as a failure.
-            if let Some(mut slot) = self.data.try_lock() {
-                if let Some(data) = slot.take() {
-                    return Poll::Ready(Ok(data));
+
+            if state & COMPLETE == 0 {
+                unsafe { *self.rx_task.get() = Some(cx.waker().clone()) };
+
+                // If the sender completed while we stored the task, it didn't
+                // see it, so we need to pick up the result ourselves.
+                state = self.state.fetch_or(RX_TASK_SET, AcqRel);
+                if state & COMPLETE == 0 {
+                    return Poll::Pending
                 }
             }
-            Poll::Ready(Err(Canceled))
+        }
+
+        if state & COMPLETE != 0 {
+            match self.take_data() {
+                Some(data) => Poll::Ready(Ok(data)),
+                None => Poll::Ready(Err(Canceled)),
+            }
         } else {
-            Poll::Pending
+            // We were closed before the sender completed, so it never will.
+            Poll::Ready(Err(Canceled))
         }
     }
 
+    /// Takes the value out of `data`, which may only be called after
+    /// observing `COMPLETE`.
+    fn take_data(&self) -> Option<T> {
+        // The sender won't touch `data` anymore after setting `COMPLETE`.
+        unsafe { (*self.data.get()).take() }
+    }
+
     fn drop_rx(&self) {
         // Indicate to the `Sender` that we're done, so any future calls to
         // `poll_cancel` are weeded out.
-        self.complete.store(true, SeqCst);
+        let state = self.close_rx();
 
         // If we've blocked a task then there's no need for it to stick around,
-        // so we need to drop it. If this lock acquisition fails, though, then
-        // it's just because our `Sender` is trying to take the task, so we
-        // let them take care of that.
-        if let Some(mut slot) = self.rx_task.try_lock() {
-            let task = slot.take();
-            drop(slot);
-            drop(task);
+        // so we drop it right away. The sender can't set `COMPLETE` after
+        // `CLOSED`, so unless it completed before, it won't look at the task.
+        if state & COMPLETE == 0 {
+            unsafe { drop((*self.rx_task.get()).take()) };
         }
+    }
+}
 
-        // Finally, if our `Sender` wants to get notified of us going away, it
-        // would have stored something in `tx_task`. Here we try to peel that
-        // out and unpark it.
-        //
-        // Note that the `try_lock` here may fail, but only if the `Sender` is
-        // in the process of filling in the task. If that happens then we
-        // already flagged `complete` and they'll pick that up above.
-        if let Some(mut handle) = self.tx_task.try_lock() {
-            if let Some(task) = handle.take() {
-                drop(handle);
-                task.wake()
-            }
-        }
+impl<T> fmt::Debug for Inner<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let state = self.state.load(SeqCst);
+        fmt.debug_struct("Inner")
+            .field("complete", &(state & COMPLETE != 0))
+            .field("closed", &(state & CLOSED != 0))
+            .finish()
     }
 }
 
@@ -381,12 +384,19 @@ impl fmt::Display for Canceled {
     }
 }
 
-impl Error for Canceled {
+#[cfg(feature = "std")]
+impl ::std::error::Error for Canceled {
     fn description(&self) -> &str {
         "oneshot canceled"
     }
 }
 
+impl From<Never> for Canceled {
+    fn from(never: Never) -> Canceled {
+        never.into_any()
+    }
+}
+
 impl<T> Receiver<T> {
     /// Gracefully close this receiver, preventing any subsequent attempts to
     /// send to it.
@@ -396,7 +406,7 @@ impl<T> Receiver<T> {
     /// [`Receiver::poll`](Future::poll) to determine whether a
     /// message had previously been sent.
     pub fn close(&mut self) {
-        self.inner.close_rx()
+        self.inner.close_rx();
     }
 
     /// Attempts to receive a message outside of the context of a task.
@@ -411,7 +421,12 @@ impl<T> Receiver<T> {
     ///
     /// Returns an error if the sender was dropped.
     pub fn try_recv(&mut self) -> Result<Option<T>, Canceled> {
-        self.inner.try_recv()
+        let res = self.inner.try_recv();
+        match res {
+            Ok(None) => {}
+            Ok(Some(_)) | Err(_) => self.is_terminated = true,
+        }
+        res
     }
 }
 
@@ -419,10 +434,20 @@ impl<T> Future for Receiver<T> {
     type Output = Result<T, Canceled>;
 
     fn poll(
-        self: PinMut<Self>,
+        mut self: PinMut<Self>,
         cx: &mut task::Context,
     ) -> Poll<Result<T, Canceled>> {
-        self.inner.recv(cx)
+        let res = self.inner.recv(cx);
+        if res.is_ready() {
+            self.is_terminated = true;
+        }
+        res
+    }
+}
+
+impl<T> FusedFuture for Receiver<T> {
+    fn is_terminated(&self) -> bool {
+        self.is_terminated
     }
 }
 
@@ -1,7 +1,7 @@
 #![feature(futures_api, async_await, await_macro, pin)]
 
 use futures::channel::{mpsc, oneshot};
-use futures::executor::{block_on, block_on_stream};
+use futures::executor::{block_on, block_on_stream, LocalPool};
 use futures::future::{FutureExt, poll_fn};
 use futures::stream::{Stream, StreamExt};
 use futures::sink::{Sink, SinkExt};
@@ -524,3 +524,102 @@ fn try_send_recv() {
     rx.try_next().unwrap();
     rx.try_next().unwrap_err(); // should be empty
 }
+
+#[test]
+fn parked_senders_are_served_fairly() {
+    const PRODUCERS: usize = 8;
+    const BUFFER: usize = 4;
+    const MESSAGES: usize = 100;
+
+    let mut pool = LocalPool::new();
+    let mut spawn = pool.spawner();
+    let (tx, rx) = mpsc::channel::<(usize, usize)>(BUFFER);
+
+    for id in 0..PRODUCERS {
+        let mut tx = tx.clone();
+        spawn.spawn_local(async move {
+            for i in 0..MESSAGES {
+                await!(tx.send((id, i))).unwrap();
+            }
+        }).unwrap();
+    }
+    drop(tx);
+
+    let received: Vec<_> = pool.run_until(rx.collect(), &mut spawn);
+    assert_eq!(received.len(), PRODUCERS * MESSAGES);
+
+    // No producer may wait for more than a couple of rounds of the other
+    // producers between two of its messages being received.
+    let max_gap = 2 * (PRODUCERS + BUFFER);
+    let mut last_seen = vec![None; PRODUCERS];
+    for (pos, &(id, i)) in received.iter().enumerate() {
+        let gap = match last_seen[id] {
+            Some((last_pos, last_i)) => {
+                assert_eq!(last_i + 1, i);
+                pos - last_pos
+            }
+            None => {
+                assert_eq!(i, 0);
+                pos + 1
+            }
+        };
+        assert!(gap <= max_gap, "producer {} waited for {} messages", id, gap);
+        last_seen[id] = Some((pos, i));
+    }
+}
+
+#[test]
+fn dropped_parked_sender_is_skipped() {
+    block_on(poll_fn(move |cx| {
+        let (mut tx1, mut rx) = mpsc::channel::<i32>(0);
+        let mut tx2 = tx1.clone();
+        let mut tx3 = tx1.clone();
+
+        assert!(tx1.start_send(1).is_ok());
+        assert!(tx2.start_send(2).is_ok());
+        assert!(tx3.start_send(3).is_ok());
+        assert!(tx1.poll_ready(cx).is_pending());
+        assert!(tx2.poll_ready(cx).is_pending());
+        assert!(tx3.poll_ready(cx).is_pending());
+
+        drop(tx2);
+
+        assert_eq!(rx.try_next().unwrap(), Some(1));
+        assert!(tx1.poll_ready(cx).is_ready());
+        assert!(tx3.poll_ready(cx).is_pending());
+
+        // The wakeup for the second message skips the dropped sender
+        assert_eq!(rx.try_next().unwrap(), Some(2));
+        assert!(tx3.poll_ready(cx).is_ready());
+
+        Poll::Ready(())
+    }));
+}
+
+#[test]
+fn dropped_unparked_sender_passes_on_wakeup() {
+    block_on(poll_fn(move |cx| {
+        let (mut tx1, mut rx) = mpsc::channel::<i32>(0);
+        let mut tx2 = tx1.clone();
+        let mut tx3 = tx1.clone();
+
+        assert!(tx1.start_send(1).is_ok());
+        assert!(tx2.start_send(2).is_ok());
+        assert!(tx3.start_send(3).is_ok());
+        assert!(tx1.poll_ready(cx).is_pending());
+        assert!(tx2.poll_ready(cx).is_pending());
+        assert!(tx3.poll_ready(cx).is_pending());
+
+        // `tx1` is woken up, but dropped without sending
+        assert_eq!(rx.try_next().unwrap(), Some(1));
+        assert!(tx2.poll_ready(cx).is_pending());
+        drop(tx1);
+
+        // Its wakeup goes to `tx2`, the one for the second message to `tx3`
+        assert_eq!(rx.try_next().unwrap(), Some(2));
+        assert!(tx2.poll_ready(cx).is_ready());
+        assert!(tx3.poll_ready(cx).is_ready());
+
+        Poll::Ready(())
+    }));
+}
@@ -5,6 +5,7 @@ use futures::executor::block_on;
 use futures::future::{Future, FutureExt, poll_fn};
 use futures::task::{self, Poll};
 use std::mem::PinMut;
+use std::sync::Arc;
 use std::sync::mpsc;
 use std::thread;
 
@@ -125,6 +126,67 @@ fn cancel_sends() {
     t.join().unwrap();
 }
 
+#[test]
+fn stress_send_drop_and_poll() {
+    for _ in 0..10_000 {
+        let value = Arc::new(());
+        let (tx, mut rx) = oneshot::channel::<Arc<()>>();
+
+        let sent = value.clone();
+        let sender = thread::spawn(move || tx.send(sent).is_ok());
+        let receiver = thread::spawn(move || {
+            // Poll once and drop the receiver if nothing was sent yet, racing
+            // both the send and the drop with the sender.
+            block_on(poll_fn(|cx| Poll::Ready(match rx.poll_unpin(cx) {
+                Poll::Ready(Ok(value)) => Some(value),
+                Poll::Ready(Err(oneshot::Canceled)) => panic!("sender was dropped"),
+                Poll::Pending => None,
+            })))
+        });
+
+        let sent = sender.join().unwrap();
+        let received = receiver.join().unwrap();
+        if received.is_some() {
+            assert!(sent);
+        }
+
+        // The value was either returned to the sender, or received, or
+        // dropped with the channel, but never leaked.
+        drop(received);
+        assert_eq!(Arc::strong_count(&value), 1);
+    }
+}
+
+#[test]
+fn stress_send_and_blocking_recv() {
+    for i in 0..10_000 {
+        let (tx, rx) = oneshot::channel::<u32>();
+        let sender = thread::spawn(move || tx.send(i).unwrap());
+        assert_eq!(block_on(rx), Ok(i));
+        sender.join().unwrap();
+    }
+}
+
+#[test]
+fn stress_drop_sender_and_blocking_recv() {
+    for _ in 0..10_000 {
+        let (tx, rx) = oneshot::channel::<u32>();
+        let sender = thread::spawn(move || drop(tx));
+        assert_eq!(block_on(rx), Err(oneshot::Canceled));
+        sender.join().unwrap();
+    }
+}
+
+#[test]
+fn stress_poll_cancel_and_drop_receiver() {
+    for _ in 0..10_000 {
+        let (tx, rx) = oneshot::channel::<u32>();
+        let receiver = thread::spawn(move || drop(rx));
+        block_on(WaitForCancel { tx });
+        receiver.join().unwrap();
+    }
+}
+
 // #[test]
 // fn spawn_sends_items() {
 //     let core = local_executor::Core::new();
@@ -1,6 +1,7 @@
 //! Core traits and types for asynchronous operations in Rust.
 
 #![feature(pin, arbitrary_self_types, futures_api)]
+#![cfg_attr(feature = "alloc", feature(alloc))]
 
 #![no_std]
 
@@ -24,8 +25,20 @@ macro_rules! if_std {
     )*)
 }
 
+macro_rules! if_alloc {
+    ($($i:item)*) => ($(
+        #[cfg(feature = "alloc")]
+        $i
+    )*)
+}
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod future;
 
+pub mod never;
+
 pub mod stream;
 
 pub mod task;
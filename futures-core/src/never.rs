@@ -0,0 +1,43 @@
+//! The `Never` type.
+
+use core::fmt;
+
+/// A type with no possible values.
+///
+/// This is used as the error type of futures, streams and sinks which can't
+/// fail, e.g. the sinks for `Vec` and `VecDeque`. Since no value of this type
+/// can exist, a `Result<T, Never>` is always `Ok`.
+///
+/// `Never` can't be converted into every other error type with a blanket
+/// `From` impl, as that would overlap with the reflexive `impl<T> From<T> for
+/// T`. It implements `Error` when the `std` feature is enabled, so it still
+/// converts into `Box<dyn Error>`, and the error types of this library
+/// implement `From<Never>`. For any other type, [`Never::into_any`] can be
+/// used.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Never {}
+
+impl Never {
+    /// Convert this never value into any type.
+    ///
+    /// This can never be called, as there are no values of type `Never`.
+    pub fn into_any<T>(self) -> T {
+        match self {}
+    }
+}
+
+impl fmt::Display for Never {
+    fn fmt(&self, _: &mut fmt::Formatter) -> fmt::Result {
+        match *self {}
+    }
+}
+
+if_std! {
+    impl ::std::error::Error for Never {}
+
+    impl From<Never> for ::std::io::Error {
+        fn from(never: Never) -> ::std::io::Error {
+            never.into_any()
+        }
+    }
+}
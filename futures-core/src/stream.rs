@@ -4,9 +4,6 @@ use crate::task::{self, Poll};
 use core::marker::Unpin;
 use core::mem::PinMut;
 
-#[cfg(feature = "either")]
-use either::Either;
-
 /// A stream of values produced asynchronously.
 ///
 /// If `Future<Output = T>` is an asynchronous version of `T`, then `Stream<Item
@@ -52,6 +49,21 @@ pub trait Stream {
         self: PinMut<Self>,
         cx: &mut task::Context,
     ) -> Poll<Option<Self::Item>>;
+
+    /// Returns the bounds on the remaining length of the stream.
+    ///
+    /// Specifically, `size_hint()` returns a tuple where the first element
+    /// is the lower bound, and the second element is the upper bound. A
+    /// `None` upper bound means that there is no known upper bound, or that
+    /// it is larger than `usize`.
+    ///
+    /// Like [`Iterator::size_hint`], this is only a hint and must not be
+    /// trusted for memory safety; a buggy stream may yield fewer or more
+    /// items than it reports. The default implementation returns `(0, None)`,
+    /// which is correct for any stream.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
 }
 
 impl<'a, S: ?Sized + Stream + Unpin> Stream for &'a mut S {
@@ -63,6 +75,10 @@ impl<'a, S: ?Sized + Stream + Unpin> Stream for &'a mut S {
     ) -> Poll<Option<Self::Item>> {
         S::poll_next(PinMut::new(&mut **self), cx)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
 }
 
 impl<'a, S: ?Sized + Stream> Stream for PinMut<'a, S> {
@@ -74,22 +90,34 @@ impl<'a, S: ?Sized + Stream> Stream for PinMut<'a, S> {
     ) -> Poll<Option<Self::Item>> {
         S::poll_next((*self).reborrow(), cx)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
 }
 
-#[cfg(feature = "either")]
-impl<A, B> Stream for Either<A, B>
-    where A: Stream,
-          B: Stream<Item = A::Item>
-{
-    type Item = A::Item;
-
-    fn poll_next(self: PinMut<Self>, cx: &mut task::Context) -> Poll<Option<A::Item>> {
-        unsafe {
-            match PinMut::get_mut_unchecked(self) {
-                Either::Left(a) => PinMut::new_unchecked(a).poll_next(cx),
-                Either::Right(b) => PinMut::new_unchecked(b).poll_next(cx),
-            }
-        }
+/// A `Stream` which tracks whether or not the underlying stream
+/// should no longer be polled.
+///
+/// `is_terminated` will return `true` if a stream should no longer be polled.
+/// Usually, this state occurs after `poll_next` (or `try_poll_next`) returned
+/// `Poll::Ready(None)`. However, `is_terminated` may also return `true` if a
+/// stream has become inactive and can no longer make progress and should be
+/// ignored or dropped rather than being polled again.
+pub trait FusedStream {
+    /// Returns `true` if the stream should no longer be polled.
+    fn is_terminated(&self) -> bool;
+}
+
+impl<'a, S: ?Sized + FusedStream> FusedStream for &'a mut S {
+    fn is_terminated(&self) -> bool {
+        <S as FusedStream>::is_terminated(&**self)
+    }
+}
+
+impl<'a, S: ?Sized + FusedStream> FusedStream for PinMut<'a, S> {
+    fn is_terminated(&self) -> bool {
+        <S as FusedStream>::is_terminated(&**self)
     }
 }
 
@@ -124,8 +152,9 @@ impl<S, T, E> TryStream for S
     }
 }
 
-if_std! {
-    use std::boxed::{Box, PinBox};
+if_alloc! {
+    use alloc::boxed::{Box, PinBox};
+    use alloc::collections::VecDeque;
 
     impl<S: ?Sized + Stream + Unpin> Stream for Box<S> {
         type Item = S::Item;
@@ -136,6 +165,10 @@ if_std! {
         ) -> Poll<Option<Self::Item>> {
             PinMut::new(&mut **self).poll_next(cx)
         }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (**self).size_hint()
+        }
     }
 
     impl<S: ?Sized + Stream> Stream for PinBox<S> {
@@ -147,20 +180,25 @@ if_std! {
         ) -> Poll<Option<Self::Item>> {
             self.as_pin_mut().poll_next(cx)
         }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (**self).size_hint()
+        }
     }
 
-    impl<S: Stream> Stream for ::std::panic::AssertUnwindSafe<S> {
-        type Item = S::Item;
+    impl<S: ?Sized + FusedStream> FusedStream for Box<S> {
+        fn is_terminated(&self) -> bool {
+            <S as FusedStream>::is_terminated(&**self)
+        }
+    }
 
-        fn poll_next(
-            self: PinMut<Self>,
-            cx: &mut task::Context,
-        ) -> Poll<Option<S::Item>> {
-            unsafe { PinMut::map_unchecked(self, |x| &mut x.0) }.poll_next(cx)
+    impl<S: ?Sized + FusedStream> FusedStream for PinBox<S> {
+        fn is_terminated(&self) -> bool {
+            <S as FusedStream>::is_terminated(&**self)
         }
     }
 
-    impl<T: Unpin> Stream for ::std::collections::VecDeque<T> {
+    impl<T: Unpin> Stream for VecDeque<T> {
         type Item = T;
 
         fn poll_next(
@@ -169,5 +207,27 @@ if_std! {
         ) -> Poll<Option<Self::Item>> {
             Poll::Ready(self.pop_front())
         }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let len = self.len();
+            (len, Some(len))
+        }
+    }
+}
+
+if_std! {
+    impl<S: Stream> Stream for ::std::panic::AssertUnwindSafe<S> {
+        type Item = S::Item;
+
+        fn poll_next(
+            self: PinMut<Self>,
+            cx: &mut task::Context,
+        ) -> Poll<Option<S::Item>> {
+            unsafe { PinMut::map_unchecked(self, |x| &mut x.0) }.poll_next(cx)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.0.size_hint()
+        }
     }
 }
@@ -6,6 +6,6 @@ pub use core::task::{
     SpawnErrorKind, SpawnObjError, SpawnLocalObjError,
 };
 
-if_std! {
-    pub use std::task::{Wake, local_waker, local_waker_from_nonlocal};
+if_alloc! {
+    pub use alloc::task::{Wake, local_waker, local_waker_from_nonlocal};
 }
@@ -0,0 +1,82 @@
+#![feature(test, pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::ThreadPool;
+use futures::future;
+use futures::task::SpawnExt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use test::Bencher;
+
+#[bench]
+fn spawn_many_from_outside(b: &mut Bencher) {
+    const NUM: usize = 10_000;
+
+    let mut pool = ThreadPool::new().unwrap();
+
+    b.iter(|| {
+        let (tx, rx) = mpsc::channel();
+        for _ in 0..NUM {
+            let tx = tx.clone();
+            pool.spawn(future::lazy(move |_| tx.send(()).unwrap())).unwrap();
+        }
+        for _ in 0..NUM {
+            rx.recv().unwrap();
+        }
+    });
+}
+
+#[bench]
+fn spawn_many_from_task(b: &mut Bencher) {
+    const NUM: usize = 10_000;
+
+    let mut pool = ThreadPool::new().unwrap();
+
+    b.iter(|| {
+        let (tx, rx) = mpsc::channel();
+        pool.spawn(future::lazy(move |cx| {
+            for _ in 0..NUM {
+                let tx = tx.clone();
+                cx.spawner().spawn(future::lazy(move |_| tx.send(()).unwrap())).unwrap();
+            }
+        })).unwrap();
+        for _ in 0..NUM {
+            rx.recv().unwrap();
+        }
+    });
+}
+
+#[bench]
+fn spawn_tree_from_tasks(b: &mut Bencher) {
+    const DEPTH: usize = 12;
+
+    fn spawn_tree(
+        cx: &mut futures::task::Context,
+        depth: usize,
+        done: Arc<AtomicUsize>,
+        tx: mpsc::Sender<()>,
+    ) {
+        if depth == 0 {
+            if done.fetch_sub(1, Ordering::SeqCst) == 1 {
+                tx.send(()).unwrap();
+            }
+            return;
+        }
+        for _ in 0..2 {
+            let done = done.clone();
+            let tx = tx.clone();
+            cx.spawner().spawn(future::lazy(move |cx| {
+                spawn_tree(cx, depth - 1, done, tx)
+            })).unwrap();
+        }
+    }
+
+    let mut pool = ThreadPool::new().unwrap();
+
+    b.iter(|| {
+        let (tx, rx) = mpsc::channel();
+        let done = Arc::new(AtomicUsize::new(1 << DEPTH));
+        pool.spawn(future::lazy(move |cx| spawn_tree(cx, DEPTH, done, tx))).unwrap();
+        rx.recv().unwrap();
+    });
+}
@@ -2,11 +2,12 @@ use crate::{enter, ThreadPool};
 use futures_core::future::{Future, FutureObj, LocalFutureObj};
 use futures_core::stream::{Stream};
 use futures_core::task::{
-    self, Context, Poll, LocalWaker, Wake,
+    Context, Poll, LocalWaker,
     Spawn, SpawnObjError, SpawnLocalObjError, SpawnErrorKind
 };
 use futures_util::stream::FuturesUnordered;
 use futures_util::stream::StreamExt;
+use futures_util::task::{ArcWake, SpawnError, waker_ref};
 use lazy_static::lazy_static;
 use pin_utils::pin_mut;
 use std::cell::{RefCell};
@@ -15,6 +16,8 @@ use std::ops::{Deref, DerefMut};
 use std::prelude::v1::*;
 use std::rc::{Rc, Weak};
 use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering::SeqCst;
 use std::thread::{self, Thread};
 
 /// A single-threaded task pool for polling futures to completion.
@@ -44,18 +47,64 @@ pub struct LocalSpawn {
 type Incoming = RefCell<Vec<LocalFutureObj<'static, ()>>>;
 
 pub(crate) struct ThreadNotify {
-    thread: Thread
+    thread: Thread,
+    state: AtomicUsize,
 }
 
+// There are three possible states for a `ThreadNotify`, listed below with
+// their possible transitions:
+
+// No wakeup is pending and the thread is not (about to be) parked
+const EMPTY: usize = 0;         // --> WAITING, NOTIFIED
+
+// The thread is parked, or about to park, waiting for a wakeup
+const WAITING: usize = 1;       // --> NOTIFIED
+
+// A wakeup arrived that has not been consumed by the executor yet
+const NOTIFIED: usize = 2;      // --> EMPTY
+
 thread_local! {
     static CURRENT_THREAD_NOTIFY: Arc<ThreadNotify> = Arc::new(ThreadNotify {
         thread: thread::current(),
+        state: AtomicUsize::new(EMPTY),
     });
 }
 
-impl Wake for ThreadNotify {
+impl ThreadNotify {
+    /// Block the current thread until a wakeup has been received.
+    ///
+    /// Returns immediately if a wakeup arrived since the last call, e.g.
+    /// because the future woke itself while being polled. Spurious returns
+    /// from `thread::park` are ignored, so a pending future costs no CPU
+    /// time until it is actually woken.
+    fn park(&self) {
+        match self.state.compare_exchange(EMPTY, WAITING, SeqCst, SeqCst) {
+            Ok(_) => {}
+            Err(NOTIFIED) => {
+                // Consume the wakeup that raced ahead of us.
+                self.state.store(EMPTY, SeqCst);
+                return;
+            }
+            Err(actual) => panic!("inconsistent park state: {}", actual),
+        }
+
+        loop {
+            thread::park();
+            if self.state.compare_exchange(NOTIFIED, EMPTY, SeqCst, SeqCst).is_ok() {
+                return;
+            }
+            // Spurious wakeup, go back to sleep.
+        }
+    }
+}
+
+impl ArcWake for ThreadNotify {
     fn wake(arc_self: &Arc<Self>) {
-        arc_self.thread.unpark();
+        // Only the thread sleeping in `park` needs to be unparked; a wakeup
+        // recorded while it is running is picked up by its next `park` call.
+        if arc_self.state.swap(NOTIFIED, SeqCst) == WAITING {
+            arc_self.thread.unpark();
+        }
     }
 }
 
@@ -67,13 +116,12 @@ fn run_executor<T, F: FnMut(&LocalWaker) -> Poll<T>>(mut f: F) -> T {
                  another executor");
 
     CURRENT_THREAD_NOTIFY.with(|thread_notify| {
-        let local_waker =
-          task::local_waker_from_nonlocal(thread_notify.clone());
+        let waker = waker_ref(thread_notify);
         loop {
-            if let Poll::Ready(t) = f(&local_waker) {
+            if let Poll::Ready(t) = f(waker.as_local_waker()) {
                 return t;
             }
-            thread::park();
+            thread_notify.park();
         }
     })
 }
@@ -165,6 +213,43 @@ impl LocalPool {
         })
     }
 
+    /// Runs all tasks in the pool and returns once no more progress can be
+    /// made, i.e. when every remaining task is waiting to be woken.
+    ///
+    /// Unlike [`run`](LocalPool::run), this doesn't block the calling thread
+    /// on tasks which aren't ready. Tasks which are spawned through the
+    /// [`spawner()`](LocalPool::spawner) handle while the pool is running,
+    /// including from within other tasks, are run as well.
+    ///
+    /// ```
+    /// #![feature(pin, arbitrary_self_types, futures_api)]
+    /// use futures::executor::LocalPool;
+    /// use futures::future::lazy;
+    ///
+    /// let mut pool = LocalPool::new();
+    /// let mut spawn = pool.spawner();
+    /// let mut inner_spawn = spawn.clone();
+    ///
+    /// spawn.spawn_local(lazy(move |_| {
+    ///     inner_spawn.spawn_local(lazy(|_| ())).unwrap();
+    /// })).unwrap();
+    ///
+    /// // runs both the spawned task and the one it spawned
+    /// pool.run_until_stalled(&mut spawn);
+    /// ```
+    pub fn run_until_stalled<Sp>(&mut self, spawn: &mut Sp)
+        where Sp: Spawn + Sized
+    {
+        let _enter = enter()
+            .expect("cannot execute `LocalPool` executor from within \
+                     another executor");
+
+        CURRENT_THREAD_NOTIFY.with(|thread_notify| {
+            let waker = waker_ref(thread_notify);
+            let _ = self.poll_pool(waker.as_local_waker(), spawn);
+        })
+    }
+
     // Make maximal progress on the entire pool of spawned task, returning `Ready`
     // if the pool is empty and `Pending` if no further progress can be made.
     fn poll_pool<Sp>(&mut self, local_waker: &LocalWaker, spawn: &mut Sp)
@@ -299,4 +384,18 @@ impl LocalSpawn {
             Err(SpawnLocalObjError{ future, kind: SpawnErrorKind::shutdown() })
         }
     }
+
+    /// Spawn a non-`Send` future with output `()` onto the associated
+    /// [`LocalPool`](LocalPool).
+    ///
+    /// This is a convenience for
+    /// [`spawn_local_obj`](LocalSpawn::spawn_local_obj) which boxes the
+    /// future. Like all spawns through this handle, it fails with a
+    /// [`shutdown`](SpawnErrorKind::shutdown) error once the pool is dropped.
+    pub fn spawn_local<Fut>(&mut self, future: Fut) -> Result<(), SpawnError>
+        where Fut: Future<Output = ()> + 'static,
+    {
+        let res = self.spawn_local_obj(Box::new(future).into());
+        res.map_err(|err| SpawnError { kind: err.kind })
+    }
 }
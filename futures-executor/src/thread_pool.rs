@@ -1,15 +1,17 @@
 use crate::enter;
 use crate::unpark_mutex::UnparkMutex;
 use futures_core::future::{Future, FutureObj};
-use futures_core::task::{self, Poll, Wake, Spawn, SpawnObjError};
+use futures_core::task::{self, Poll, Spawn, SpawnObjError, SpawnErrorKind};
 use futures_util::future::FutureExt;
-use futures_util::task::local_waker_ref_from_nonlocal;
+use futures_util::task::{ArcWake, waker_ref};
 use num_cpus;
+use std::cell::Cell;
+use std::collections::VecDeque;
 use std::io;
+use std::mem;
 use std::prelude::v1::*;
-use std::sync::{Arc, Mutex};
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::mpsc;
+use std::sync::{Arc, Condvar, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::thread;
 use std::fmt;
 
@@ -19,8 +21,38 @@ use std::fmt;
 /// The thread pool multiplexes any number of tasks onto a fixed number of
 /// worker threads.
 ///
+/// Every worker thread has its own queue of tasks. Tasks spawned or woken from
+/// within a worker are pushed onto that worker's queue, while tasks spawned
+/// from outside of the pool go to a shared injector queue. Workers that run
+/// out of tasks take work from the injector queue or steal it from the queues
+/// of their peers.
+///
 /// This type is a clonable handle to the threadpool itself.
 /// Cloning it will only create a new reference, not a new threadpool.
+///
+/// Tasks whose output is needed later can be spawned with
+/// [`spawn_with_handle`](futures_util::task::SpawnExt::spawn_with_handle).
+/// The returned [`JoinHandle`](futures_util::task::JoinHandle) resolves to
+/// the task's output, dropping it cancels the task and a panic inside the
+/// task is resumed when the handle is awaited:
+///
+/// ```
+/// #![feature(async_await, await_macro, futures_api)]
+/// use futures::executor::{block_on, ThreadPool};
+/// use futures::future;
+/// use futures::task::SpawnExt;
+///
+/// let mut pool = ThreadPool::new().unwrap();
+///
+/// let handle = pool.spawn_with_handle(future::ready(7)).unwrap();
+/// assert_eq!(block_on(handle), 7);
+/// ```
+///
+/// A pool can be shut down explicitly with [`shutdown`](ThreadPool::shutdown)
+/// or [`shutdown_now`](ThreadPool::shutdown_now). Otherwise it shuts down
+/// gracefully once the last handle to it is dropped, including the handles
+/// held by its own tasks, and the thread dropping that handle waits for the
+/// worker threads to exit.
 pub struct ThreadPool {
     state: Arc<PoolState>,
 }
@@ -38,12 +70,43 @@ trait AssertSendSync: Send + Sync {}
 impl AssertSendSync for ThreadPool {}
 
 struct PoolState {
-    tx: Mutex<mpsc::Sender<Message>>,
-    rx: Mutex<mpsc::Receiver<Message>>,
+    injector: Mutex<VecDeque<Task>>,
+    queues: Vec<Mutex<VecDeque<Task>>>,
+    sleep: Mutex<Sleep>,
+    wakeup: Condvar,
+    stopped: AtomicBool,
+    threads: Mutex<Vec<thread::JoinHandle<()>>>,
     cnt: AtomicUsize,
     size: usize,
 }
 
+struct Sleep {
+    sleeping: usize,
+    workers: usize,
+    lifecycle: Lifecycle,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd)]
+enum Lifecycle {
+    // Tasks are spawned and run as usual.
+    Running,
+    // Spawning fails, woken tasks still run. The workers exit once all queues
+    // are empty.
+    ShuttingDown,
+    // The workers have exited or are about to. Woken tasks are dropped.
+    Stopped,
+}
+
+// A worker checks the injector queue before its own queue once every this many
+// tasks, so that tasks which keep rescheduling themselves on the local queue
+// can't starve tasks spawned from outside of the pool.
+const INJECTOR_INTERVAL: usize = 61;
+
+thread_local! {
+    // The pool and queue index of the worker running on this thread, if any.
+    static CURRENT_WORKER: Cell<Option<(usize, usize)>> = Cell::new(None);
+}
+
 impl fmt::Debug for ThreadPool {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("ThreadPool")
@@ -61,11 +124,6 @@ impl fmt::Debug for ThreadPoolBuilder {
     }
 }
 
-enum Message {
-    Run(Task),
-    Close,
-}
-
 impl ThreadPool {
     /// Creates a new thread pool with the default configuration.
     ///
@@ -97,6 +155,34 @@ impl ThreadPool {
     pub fn run<F: Future>(&mut self, f: F) -> F::Output {
         crate::LocalPool::new().run_until(f, self)
     }
+
+    /// Shuts the thread pool down gracefully, blocking the current thread
+    /// until all worker threads have exited.
+    ///
+    /// Once the shutdown has begun, spawning onto the pool fails with
+    /// [`SpawnErrorKind::shutdown`](futures_core::task::SpawnErrorKind::shutdown),
+    /// this includes tasks spawned by the pool's own tasks. Tasks that are
+    /// already queued, or that are woken up while the pool shuts down, still
+    /// run. The workers exit when there is nothing left to run, tasks that are
+    /// still pending at that point are never polled again.
+    ///
+    /// When called from a task running on this pool, all workers except for
+    /// the current one are joined.
+    pub fn shutdown(self) {
+        self.state.shutdown(false);
+        self.state.join_workers();
+    }
+
+    /// Shuts the thread pool down without running the queued tasks, blocking
+    /// the current thread until all worker threads have exited.
+    ///
+    /// Like [`shutdown`](ThreadPool::shutdown), this makes further spawns
+    /// fail. All queued tasks are dropped, tasks that are being polled right
+    /// now finish their current poll but are never polled again.
+    pub fn shutdown_now(self) {
+        self.state.shutdown(true);
+        self.state.join_workers();
+    }
 }
 
 impl Spawn for ThreadPool {
@@ -112,14 +198,182 @@ impl Spawn for ThreadPool {
             }),
             exec: self.clone(),
         };
-        self.state.send(Message::Run(task));
-        Ok(())
+        self.state.spawn(task).map_err(|task| SpawnObjError {
+            future: task.future,
+            kind: SpawnErrorKind::shutdown(),
+        })
+    }
+
+    fn status(&self) -> Result<(), SpawnErrorKind> {
+        if self.state.sleep.lock().unwrap().lifecycle == Lifecycle::Running {
+            Ok(())
+        } else {
+            Err(SpawnErrorKind::shutdown())
+        }
     }
 }
 
 impl PoolState {
-    fn send(&self, msg: Message) {
-        self.tx.lock().unwrap().send(msg).unwrap();
+    fn id(&self) -> usize {
+        self as *const PoolState as usize
+    }
+
+    /// Queue a newly spawned task, unless the pool is shutting down.
+    fn spawn(&self, task: Task) -> Result<(), Task> {
+        self.push(task, Lifecycle::Running)
+    }
+
+    /// Queue a task that has been woken up. The task is dropped if the
+    /// workers have stopped.
+    fn schedule(&self, task: Task) {
+        // Dropping the task can spawn tasks or drop the last handle to the
+        // pool, so it must only happen once `push` released its locks.
+        if let Err(task) = self.push(task, Lifecycle::ShuttingDown) {
+            drop(task);
+        }
+    }
+
+    // Queue `task` if the pool hasn't progressed past `latest`, waking up an
+    // idle worker if there is one.
+    //
+    // Tasks are queued while holding the sleep lock, so they can't slip into
+    // the queues after the lifecycle changed or a worker checked for work.
+    fn push(&self, task: Task, latest: Lifecycle) -> Result<(), Task> {
+        let sleep = self.sleep.lock().unwrap();
+        if sleep.lifecycle > latest {
+            return Err(task);
+        }
+
+        let local = CURRENT_WORKER.with(|worker| worker.get())
+            .and_then(|(pool, idx)| if pool == self.id() { Some(idx) } else { None });
+        match local {
+            Some(idx) => self.queues[idx].lock().unwrap().push_back(task),
+            None => self.injector.lock().unwrap().push_back(task),
+        }
+
+        if sleep.sleeping > 0 {
+            self.wakeup.notify_one();
+        }
+        Ok(())
+    }
+
+    // Stop accepting new tasks. With `drop_queued` set, the workers stop
+    // right away and the queued tasks are dropped instead of run.
+    fn shutdown(&self, drop_queued: bool) {
+        let dropped = {
+            let mut sleep = self.sleep.lock().unwrap();
+            if sleep.lifecycle == Lifecycle::Running {
+                sleep.lifecycle = Lifecycle::ShuttingDown;
+            }
+            let dropped = if drop_queued {
+                self.stop(&mut sleep)
+            } else {
+                Vec::new()
+            };
+            self.wakeup.notify_all();
+            dropped
+        };
+        drop(dropped);
+    }
+
+    // Move to `Stopped` and take all queued tasks out of the queues. The
+    // caller must drop them after releasing the sleep lock.
+    fn stop(&self, sleep: &mut Sleep) -> Vec<Task> {
+        sleep.lifecycle = Lifecycle::Stopped;
+        self.stopped.store(true, Ordering::SeqCst);
+        let mut tasks: Vec<Task> = self.injector.lock().unwrap().drain(..).collect();
+        for queue in &self.queues {
+            tasks.extend(queue.lock().unwrap().drain(..));
+        }
+        tasks
+    }
+
+    fn worker_exited(&self) {
+        let dropped = {
+            let mut sleep = self.sleep.lock().unwrap();
+            sleep.workers -= 1;
+            if sleep.workers == 0 {
+                // Normally the queues are empty at this point, but workers
+                // that stopped early or died to a panic can leave tasks behind.
+                self.stop(&mut sleep)
+            } else {
+                Vec::new()
+            }
+        };
+        drop(dropped);
+    }
+
+    fn join_workers(&self) {
+        let threads = mem::replace(&mut *self.threads.lock().unwrap(), Vec::new());
+        let current = thread::current().id();
+        for thread in threads {
+            // A worker can't wait for itself to exit.
+            if thread.thread().id() != current {
+                // Joining only fails if a task panicked on the worker, that
+                // panic has already been reported.
+                let _ = thread.join();
+            }
+        }
+    }
+
+    fn find_task(&self, idx: usize, tick: usize) -> Option<Task> {
+        if tick % INJECTOR_INTERVAL == 0 {
+            if let Some(task) = self.injector.lock().unwrap().pop_front() {
+                return Some(task);
+            }
+        }
+        if let Some(task) = self.queues[idx].lock().unwrap().pop_front() {
+            return Some(task);
+        }
+        if let Some(task) = self.injector.lock().unwrap().pop_front() {
+            return Some(task);
+        }
+        self.steal(idx)
+    }
+
+    // Take the newer half of the tasks from the first peer that has any,
+    // keeping one task to run and moving the rest to our own queue.
+    fn steal(&self, idx: usize) -> Option<Task> {
+        for offset in 1..self.size {
+            let victim = (idx + offset) % self.size;
+            let mut stolen = {
+                let mut queue = self.queues[victim].lock().unwrap();
+                let len = queue.len();
+                queue.split_off(len / 2)
+            };
+            if let Some(task) = stolen.pop_front() {
+                self.queues[idx].lock().unwrap().extend(stolen);
+                return Some(task);
+            }
+        }
+        None
+    }
+
+    fn has_work(&self) -> bool {
+        !self.injector.lock().unwrap().is_empty() ||
+            self.queues.iter().any(|queue| !queue.lock().unwrap().is_empty())
+    }
+
+    // Block until there may be work to do. Returns `false` once the worker
+    // should exit.
+    fn wait_for_work(&self) -> bool {
+        let mut sleep = self.sleep.lock().unwrap();
+        loop {
+            // Tasks are queued under the sleep lock in `push`, so checking
+            // under the lock guarantees no wakeup is missed.
+            if sleep.lifecycle == Lifecycle::Stopped {
+                return false;
+            }
+            if self.has_work() {
+                return true;
+            }
+            if sleep.lifecycle == Lifecycle::ShuttingDown {
+                return false;
+            }
+            sleep.sleeping += 1;
+            sleep = self.wakeup.wait(sleep).unwrap();
+            sleep.sleeping -= 1;
+        }
     }
 
     fn work(&self,
@@ -127,19 +381,39 @@ impl PoolState {
             after_start: Option<Arc<dyn Fn(usize) + Send + Sync>>,
             before_stop: Option<Arc<dyn Fn(usize) + Send + Sync>>) {
         let _scope = enter().unwrap();
+        let _exit = WorkerExit(self);
+        CURRENT_WORKER.with(|worker| worker.set(Some((self.id(), idx))));
         if let Some(after_start) = after_start {
             after_start(idx);
         }
-        loop {
-            let msg = self.rx.lock().unwrap().recv().unwrap();
-            match msg {
-                Message::Run(task) => task.run(),
-                Message::Close => break,
+        let mut tick = 0usize;
+        while !self.stopped.load(Ordering::SeqCst) {
+            match self.find_task(idx, tick) {
+                Some(task) => {
+                    tick = tick.wrapping_add(1);
+                    task.run();
+                }
+                None => {
+                    if !self.wait_for_work() {
+                        break;
+                    }
+                }
             }
         }
         if let Some(before_stop) = before_stop {
             before_stop(idx);
         }
+        CURRENT_WORKER.with(|worker| worker.set(None));
+    }
+}
+
+// Accounts for the exit of a worker, even if it is unwinding from a panic in
+// one of the tasks.
+struct WorkerExit<'a>(&'a PoolState);
+
+impl<'a> Drop for WorkerExit<'a> {
+    fn drop(&mut self) {
+        self.0.worker_exited();
     }
 }
 
@@ -153,9 +427,8 @@ impl Clone for ThreadPool {
 impl Drop for ThreadPool {
     fn drop(&mut self) {
         if self.state.cnt.fetch_sub(1, Ordering::Relaxed) == 1 {
-            for _ in 0..self.state.size {
-                self.state.send(Message::Close);
-            }
+            self.state.shutdown(false);
+            self.state.join_workers();
         }
     }
 }
@@ -239,16 +512,25 @@ impl ThreadPoolBuilder {
     ///
     /// Panics if `pool_size == 0`.
     pub fn create(&mut self) -> Result<ThreadPool, io::Error> {
-        let (tx, rx) = mpsc::channel();
+        assert!(self.pool_size > 0);
         let pool = ThreadPool {
             state: Arc::new(PoolState {
-                tx: Mutex::new(tx),
-                rx: Mutex::new(rx),
+                injector: Mutex::new(VecDeque::new()),
+                queues: (0..self.pool_size)
+                    .map(|_| Mutex::new(VecDeque::new()))
+                    .collect(),
+                sleep: Mutex::new(Sleep {
+                    sleeping: 0,
+                    workers: self.pool_size,
+                    lifecycle: Lifecycle::Running,
+                }),
+                wakeup: Condvar::new(),
+                stopped: AtomicBool::new(false),
+                threads: Mutex::new(Vec::with_capacity(self.pool_size)),
                 cnt: AtomicUsize::new(1),
                 size: self.pool_size,
             }),
         };
-        assert!(self.pool_size > 0);
 
         for counter in 0..self.pool_size {
             let state = pool.state.clone();
@@ -261,7 +543,10 @@ impl ThreadPoolBuilder {
             if self.stack_size > 0 {
                 thread_builder = thread_builder.stack_size(self.stack_size);
             }
-            thread_builder.spawn(move || state.work(counter, after_start, before_stop))?;
+            let thread = thread_builder.spawn(move || {
+                state.work(counter, after_start, before_stop)
+            })?;
+            pool.state.threads.lock().unwrap().push(thread);
         }
         Ok(pool)
     }
@@ -290,33 +575,34 @@ impl Task {
     /// thread.
     pub fn run(self) {
         let Task { mut future, wake_handle, mut exec } = self;
-        let local_waker = local_waker_ref_from_nonlocal(&wake_handle);
+        let waker = waker_ref(&wake_handle);
 
         // Safety: The ownership of this `Task` object is evidence that
         // we are in the `POLLING`/`REPOLL` state for the mutex.
         unsafe {
             wake_handle.mutex.start_poll();
 
-            loop {
-                let res = {
-                    let mut cx = task::Context::new(&local_waker, &mut exec);
-                    future.poll_unpin(&mut cx)
-                };
-                match res {
-                    Poll::Pending => {}
-                    Poll::Ready(()) => return wake_handle.mutex.complete(),
-                }
-                let task = Task {
-                    future,
-                    wake_handle: wake_handle.clone(),
-                    exec,
-                };
-                match wake_handle.mutex.wait(task) {
-                    Ok(()) => return, // we've waited
-                    Err(task) => { // someone's notified us
-                        future = task.future;
-                        exec = task.exec;
-                    }
+            let res = {
+                let mut cx = task::Context::new(waker.as_local_waker(), &mut exec);
+                future.poll_unpin(&mut cx)
+            };
+            match res {
+                Poll::Pending => {}
+                Poll::Ready(()) => return wake_handle.mutex.complete(),
+            }
+            let task = Task {
+                future,
+                wake_handle: wake_handle.clone(),
+                exec,
+            };
+            match wake_handle.mutex.wait(task) {
+                Ok(()) => {} // we've waited
+                Err(task) => {
+                    // someone's notified us; queue the task up again behind
+                    // the other tasks of this worker instead of re-polling it
+                    // right away, so that it can't monopolize the thread.
+                    let state = task.exec.state.clone();
+                    state.schedule(task);
                 }
             }
         }
@@ -331,10 +617,10 @@ impl fmt::Debug for Task {
     }
 }
 
-impl Wake for WakeHandle {
+impl ArcWake for WakeHandle {
     fn wake(arc_self: &Arc<Self>) {
         match arc_self.mutex.notify() {
-            Ok(task) => arc_self.exec.state.send(Message::Run(task)),
+            Ok(task) => arc_self.exec.state.schedule(task),
             Err(()) => {}
         }
     }
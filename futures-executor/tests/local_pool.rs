@@ -1,13 +1,18 @@
 #![feature(pin, arbitrary_self_types, futures_api)]
 
 use futures::channel::oneshot;
-use futures::executor::LocalPool;
-use futures::future::{Future, lazy};
-use futures::task::{self, Poll, Spawn};
+use futures::executor::{block_on, LocalPool, LocalSpawn};
+use futures::future::{Future, FutureExt, lazy, poll_fn};
+use futures::task::{self, Poll, Spawn, SpawnExt, Waker};
 use std::boxed::PinBox;
 use std::cell::{Cell, RefCell};
 use std::mem::PinMut;
 use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
 
 struct Pending(Rc<()>);
 
@@ -171,3 +176,161 @@ fn tasks_are_scheduled_fairly() {
     pool.run(&mut spawn);
 }
 
+
+#[test]
+fn block_on_wakeup_from_other_thread() {
+    let woken = Arc::new(AtomicBool::new(false));
+    let mut polls = 0;
+    let mut started = false;
+
+    block_on(poll_fn(|cx| {
+        polls += 1;
+        if woken.load(Ordering::SeqCst) {
+            return Poll::Ready(());
+        }
+        if !started {
+            started = true;
+            let woken = woken.clone();
+            let waker = cx.waker().clone();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(50));
+                woken.store(true, Ordering::SeqCst);
+                waker.wake();
+            });
+        }
+        Poll::Pending
+    }));
+
+    // The future is only polled again after the real wakeup.
+    assert_eq!(polls, 2);
+}
+
+#[test]
+fn block_on_ignores_spurious_unparks() {
+    let woken = Arc::new(AtomicBool::new(false));
+    let mut polls = 0;
+    let mut started = false;
+    let main_thread = thread::current();
+
+    block_on(poll_fn(|cx| {
+        polls += 1;
+        if woken.load(Ordering::SeqCst) {
+            return Poll::Ready(());
+        }
+        if !started {
+            started = true;
+            let woken = woken.clone();
+            let main_thread = main_thread.clone();
+            let waker = cx.waker().clone();
+            thread::spawn(move || {
+                for _ in 0..10 {
+                    main_thread.unpark();
+                    thread::sleep(Duration::from_millis(5));
+                }
+                woken.store(true, Ordering::SeqCst);
+                waker.wake();
+            });
+        }
+        Poll::Pending
+    }));
+
+    assert_eq!(polls, 2);
+}
+
+#[test]
+fn block_on_wake_before_park() {
+    // Wake the task from a helper thread as soon as it returns `Pending`, so
+    // that the wakeup races with the executor going to sleep.
+    let (tx, rx) = mpsc::channel::<Waker>();
+    let helper = thread::spawn(move || {
+        for waker in rx {
+            waker.wake();
+        }
+    });
+
+    for _ in 0..10_000 {
+        let mut sent = false;
+        block_on(poll_fn(|cx| {
+            if sent {
+                Poll::Ready(())
+            } else {
+                sent = true;
+                tx.send(cx.waker().clone()).unwrap();
+                Poll::Pending
+            }
+        }));
+
+        // Waking before returning `Pending` must not be lost either.
+        let mut woke = false;
+        block_on(poll_fn(|cx| {
+            if woke {
+                Poll::Ready(())
+            } else {
+                woke = true;
+                cx.waker().wake();
+                Poll::Pending
+            }
+        }));
+    }
+
+    drop(tx);
+    helper.join().unwrap();
+}
+
+#[test]
+fn spawn_from_poll_fn() {
+    let mut pool = LocalPool::new();
+    let mut spawn = pool.spawner();
+    let (tx, mut rx) = oneshot::channel();
+    let mut tx = Some(tx);
+
+    let res = pool.run_until(poll_fn(move |cx| {
+        if let Some(tx) = tx.take() {
+            cx.spawner().spawn(lazy(move |_| tx.send(5).unwrap())).unwrap();
+        }
+        rx.poll_unpin(cx)
+    }), &mut spawn);
+
+    assert_eq!(res, Ok(5));
+}
+
+#[test]
+fn run_until_stalled_runs_spawned_descendants() {
+    let done = Rc::new(RefCell::new(Vec::new()));
+
+    let mut pool = LocalPool::new();
+    let mut spawn = pool.spawner();
+
+    // Each task records itself and spawns the next one through its own clone
+    // of the spawner, while the pool is busy running it.
+    fn spawn_chain(mut spawn: LocalSpawn, done: Rc<RefCell<Vec<usize>>>, depth: usize) {
+        let inner_spawn = spawn.clone();
+        spawn.spawn_local(lazy(move |_| {
+            done.borrow_mut().push(depth);
+            if depth < 3 {
+                spawn_chain(inner_spawn, done, depth + 1);
+            }
+        })).unwrap();
+    }
+    spawn_chain(spawn.clone(), done.clone(), 0);
+
+    // A task which never completes doesn't keep `run_until_stalled` from
+    // returning.
+    spawn.spawn_local(pending()).unwrap();
+
+    pool.run_until_stalled(&mut spawn);
+    assert_eq!(*done.borrow(), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn spawn_after_pool_dropped_fails() {
+    let pool = LocalPool::new();
+    let mut spawn = pool.spawner();
+    drop(pool);
+
+    assert!(spawn.status().unwrap_err().is_shutdown());
+    let err = spawn.spawn_local(lazy(|_| ())).unwrap_err();
+    assert!(err.kind.is_shutdown());
+    let err = spawn.spawn(lazy(|_| ())).unwrap_err();
+    assert!(err.kind.is_shutdown());
+}
@@ -0,0 +1,301 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::oneshot;
+use futures::executor::{block_on, ThreadPool};
+use futures::future::{self, poll_fn};
+use futures::task::{Poll, SpawnExt};
+use std::collections::HashSet;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+fn single_threaded_pool() -> ThreadPool {
+    ThreadPool::builder().pool_size(1).create().unwrap()
+}
+
+#[test]
+fn spawn_with_handle_resolves_to_output() {
+    let mut pool = single_threaded_pool();
+
+    let handle = pool.spawn_with_handle(future::lazy(|_| 1 + 2)).unwrap();
+
+    assert_eq!(block_on(handle), 3);
+}
+
+#[test]
+fn dropping_handle_cancels_task() {
+    let mut pool = single_threaded_pool();
+    let polls = Arc::new(AtomicUsize::new(0));
+
+    let handle = {
+        let polls = polls.clone();
+        pool.spawn_with_handle(poll_fn(move |cx| {
+            polls.fetch_add(1, Ordering::SeqCst);
+            cx.waker().wake();
+            Poll::<()>::Pending
+        })).unwrap()
+    };
+
+    // Wait for the task to start running before cancelling it.
+    while polls.load(Ordering::SeqCst) == 0 {
+        thread::yield_now();
+    }
+    drop(handle);
+
+    // The task observes the cancellation at its next yield point, after that
+    // it is never polled again.
+    thread::sleep(Duration::from_millis(50));
+    let after_cancel = polls.load(Ordering::SeqCst);
+    thread::sleep(Duration::from_millis(50));
+    assert_eq!(polls.load(Ordering::SeqCst), after_cancel);
+}
+
+#[test]
+fn forgotten_handle_keeps_task_running() {
+    let mut pool = single_threaded_pool();
+    let polls = Arc::new(AtomicUsize::new(0));
+
+    {
+        let polls = polls.clone();
+        pool.spawn_with_handle(poll_fn(move |cx| {
+            if polls.fetch_add(1, Ordering::SeqCst) == 10 {
+                Poll::Ready(())
+            } else {
+                cx.waker().wake();
+                Poll::Pending
+            }
+        })).unwrap().forget();
+    }
+
+    while polls.load(Ordering::SeqCst) <= 10 {
+        thread::yield_now();
+    }
+}
+
+#[test]
+fn panic_is_propagated_to_handle() {
+    let mut pool = single_threaded_pool();
+
+    let handle = pool.spawn_with_handle(future::lazy(|_| -> i32 {
+        panic!("task panicked")
+    })).unwrap();
+
+    let res = panic::catch_unwind(AssertUnwindSafe(|| block_on(handle)));
+    assert!(res.is_err());
+
+    // The worker thread survived the panic and keeps running tasks.
+    let handle = pool.spawn_with_handle(future::ready(5)).unwrap();
+    assert_eq!(block_on(handle), 5);
+}
+
+#[test]
+fn tasks_spawned_from_a_worker_are_stolen() {
+    let mut pool = ThreadPool::builder().pool_size(4).create().unwrap();
+    let threads = Arc::new(Mutex::new(HashSet::new()));
+    let (tx, rx) = mpsc::channel();
+
+    {
+        let threads = threads.clone();
+        pool.spawn(future::lazy(move |cx| {
+            // All of these end up on the local queue of the current worker,
+            // the other workers have to steal them.
+            for _ in 0..64 {
+                let threads = threads.clone();
+                let tx = tx.clone();
+                cx.spawner().spawn(future::lazy(move |_| {
+                    thread::sleep(Duration::from_millis(1));
+                    threads.lock().unwrap().insert(thread::current().id());
+                    tx.send(()).unwrap();
+                })).unwrap();
+            }
+        })).unwrap();
+    }
+
+    for _ in 0..64 {
+        rx.recv().unwrap();
+    }
+    assert!(threads.lock().unwrap().len() > 1);
+}
+
+#[test]
+fn rescheduling_task_does_not_starve_injector() {
+    let mut pool = single_threaded_pool();
+    let done = Arc::new(AtomicBool::new(false));
+
+    {
+        let done = done.clone();
+        pool.spawn(poll_fn(move |cx| {
+            if done.load(Ordering::SeqCst) {
+                Poll::Ready(())
+            } else {
+                cx.waker().wake();
+                Poll::Pending
+            }
+        })).unwrap();
+    }
+
+    let (tx, rx) = oneshot::channel();
+    {
+        let done = done.clone();
+        pool.spawn(future::lazy(move |_| {
+            done.store(true, Ordering::SeqCst);
+            tx.send(()).unwrap();
+        })).unwrap();
+    }
+
+    block_on(rx).unwrap();
+}
+
+#[test]
+fn workers_stop_once_queues_are_drained() {
+    const TASKS: usize = 100;
+
+    let (stop_tx, stop_rx) = mpsc::channel();
+    let stop_tx = Mutex::new(stop_tx);
+    let mut pool = ThreadPool::builder()
+        .pool_size(3)
+        .before_stop(move |idx| stop_tx.lock().unwrap().send(idx).unwrap())
+        .create()
+        .unwrap();
+    let cnt = Arc::new(AtomicUsize::new(0));
+
+    for _ in 0..TASKS {
+        let cnt = cnt.clone();
+        pool.spawn(future::lazy(move |cx| {
+            cx.spawner().spawn(future::lazy(move |_| {
+                cnt.fetch_add(1, Ordering::SeqCst);
+            })).unwrap();
+        })).unwrap();
+    }
+    drop(pool);
+
+    let mut stopped: Vec<usize> = stop_rx.iter().collect();
+    stopped.sort();
+    assert_eq!(stopped, vec![0, 1, 2]);
+    assert_eq!(cnt.load(Ordering::SeqCst), TASKS);
+}
+
+#[test]
+fn shutdown_runs_queued_tasks() {
+    const TASKS: usize = 50;
+
+    let mut pool = ThreadPool::builder().pool_size(2).create().unwrap();
+    let cnt = Arc::new(AtomicUsize::new(0));
+
+    for _ in 0..TASKS {
+        let cnt = cnt.clone();
+        pool.spawn(future::lazy(move |_| {
+            thread::sleep(Duration::from_millis(1));
+            cnt.fetch_add(1, Ordering::SeqCst);
+        })).unwrap();
+    }
+    pool.shutdown();
+
+    assert_eq!(cnt.load(Ordering::SeqCst), TASKS);
+}
+
+#[test]
+fn shutdown_rejects_spawns_from_tasks() {
+    let mut pool = single_threaded_pool();
+    let rejected = Arc::new(AtomicBool::new(false));
+
+    {
+        let rejected = rejected.clone();
+        pool.spawn(future::lazy(move |cx| {
+            // Wait for `shutdown` to be called on the main thread.
+            while cx.spawner().status().is_ok() {
+                thread::sleep(Duration::from_millis(1));
+            }
+            let err = cx.spawner().spawn(future::ready(())).unwrap_err();
+            rejected.store(err.kind.is_shutdown(), Ordering::SeqCst);
+        })).unwrap();
+    }
+    let mut spawner = pool.clone();
+    pool.shutdown();
+
+    assert!(rejected.load(Ordering::SeqCst));
+    assert!(spawner.spawn(future::ready(())).unwrap_err().kind.is_shutdown());
+}
+
+struct CountDrops(Arc<AtomicUsize>);
+
+impl Drop for CountDrops {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn shutdown_now_drops_queued_tasks() {
+    const TASKS: usize = 10;
+
+    let mut pool = single_threaded_pool();
+    let (release_tx, release_rx) = mpsc::channel::<()>();
+    let (started_tx, started_rx) = mpsc::channel();
+    let runs = Arc::new(AtomicUsize::new(0));
+    let drops = Arc::new(AtomicUsize::new(0));
+
+    // Keep the only worker busy so that the other tasks stay queued.
+    pool.spawn(future::lazy(move |_| {
+        started_tx.send(()).unwrap();
+        release_rx.recv().unwrap();
+    })).unwrap();
+    started_rx.recv().unwrap();
+
+    for _ in 0..TASKS {
+        let runs = runs.clone();
+        let on_drop = CountDrops(drops.clone());
+        pool.spawn(future::lazy(move |_| {
+            let _on_drop = on_drop;
+            runs.fetch_add(1, Ordering::SeqCst);
+        })).unwrap();
+    }
+
+    let releaser = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        release_tx.send(()).unwrap();
+    });
+    pool.shutdown_now();
+    releaser.join().unwrap();
+
+    assert_eq!(runs.load(Ordering::SeqCst), 0);
+    assert_eq!(drops.load(Ordering::SeqCst), TASKS);
+}
+
+#[test]
+fn drop_joins_worker_threads() {
+    let stopped = Arc::new(AtomicUsize::new(0));
+    let pool = {
+        let stopped = stopped.clone();
+        ThreadPool::builder()
+            .pool_size(3)
+            .before_stop(move |_| {
+                thread::sleep(Duration::from_millis(10));
+                stopped.fetch_add(1, Ordering::SeqCst);
+            })
+            .create()
+            .unwrap()
+    };
+    drop(pool);
+
+    assert_eq!(stopped.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn spawn_from_poll_fn() {
+    let mut pool = single_threaded_pool();
+    let (tx, rx) = mpsc::channel();
+
+    pool.spawn(poll_fn(move |cx| {
+        let tx = tx.clone();
+        cx.spawner().spawn(future::lazy(move |_| {
+            tx.send(thread::current().id()).unwrap();
+        })).unwrap();
+        Poll::Ready(())
+    })).unwrap();
+
+    // The spawned task runs on the pool, not on the spawning thread.
+    assert_ne!(rx.recv().unwrap(), thread::current().id());
+}
@@ -1,7 +1,8 @@
 //! Asynchronous I/O
 //!
-//! This crate contains the `AsyncRead` and `AsyncWrite` traits, the
-//! asynchronous analogs to `std::io::{Read, Write}`. The primary difference is
+//! This crate contains the `AsyncRead`, `AsyncBufRead`, `AsyncWrite` and
+//! `AsyncSeek` traits, the asynchronous analogs to
+//! `std::io::{Read, BufRead, Write, Seek}`. The primary difference is
 //! that these traits integrate with the asynchronous task system.
 
 #![no_std]
@@ -23,9 +24,9 @@ macro_rules! if_std {
 if_std! {
     use futures_core::task::{self, Poll};
     use std::boxed::Box;
-    use std::cmp;
     use std::io as StdIo;
     use std::ptr;
+    use std::vec::Vec;
 
     // Re-export IoVec for convenience
     pub use iovec::IoVec;
@@ -35,6 +36,7 @@ if_std! {
     pub use crate::StdIo::Error as Error;
     pub use crate::StdIo::ErrorKind as ErrorKind;
     pub use crate::StdIo::Result as Result;
+    pub use crate::StdIo::SeekFrom as SeekFrom;
 
     /// A type used to conditionally initialize buffers passed to `AsyncRead`
     /// methods, modeled after `std`.
@@ -152,6 +154,49 @@ if_std! {
         }
     }
 
+    /// Read bytes asynchronously from a reader with an internal buffer.
+    ///
+    /// This trait is analogous to the `std::io::BufRead` trait, but integrates
+    /// with the asynchronous task system. In particular, the `poll_fill_buf`
+    /// method, unlike `BufRead::fill_buf`, will automatically queue the current
+    /// task for wakeup and return if data is not yet available, rather than
+    /// blocking the calling thread.
+    pub trait AsyncBufRead: AsyncRead {
+        /// Attempt to return the contents of the internal buffer, filling it
+        /// with more data from the inner reader if it is empty.
+        ///
+        /// On success, returns `Ok(Async::Ready(buf))`. An empty buffer means
+        /// that the reader has reached EOF.
+        ///
+        /// If no data is available for reading, the method returns
+        /// `Ok(Async::Pending)` and arranges for the current task (via
+        /// `cx.waker()`) to receive a notification when the object becomes
+        /// readable or is closed.
+        ///
+        /// This function is a lower-level call. It needs to be paired with the
+        /// [`consume`](AsyncBufRead::consume) method to function properly.
+        /// When calling this method, none of the contents will be "read" in
+        /// the sense that later calling `poll_read` may return the same
+        /// contents.
+        ///
+        /// # Implementation
+        ///
+        /// This function may not return errors of kind `WouldBlock` or
+        /// `Interrupted`.  Implementations must convert `WouldBlock` into
+        /// `Async::Pending` and either internally retry or convert
+        /// `Interrupted` into another error kind.
+        fn poll_fill_buf<'a>(&'a mut self, cx: &mut task::Context)
+            -> Poll<Result<&'a [u8]>>;
+
+        /// Tells this buffer that `amt` bytes have been consumed from the
+        /// buffer, so they should no longer be returned in calls to
+        /// `poll_read` or `poll_fill_buf`.
+        ///
+        /// The `amt` must be `<=` the number of bytes in the buffer returned
+        /// by `poll_fill_buf`.
+        fn consume(&mut self, amt: usize);
+    }
+
     /// Write bytes asynchronously.
     ///
     /// This trait is analogous to the `std::io::Write` trait, but integrates
@@ -248,6 +293,44 @@ if_std! {
         fn poll_close(&mut self, cx: &mut task::Context) -> Poll<Result<()>>;
     }
 
+    /// Seek bytes asynchronously.
+    ///
+    /// This trait is analogous to the `std::io::Seek` trait, but integrates
+    /// with the asynchronous task system. In particular, the `poll_seek`
+    /// method, unlike `Seek::seek`, will automatically queue the current task
+    /// for wakeup and return if the object is not yet ready to seek, rather
+    /// than blocking the calling thread.
+    pub trait AsyncSeek {
+        /// Attempt to seek to an offset, in bytes, in a stream.
+        ///
+        /// A seek beyond the end of a stream is allowed, but behavior is defined
+        /// by the implementation.
+        ///
+        /// On success, returns `Ok(Async::Ready(new_position))`, the new
+        /// position from the start of the stream.
+        ///
+        /// If the object is not ready to seek, the method returns
+        /// `Ok(Async::Pending)` and arranges for the current task (via
+        /// `cx.waker()`) to receive a notification when it becomes ready.
+        /// A seek that returned `Pending` has not moved the position, it is
+        /// retried by calling `poll_seek` again with the same `pos`, which is
+        /// also correct for relative `SeekFrom::Current` and `SeekFrom::End`
+        /// positions.
+        ///
+        /// # Errors
+        ///
+        /// Seeking to a negative offset is considered an error.
+        ///
+        /// # Implementation
+        ///
+        /// This function may not return errors of kind `WouldBlock` or
+        /// `Interrupted`.  Implementations must convert `WouldBlock` into
+        /// `Async::Pending` and either internally retry or convert
+        /// `Interrupted` into another error kind.
+        fn poll_seek(&mut self, cx: &mut task::Context, pos: SeekFrom)
+            -> Poll<Result<u64>>;
+    }
+
     macro_rules! deref_async_read {
         () => {
             unsafe fn initializer(&self) -> Initializer {
@@ -304,6 +387,50 @@ if_std! {
         unsafe_delegate_async_read_to_stdio!();
     }
 
+    macro_rules! deref_async_buf_read {
+        () => {
+            fn poll_fill_buf<'a>(&'a mut self, cx: &mut task::Context)
+                -> Poll<Result<&'a [u8]>>
+            {
+                (**self).poll_fill_buf(cx)
+            }
+
+            fn consume(&mut self, amt: usize) {
+                (**self).consume(amt)
+            }
+        }
+    }
+
+    impl<T: ?Sized + AsyncBufRead> AsyncBufRead for Box<T> {
+        deref_async_buf_read!();
+    }
+
+    impl<'a, T: ?Sized + AsyncBufRead> AsyncBufRead for &'a mut T {
+        deref_async_buf_read!();
+    }
+
+    macro_rules! delegate_async_buf_read_to_stdio {
+        () => {
+            fn poll_fill_buf<'a>(&'a mut self, _: &mut task::Context)
+                -> Poll<Result<&'a [u8]>>
+            {
+                Poll::Ready(StdIo::BufRead::fill_buf(self))
+            }
+
+            fn consume(&mut self, amt: usize) {
+                StdIo::BufRead::consume(self, amt)
+            }
+        }
+    }
+
+    impl<'a> AsyncBufRead for &'a [u8] {
+        delegate_async_buf_read_to_stdio!();
+    }
+
+    impl<T: AsRef<[u8]>> AsyncBufRead for StdIo::Cursor<T> {
+        delegate_async_buf_read_to_stdio!();
+    }
+
     macro_rules! deref_async_write {
         () => {
             fn poll_write(&mut self, cx: &mut task::Context, buf: &[u8])
@@ -354,34 +481,63 @@ if_std! {
         }
     }
 
-    impl<T: AsMut<[u8]>> AsyncWrite for StdIo::Cursor<T> {
-        fn poll_write(
-            &mut self,
-            _: &mut task::Context,
-            buf: &[u8],
-        ) -> Poll<Result<usize>> {
-            let position = self.position();
-            let result = {
-                let out = self.get_mut().as_mut();
-                let pos = cmp::min(out.len() as u64, position) as usize;
-                StdIo::Write::write(&mut &mut out[pos..], buf)
-            };
-            if let Ok(offset) = result {
-                self.set_position(position + offset as u64);
+    impl<'a> AsyncWrite for StdIo::Cursor<&'a mut [u8]> {
+        delegate_async_write_to_stdio!();
+    }
+
+    impl AsyncWrite for StdIo::Cursor<Vec<u8>> {
+        delegate_async_write_to_stdio!();
+    }
+
+    impl<'a> AsyncWrite for StdIo::Cursor<&'a mut Vec<u8>> {
+        delegate_async_write_to_stdio!();
+    }
+
+    impl AsyncWrite for StdIo::Cursor<Box<[u8]>> {
+        delegate_async_write_to_stdio!();
+    }
+
+    impl<'a> AsyncWrite for &'a mut [u8] {
+        delegate_async_write_to_stdio!();
+    }
+
+    impl AsyncWrite for Vec<u8> {
+        delegate_async_write_to_stdio!();
+    }
+
+    impl AsyncWrite for StdIo::Sink {
+        delegate_async_write_to_stdio!();
+    }
+
+    macro_rules! deref_async_seek {
+        () => {
+            fn poll_seek(&mut self, cx: &mut task::Context, pos: SeekFrom)
+                -> Poll<Result<u64>>
+            {
+                (**self).poll_seek(cx, pos)
             }
-            Poll::Ready(result)
         }
+    }
 
-        fn poll_flush(&mut self, _: &mut task::Context) -> Poll<Result<()>> {
-            Poll::Ready(StdIo::Write::flush(&mut self.get_mut().as_mut()))
-        }
+    impl<T: ?Sized + AsyncSeek> AsyncSeek for Box<T> {
+        deref_async_seek!();
+    }
+
+    impl<'a, T: ?Sized + AsyncSeek> AsyncSeek for &'a mut T {
+        deref_async_seek!();
+    }
 
-        fn poll_close(&mut self, cx: &mut task::Context) -> Poll<Result<()>> {
-            self.poll_flush(cx)
+    macro_rules! delegate_async_seek_to_stdio {
+        () => {
+            fn poll_seek(&mut self, _: &mut task::Context, pos: SeekFrom)
+                -> Poll<Result<u64>>
+            {
+                Poll::Ready(StdIo::Seek::seek(self, pos))
+            }
         }
     }
 
-    impl AsyncWrite for StdIo::Sink {
-        delegate_async_write_to_stdio!();
+    impl<T: AsRef<[u8]>> AsyncSeek for StdIo::Cursor<T> {
+        delegate_async_seek_to_stdio!();
     }
 }
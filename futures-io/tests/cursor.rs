@@ -3,17 +3,98 @@
 use assert_matches::assert_matches;
 use futures::Poll;
 use futures::future::lazy;
-use futures::io::AsyncWrite;
+use futures::io::{AsyncRead, AsyncWrite};
 use std::io::Cursor;
 
 #[test]
-fn cursor_asyncwrite_asmut() {
-    let mut cursor = Cursor::new([0; 5]);
+fn cursor_asyncwrite_slice() {
+    let mut buf = [0; 5];
+    {
+        let mut cursor = Cursor::new(&mut buf[..]);
+        futures::executor::block_on(lazy(|ctx| {
+            assert_matches!(cursor.poll_write(ctx, &[1, 2]), Poll::Ready(Ok(2)));
+            assert_matches!(cursor.poll_write(ctx, &[3, 4]), Poll::Ready(Ok(2)));
+            assert_matches!(cursor.poll_write(ctx, &[5, 6]), Poll::Ready(Ok(1)));
+            assert_matches!(cursor.poll_write(ctx, &[6, 7]), Poll::Ready(Ok(0)));
+        }));
+        assert_eq!(cursor.position(), 5);
+    }
+    assert_eq!(buf, [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn cursor_asyncwrite_vec() {
+    let mut cursor = Cursor::new(vec![0; 3]);
     futures::executor::block_on(lazy(|ctx| {
         assert_matches!(cursor.poll_write(ctx, &[1, 2]), Poll::Ready(Ok(2)));
+        // Writing past the end grows the `Vec`, so it never runs out of space.
         assert_matches!(cursor.poll_write(ctx, &[3, 4]), Poll::Ready(Ok(2)));
-        assert_matches!(cursor.poll_write(ctx, &[5, 6]), Poll::Ready(Ok(1)));
-        assert_matches!(cursor.poll_write(ctx, &[6, 7]), Poll::Ready(Ok(0)));
+        assert_matches!(cursor.poll_close(ctx), Poll::Ready(Ok(())));
+    }));
+    assert_eq!(cursor.into_inner(), [1, 2, 3, 4]);
+}
+
+#[test]
+fn cursor_asyncwrite_vec_ref() {
+    let mut vec = vec![0; 2];
+    {
+        let mut cursor = Cursor::new(&mut vec);
+        cursor.set_position(1);
+        futures::executor::block_on(lazy(|ctx| {
+            // Like `Cursor<Vec<u8>>`, writing past the end grows the `Vec`.
+            assert_matches!(cursor.poll_write(ctx, &[1, 2, 3]), Poll::Ready(Ok(3)));
+            assert_matches!(cursor.poll_flush(ctx), Poll::Ready(Ok(())));
+        }));
+        assert_eq!(cursor.position(), 4);
+    }
+    assert_eq!(vec, [0, 1, 2, 3]);
+}
+
+#[test]
+fn cursor_asyncwrite_box() {
+    let mut cursor = Cursor::new(vec![0; 3].into_boxed_slice());
+    futures::executor::block_on(lazy(|ctx| {
+        // A boxed slice can't grow, so the write is cut short.
+        assert_matches!(cursor.poll_write(ctx, &[1, 2, 3, 4]), Poll::Ready(Ok(3)));
+        assert_matches!(cursor.poll_write(ctx, &[5]), Poll::Ready(Ok(0)));
+    }));
+    assert_eq!(&*cursor.into_inner(), [1, 2, 3]);
+}
+
+#[test]
+fn cursor_asyncread() {
+    let mut cursor = Cursor::new(vec![1, 2, 3]);
+    cursor.set_position(1);
+    let mut buf = [0; 4];
+    futures::executor::block_on(lazy(|ctx| {
+        assert_matches!(cursor.poll_read(ctx, &mut buf), Poll::Ready(Ok(2)));
+        assert_matches!(cursor.poll_read(ctx, &mut buf), Poll::Ready(Ok(0)));
+    }));
+    assert_eq!(&buf[..2], [2, 3]);
+}
+
+#[test]
+fn slice_asyncwrite() {
+    let mut buf = [0; 3];
+    {
+        let mut slice = &mut buf[..];
+        futures::executor::block_on(lazy(|ctx| {
+            assert_matches!(slice.poll_write(ctx, &[1, 2]), Poll::Ready(Ok(2)));
+            assert_matches!(slice.poll_write(ctx, &[3, 4]), Poll::Ready(Ok(1)));
+            // An exhausted slice accepts no more bytes, like in std.
+            assert_matches!(slice.poll_write(ctx, &[5]), Poll::Ready(Ok(0)));
+        }));
+        assert!(slice.is_empty());
+    }
+    assert_eq!(buf, [1, 2, 3]);
+}
+
+#[test]
+fn vec_asyncwrite() {
+    let mut vec = vec![1];
+    futures::executor::block_on(lazy(|ctx| {
+        assert_matches!(vec.poll_write(ctx, &[2, 3]), Poll::Ready(Ok(2)));
+        assert_matches!(vec.poll_flush(ctx), Poll::Ready(Ok(())));
     }));
-    assert_eq!(cursor.into_inner(), [1, 2, 3, 4, 5]);
+    assert_eq!(vec, [1, 2, 3]);
 }
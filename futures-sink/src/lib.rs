@@ -8,6 +8,7 @@
 #![doc(html_root_url = "https://rust-lang-nursery.github.io/futures-api-docs/0.3.0-alpha.3/futures_sink")]
 
 #![feature(pin, arbitrary_self_types, futures_api)]
+#![cfg_attr(feature = "alloc", feature(alloc))]
 
 macro_rules! if_std {
     ($($i:item)*) => ($(
@@ -16,6 +17,16 @@ macro_rules! if_std {
     )*)
 }
 
+macro_rules! if_alloc {
+    ($($i:item)*) => ($(
+        #[cfg(feature = "alloc")]
+        $i
+    )*)
+}
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use futures_core::task::{self, Poll};
 use core::marker::Unpin;
 use core::mem::PinMut;
@@ -161,15 +172,17 @@ impl<'a, S: ?Sized + Sink> Sink for PinMut<'a, S> {
 
 if_std! {
     mod channel_impls;
+}
 
-    /// The error type for `Vec` and `VecDequeue` when used as `Sink`s.
-    /// Values of this type can never be created.
-    #[derive(Copy, Clone, Debug)]
-    pub enum VecSinkError {}
+if_alloc! {
+    use alloc::boxed::Box;
+    use alloc::collections::VecDeque;
+    use alloc::vec::Vec;
+    use futures_core::never::Never;
 
-    impl<T> Sink for ::std::vec::Vec<T> {
+    impl<T> Sink for Vec<T> {
         type SinkItem = T;
-        type SinkError = VecSinkError;
+        type SinkError = Never;
 
         fn poll_ready(self: PinMut<Self>, _: &mut task::Context) -> Poll<Result<(), Self::SinkError>> {
             Poll::Ready(Ok(()))
@@ -190,9 +203,9 @@ if_std! {
         }
     }
 
-    impl<T> Sink for ::std::collections::VecDeque<T> {
+    impl<T> Sink for VecDeque<T> {
         type SinkItem = T;
-        type SinkError = VecSinkError;
+        type SinkError = Never;
 
         fn poll_ready(self: PinMut<Self>, _: &mut task::Context) -> Poll<Result<(), Self::SinkError>> {
             Poll::Ready(Ok(()))
@@ -213,7 +226,7 @@ if_std! {
         }
     }
 
-    impl<S: ?Sized + Sink + Unpin> Sink for ::std::boxed::Box<S> {
+    impl<S: ?Sized + Sink + Unpin> Sink for Box<S> {
         type SinkItem = S::SinkItem;
         type SinkError = S::SinkError;
 
@@ -234,51 +247,3 @@ if_std! {
         }
     }
 }
-
-#[cfg(feature = "either")]
-use either::Either;
-#[cfg(feature = "either")]
-impl<A, B> Sink for Either<A, B>
-    where A: Sink,
-          B: Sink<SinkItem=<A as Sink>::SinkItem,
-                  SinkError=<A as Sink>::SinkError>
-{
-    type SinkItem = <A as Sink>::SinkItem;
-    type SinkError = <A as Sink>::SinkError;
-
-    fn poll_ready(self: PinMut<Self>, cx: &mut task::Context) -> Poll<Result<(), Self::SinkError>> {
-        unsafe {
-            match PinMut::get_mut_unchecked(self) {
-                Either::Left(x) => PinMut::new_unchecked(x).poll_ready(cx),
-                Either::Right(x) => PinMut::new_unchecked(x).poll_ready(cx),
-            }
-        }
-    }
-
-    fn start_send(self: PinMut<Self>, item: Self::SinkItem) -> Result<(), Self::SinkError> {
-        unsafe {
-            match PinMut::get_mut_unchecked(self) {
-                Either::Left(x) => PinMut::new_unchecked(x).start_send(item),
-                Either::Right(x) => PinMut::new_unchecked(x).start_send(item),
-            }
-        }
-    }
-
-    fn poll_flush(self: PinMut<Self>, cx: &mut task::Context) -> Poll<Result<(), Self::SinkError>> {
-        unsafe {
-            match PinMut::get_mut_unchecked(self) {
-                Either::Left(x) => PinMut::new_unchecked(x).poll_flush(cx),
-                Either::Right(x) => PinMut::new_unchecked(x).poll_flush(cx),
-            }
-        }
-    }
-
-    fn poll_close(self: PinMut<Self>, cx: &mut task::Context) -> Poll<Result<(), Self::SinkError>> {
-        unsafe {
-            match PinMut::get_mut_unchecked(self) {
-                Either::Left(x) => PinMut::new_unchecked(x).poll_close(cx),
-                Either::Right(x) => PinMut::new_unchecked(x).poll_close(cx),
-            }
-        }
-    }
-}
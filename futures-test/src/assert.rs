@@ -7,6 +7,12 @@ pub fn assert_is_unpin_stream<S: Stream + Unpin>(_: &mut S) {}
 /// Assert that the next poll to the provided stream will return
 /// [`Poll::Pending`](futures_core::task::Poll::Pending).
 ///
+/// The stream is polled once with a context that ignores wakeups and spawns.
+/// It can be any [`Unpin`](std::marker::Unpin) stream, including `&mut`
+/// references and streams pinned as a [`PinMut`](std::mem::PinMut). If the
+/// stream is ready, the assertion fails showing the item, which therefore has
+/// to implement `Debug`.
+///
 /// # Examples
 ///
 /// ```
@@ -35,8 +41,11 @@ macro_rules! assert_stream_pending {
         let poll = $crate::futures_core_reexport::stream::Stream::poll_next(
             stream, cx,
         );
-        if poll.is_ready() {
-            panic!("assertion failed: stream is not pending");
+        if let $crate::futures_core_reexport::task::Poll::Ready(item) = poll {
+            panic!(
+                "assertion failed: expected stream to be pending but it was ready with {:?}",
+                item
+            );
         }
     }};
 }
@@ -71,7 +80,7 @@ macro_rules! assert_stream_next {
         let cx = &mut $crate::task::no_spawn_context();
         match $crate::futures_core_reexport::stream::Stream::poll_next(stream, cx) {
             $crate::futures_core_reexport::task::Poll::Ready(Some(x)) => {
-                assert_eq!(x, $item);
+                assert_eq!(x, $item, "stream provided an unexpected item");
             }
             $crate::futures_core_reexport::task::Poll::Ready(None) => {
                 panic!("assertion failed: expected stream to provide item but stream is at its end");
@@ -113,8 +122,11 @@ macro_rules! assert_stream_done {
         let stream = $crate::std_reexport::mem::PinMut::new(stream);
         let cx = &mut $crate::task::no_spawn_context();
         match $crate::futures_core_reexport::stream::Stream::poll_next(stream, cx) {
-            $crate::futures_core_reexport::task::Poll::Ready(Some(_)) => {
-                panic!("assertion failed: expected stream to be done but had more elements");
+            $crate::futures_core_reexport::task::Poll::Ready(Some(x)) => {
+                panic!(
+                    "assertion failed: expected stream to be done but it provided {:?}",
+                    x
+                );
             }
             $crate::futures_core_reexport::task::Poll::Ready(None) => {}
             $crate::futures_core_reexport::task::Poll::Pending => {
@@ -1,8 +1,10 @@
 //! Additional combinators for testing futures.
 
 mod pending_once;
+pub use self::pending_once::PendingOnce;
+
+pub use crate::interleave_pending::InterleavePending;
 
-use self::pending_once::PendingOnce;
 use futures_core::future::Future;
 use futures_executor;
 use std::thread;
@@ -37,6 +39,37 @@ pub trait FutureTestExt: Future {
         pending_once::PendingOnce::new(self)
     }
 
+    /// Introduces an extra [`Poll::Pending`](futures_core::task::Poll::Pending)
+    /// in between each poll of the given future. Unlike
+    /// [`pending_once`](FutureTestExt::pending_once) this doesn't stop after
+    /// the first poll, so combinators which poll the future again after it
+    /// was pending are exercised too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, futures_api, pin)]
+    /// use futures::task::Poll;
+    /// use futures::future::FutureExt;
+    /// use futures_test::task;
+    /// use futures_test::future::FutureTestExt;
+    /// use pin_utils::pin_mut;
+    ///
+    /// let future = (async { 5 }).interleave_pending();
+    /// pin_mut!(future);
+    ///
+    /// let cx = &mut task::no_spawn_context();
+    ///
+    /// assert_eq!(future.poll_unpin(cx), Poll::Pending);
+    /// assert_eq!(future.poll_unpin(cx), Poll::Ready(5));
+    /// ```
+    fn interleave_pending(self) -> InterleavePending<Self>
+    where
+        Self: Sized,
+    {
+        InterleavePending::new(self)
+    }
+
     /// Runs this future on a dedicated executor running in a background thread.
     ///
     /// # Examples
@@ -0,0 +1,216 @@
+use futures_core::future::Future;
+use futures_core::stream::Stream;
+use futures_core::task::{self, Poll};
+use futures_io::{self as io, AsyncBufRead, AsyncRead, AsyncWrite, Initializer, IoVec};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use std::marker::Unpin;
+use std::mem::PinMut;
+
+/// Wrapper that interleaves [`Poll::Pending`] in calls to poll.
+///
+/// Before every result of the wrapped future, stream, sink, reader or writer
+/// is passed through, one [`Poll::Pending`] is returned and the task is woken
+/// up right away. Results that are pending anyway are passed through as is.
+///
+/// This is created by the
+/// [`FutureTestExt::interleave_pending`](crate::future::FutureTestExt::interleave_pending),
+/// [`StreamTestExt::interleave_pending`](crate::stream::StreamTestExt::interleave_pending),
+/// [`SinkTestExt::interleave_pending_sink`](crate::sink::SinkTestExt::interleave_pending_sink),
+/// [`AsyncReadTestExt::interleave_pending`](crate::io::AsyncReadTestExt::interleave_pending)
+/// and
+/// [`AsyncWriteTestExt::interleave_pending_write`](crate::io::AsyncWriteTestExt::interleave_pending_write)
+/// methods.
+#[derive(Debug)]
+pub struct InterleavePending<T> {
+    inner: T,
+    pended: bool,
+}
+
+impl<T: Unpin> Unpin for InterleavePending<T> {}
+
+impl<T> InterleavePending<T> {
+    unsafe_pinned!(inner: T);
+    unsafe_unpinned!(pended: bool);
+
+    pub(crate) fn new(inner: T) -> Self {
+        InterleavePending {
+            inner,
+            pended: false,
+        }
+    }
+
+    /// Acquires a reference to the underlying value that this adaptor is
+    /// wrapping.
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Acquires a mutable reference to the underlying value that this adaptor
+    /// is wrapping.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Acquires a pinned mutable reference to the underlying value that this
+    /// adaptor is wrapping.
+    #[allow(needless_lifetimes)] // https://github.com/rust-lang/rust/issues/52675
+    pub fn get_pin_mut<'a>(self: PinMut<'a, Self>) -> PinMut<'a, T> {
+        unsafe { PinMut::map_unchecked(self, |x| &mut x.inner) }
+    }
+
+    /// Consumes this adaptor returning the underlying value.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    fn poll_with<R>(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+        f: impl FnOnce(PinMut<T>, &mut task::Context) -> Poll<R>,
+    ) -> Poll<R> {
+        if *self.pended() {
+            let next = f(self.inner(), cx);
+            if next.is_ready() {
+                *self.pended() = false;
+            }
+            next
+        } else {
+            cx.waker().wake();
+            *self.pended() = true;
+            Poll::Pending
+        }
+    }
+
+    fn poll_with_mut<R>(
+        &mut self,
+        cx: &mut task::Context,
+        f: impl FnOnce(&mut T, &mut task::Context) -> Poll<R>,
+    ) -> Poll<R> {
+        if self.pended {
+            let next = f(&mut self.inner, cx);
+            if next.is_ready() {
+                self.pended = false;
+            }
+            next
+        } else {
+            cx.waker().wake();
+            self.pended = true;
+            Poll::Pending
+        }
+    }
+}
+
+impl<Fut: Future> Future for InterleavePending<Fut> {
+    type Output = Fut::Output;
+
+    fn poll(self: PinMut<Self>, cx: &mut task::Context) -> Poll<Self::Output> {
+        self.poll_with(cx, Fut::poll)
+    }
+}
+
+impl<St: Stream> Stream for InterleavePending<St> {
+    type Item = St::Item;
+
+    fn poll_next(
+        self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Option<Self::Item>> {
+        self.poll_with(cx, St::poll_next)
+    }
+}
+
+impl<Si: Sink> Sink for InterleavePending<Si> {
+    type SinkItem = Si::SinkItem;
+    type SinkError = Si::SinkError;
+
+    fn poll_ready(
+        self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.poll_with(cx, Si::poll_ready)
+    }
+
+    fn start_send(
+        mut self: PinMut<Self>,
+        item: Self::SinkItem,
+    ) -> Result<(), Self::SinkError> {
+        self.inner().start_send(item)
+    }
+
+    fn poll_flush(
+        self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.poll_with(cx, Si::poll_flush)
+    }
+
+    fn poll_close(
+        self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.poll_with(cx, Si::poll_close)
+    }
+}
+
+impl<R: AsyncRead> AsyncRead for InterleavePending<R> {
+    unsafe fn initializer(&self) -> Initializer {
+        self.inner.initializer()
+    }
+
+    fn poll_read(&mut self, cx: &mut task::Context, buf: &mut [u8])
+        -> Poll<io::Result<usize>>
+    {
+        self.poll_with_mut(cx, |reader, cx| reader.poll_read(cx, buf))
+    }
+
+    fn poll_vectored_read(&mut self, cx: &mut task::Context, vec: &mut [&mut IoVec])
+        -> Poll<io::Result<usize>>
+    {
+        self.poll_with_mut(cx, |reader, cx| reader.poll_vectored_read(cx, vec))
+    }
+}
+
+impl<R: AsyncBufRead> AsyncBufRead for InterleavePending<R> {
+    fn poll_fill_buf<'a>(&'a mut self, cx: &mut task::Context)
+        -> Poll<io::Result<&'a [u8]>>
+    {
+        if self.pended {
+            let next = self.inner.poll_fill_buf(cx);
+            if next.is_ready() {
+                self.pended = false;
+            }
+            next
+        } else {
+            cx.waker().wake();
+            self.pended = true;
+            Poll::Pending
+        }
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.inner.consume(amt)
+    }
+}
+
+impl<W: AsyncWrite> AsyncWrite for InterleavePending<W> {
+    fn poll_write(&mut self, cx: &mut task::Context, buf: &[u8])
+        -> Poll<io::Result<usize>>
+    {
+        self.poll_with_mut(cx, |writer, cx| writer.poll_write(cx, buf))
+    }
+
+    fn poll_vectored_write(&mut self, cx: &mut task::Context, vec: &[&IoVec])
+        -> Poll<io::Result<usize>>
+    {
+        self.poll_with_mut(cx, |writer, cx| writer.poll_vectored_write(cx, vec))
+    }
+
+    fn poll_flush(&mut self, cx: &mut task::Context) -> Poll<io::Result<()>> {
+        self.poll_with_mut(cx, W::poll_flush)
+    }
+
+    fn poll_close(&mut self, cx: &mut task::Context) -> Poll<io::Result<()>> {
+        self.poll_with_mut(cx, W::poll_close)
+    }
+}
@@ -0,0 +1,114 @@
+use futures_core::task::{self, Poll};
+use futures_io::{AsyncBufRead, AsyncRead};
+use std::io;
+
+/// A reader that reads from an in-memory buffer, for testing code built on
+/// [`AsyncRead`] and [`AsyncBufRead`].
+///
+/// The reader is always ready. By default every read hands out as many bytes
+/// as fit, [`with_chunk_size`](MockReader::with_chunk_size) limits that to
+/// simulate short reads and [`fail_on_read`](MockReader::fail_on_read) makes
+/// one read fail. Combine it with
+/// [`interleave_pending`](crate::io::AsyncReadTestExt::interleave_pending)
+/// to make it pending in between reads.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(futures_api)]
+/// use futures::executor::block_on;
+/// use futures::io::AsyncReadExt;
+/// use futures_test::io::{AsyncReadTestExt, MockReader};
+///
+/// let mut reader = MockReader::new(b"hello")
+///     .with_chunk_size(2)
+///     .interleave_pending();
+///
+/// let mut buf = [0; 4];
+/// assert_eq!(block_on(reader.read(&mut buf)).unwrap(), 2);
+/// assert_eq!(&buf[..2], b"he");
+/// assert_eq!(reader.get_ref().remaining(), b"llo");
+/// ```
+#[derive(Debug)]
+pub struct MockReader {
+    data: Vec<u8>,
+    pos: usize,
+    chunk_size: usize,
+    fail_on_read: Option<usize>,
+    reads: usize,
+}
+
+impl MockReader {
+    /// Create a new reader which yields a copy of `data`, followed by EOF.
+    pub fn new(data: &[u8]) -> MockReader {
+        MockReader {
+            data: data.to_vec(),
+            pos: 0,
+            chunk_size: usize::max_value(),
+            fail_on_read: None,
+            reads: 0,
+        }
+    }
+
+    /// Limits every read, and every buffer returned by
+    /// [`poll_fill_buf`](futures_io::AsyncBufRead::poll_fill_buf), to at
+    /// most `chunk_size` bytes.
+    pub fn with_chunk_size(self, chunk_size: usize) -> MockReader {
+        MockReader { chunk_size, ..self }
+    }
+
+    /// Makes the `n`th call to [`poll_read`](futures_io::AsyncRead::poll_read),
+    /// counting from 1, fail with an [`io::ErrorKind::Other`] error instead
+    /// of reading anything. Later reads continue where the reader left off.
+    pub fn fail_on_read(self, n: usize) -> MockReader {
+        MockReader { fail_on_read: Some(n), ..self }
+    }
+
+    /// The number of calls to [`poll_read`](futures_io::AsyncRead::poll_read)
+    /// so far, including a failed one.
+    pub fn reads(&self) -> usize {
+        self.reads
+    }
+
+    /// The data that hasn't been read yet.
+    pub fn remaining(&self) -> &[u8] {
+        &self.data[self.pos..]
+    }
+
+    fn available(&self) -> &[u8] {
+        let remaining = self.remaining();
+        &remaining[..self.chunk_size.min(remaining.len())]
+    }
+}
+
+impl AsyncRead for MockReader {
+    fn poll_read(&mut self, _: &mut task::Context, buf: &mut [u8])
+        -> Poll<io::Result<usize>>
+    {
+        self.reads += 1;
+        if self.fail_on_read == Some(self.reads) {
+            return Poll::Ready(Err(io::Error::new(io::ErrorKind::Other, "mock read error")));
+        }
+        let n = {
+            let available = self.available();
+            let n = available.len().min(buf.len());
+            buf[..n].copy_from_slice(&available[..n]);
+            n
+        };
+        self.pos += n;
+        Poll::Ready(Ok(n))
+    }
+}
+
+impl AsyncBufRead for MockReader {
+    fn poll_fill_buf<'a>(&'a mut self, _: &mut task::Context)
+        -> Poll<io::Result<&'a [u8]>>
+    {
+        Poll::Ready(Ok(self.available()))
+    }
+
+    fn consume(&mut self, amt: usize) {
+        assert!(amt <= self.available().len(), "consumed more than was filled");
+        self.pos += amt;
+    }
+}
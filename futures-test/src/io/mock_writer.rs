@@ -0,0 +1,159 @@
+use futures_core::task::{self, Poll};
+use futures_io::{AsyncWrite, IoVec};
+use std::io;
+
+/// A writer that writes into an in-memory buffer, for testing code built on
+/// [`AsyncWrite`].
+///
+/// The writer is always ready. By default every write accepts all of the
+/// given data, [`with_chunk_size`](MockWriter::with_chunk_size) and
+/// [`with_write_limits`](MockWriter::with_write_limits) limit that to
+/// simulate short writes and [`fail_on_write`](MockWriter::fail_on_write)
+/// makes one write fail. Combine it with
+/// [`interleave_pending_write`](crate::io::AsyncWriteTestExt::interleave_pending_write)
+/// to make it pending in between operations.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(futures_api)]
+/// use futures::executor::block_on;
+/// use futures::io::AsyncWriteExt;
+/// use futures_test::io::{AsyncWriteTestExt, MockWriter};
+///
+/// let mut writer = MockWriter::new()
+///     .with_chunk_size(2)
+///     .interleave_pending_write();
+///
+/// block_on(writer.write_all(b"hello")).unwrap();
+/// assert_eq!(writer.get_ref().written(), b"hello");
+/// assert_eq!(writer.get_ref().writes(), 3);
+/// ```
+#[derive(Debug)]
+pub struct MockWriter {
+    data: Vec<u8>,
+    write_limits: Vec<usize>,
+    fail_on_write: Option<usize>,
+    writes: usize,
+    flushes: usize,
+    closed: bool,
+}
+
+impl MockWriter {
+    /// Create a new, empty writer.
+    pub fn new() -> MockWriter {
+        MockWriter {
+            data: Vec::new(),
+            write_limits: vec![usize::max_value()],
+            fail_on_write: None,
+            writes: 0,
+            flushes: 0,
+            closed: false,
+        }
+    }
+
+    /// Limits every write to at most `chunk_size` bytes.
+    pub fn with_chunk_size(self, chunk_size: usize) -> MockWriter {
+        self.with_write_limits(vec![chunk_size])
+    }
+
+    /// Limits the writes to at most the given number of bytes, going through
+    /// `limits` in a cycle, one entry per call to
+    /// [`poll_write`](futures_io::AsyncWrite::poll_write) or
+    /// [`poll_vectored_write`](futures_io::AsyncWrite::poll_vectored_write).
+    ///
+    /// A limit of `0` makes that write return `Ok(0)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limits` is empty.
+    pub fn with_write_limits(self, limits: Vec<usize>) -> MockWriter {
+        assert!(!limits.is_empty(), "at least one write limit is required");
+        MockWriter { write_limits: limits, ..self }
+    }
+
+    /// Makes the `n`th call to
+    /// [`poll_write`](futures_io::AsyncWrite::poll_write) or
+    /// [`poll_vectored_write`](futures_io::AsyncWrite::poll_vectored_write),
+    /// counting from 1, fail with an [`io::ErrorKind::Other`] error instead
+    /// of writing anything.
+    pub fn fail_on_write(self, n: usize) -> MockWriter {
+        MockWriter { fail_on_write: Some(n), ..self }
+    }
+
+    /// The data written so far.
+    pub fn written(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// The number of calls to
+    /// [`poll_write`](futures_io::AsyncWrite::poll_write) and
+    /// [`poll_vectored_write`](futures_io::AsyncWrite::poll_vectored_write)
+    /// so far, including a failed one.
+    pub fn writes(&self) -> usize {
+        self.writes
+    }
+
+    /// The number of calls to
+    /// [`poll_flush`](futures_io::AsyncWrite::poll_flush) so far.
+    pub fn flushes(&self) -> usize {
+        self.flushes
+    }
+
+    /// Whether [`poll_close`](futures_io::AsyncWrite::poll_close) has been
+    /// called.
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    fn write_bufs<'a>(&mut self, bufs: impl Iterator<Item = &'a [u8]>)
+        -> io::Result<usize>
+    {
+        let mut limit = self.write_limits[self.writes % self.write_limits.len()];
+        self.writes += 1;
+        if self.fail_on_write == Some(self.writes) {
+            return Err(io::Error::new(io::ErrorKind::Other, "mock write error"));
+        }
+        let mut written = 0;
+        for buf in bufs {
+            if limit == 0 {
+                break;
+            }
+            let n = limit.min(buf.len());
+            self.data.extend_from_slice(&buf[..n]);
+            limit -= n;
+            written += n;
+        }
+        Ok(written)
+    }
+}
+
+impl Default for MockWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AsyncWrite for MockWriter {
+    fn poll_write(&mut self, _: &mut task::Context, buf: &[u8])
+        -> Poll<io::Result<usize>>
+    {
+        Poll::Ready(self.write_bufs(Some(buf).into_iter()))
+    }
+
+    fn poll_vectored_write(&mut self, _: &mut task::Context, vec: &[&IoVec])
+        -> Poll<io::Result<usize>>
+    {
+        Poll::Ready(self.write_bufs(vec.iter().map(|buf| &buf[..])))
+    }
+
+    fn poll_flush(&mut self, _: &mut task::Context) -> Poll<io::Result<()>> {
+        self.flushes += 1;
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(&mut self, _: &mut task::Context) -> Poll<io::Result<()>> {
+        self.closed = true;
+        Poll::Ready(Ok(()))
+    }
+}
@@ -0,0 +1,79 @@
+//! Additional combinators for testing async IO.
+
+pub use crate::interleave_pending::InterleavePending;
+
+mod mock_reader;
+pub use self::mock_reader::MockReader;
+
+mod mock_writer;
+pub use self::mock_writer::MockWriter;
+
+use futures_io::{AsyncRead, AsyncWrite};
+
+/// Additional combinators for testing async readers.
+pub trait AsyncReadTestExt: AsyncRead {
+    /// Introduces an extra [`Poll::Pending`](futures_core::task::Poll::Pending)
+    /// in between each read of the reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(futures_api)]
+    /// use futures::task::Poll;
+    /// use futures::io::AsyncRead;
+    /// use futures_test::task;
+    /// use futures_test::io::AsyncReadTestExt;
+    ///
+    /// let mut reader = (&[1, 2, 3][..]).interleave_pending();
+    /// let cx = &mut task::no_spawn_context();
+    ///
+    /// let mut buf = [0; 2];
+    /// assert!(reader.poll_read(cx, &mut buf).is_pending());
+    /// match reader.poll_read(cx, &mut buf) {
+    ///     Poll::Ready(Ok(n)) => assert_eq!(&buf[..n], [1, 2]),
+    ///     _ => panic!("expected a successful read"),
+    /// }
+    /// assert!(reader.poll_read(cx, &mut buf).is_pending());
+    /// ```
+    fn interleave_pending(self) -> InterleavePending<Self>
+    where
+        Self: Sized,
+    {
+        InterleavePending::new(self)
+    }
+}
+
+impl<R> AsyncReadTestExt for R where R: AsyncRead {}
+
+/// Additional combinators for testing async writers.
+pub trait AsyncWriteTestExt: AsyncWrite {
+    /// Introduces an extra [`Poll::Pending`](futures_core::task::Poll::Pending)
+    /// in between each operation on the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(futures_api)]
+    /// use futures::task::Poll;
+    /// use futures::io::AsyncWrite;
+    /// use futures_test::task;
+    /// use futures_test::io::AsyncWriteTestExt;
+    ///
+    /// let mut writer = Vec::new().interleave_pending_write();
+    /// let cx = &mut task::no_spawn_context();
+    ///
+    /// assert!(writer.poll_write(cx, &[1, 2]).is_pending());
+    /// assert!(writer.poll_write(cx, &[1, 2]).is_ready());
+    /// assert!(writer.poll_flush(cx).is_pending());
+    /// assert!(writer.poll_flush(cx).is_ready());
+    /// assert_eq!(writer.get_ref(), &[1, 2]);
+    /// ```
+    fn interleave_pending_write(self) -> InterleavePending<Self>
+    where
+        Self: Sized,
+    {
+        InterleavePending::new(self)
+    }
+}
+
+impl<W> AsyncWriteTestExt for W where W: AsyncWrite {}
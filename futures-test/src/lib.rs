@@ -26,3 +26,11 @@ pub mod assert;
 pub mod task;
 
 pub mod future;
+
+pub mod stream;
+
+pub mod sink;
+
+pub mod io;
+
+mod interleave_pending;
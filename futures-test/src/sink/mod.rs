@@ -0,0 +1,42 @@
+//! Additional combinators for testing sinks.
+
+pub use crate::interleave_pending::InterleavePending;
+
+use futures_sink::Sink;
+
+/// Additional combinators for testing sinks.
+pub trait SinkTestExt: Sink {
+    /// Introduces an extra [`Poll::Pending`](futures_core::task::Poll::Pending)
+    /// before each of `poll_ready`, `poll_flush` and `poll_close` completes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(futures_api, pin)]
+    /// use futures::task::Poll;
+    /// use futures::sink::Sink;
+    /// use futures_test::task;
+    /// use futures_test::sink::SinkTestExt;
+    /// use pin_utils::pin_mut;
+    ///
+    /// let sink = Vec::<i32>::new().interleave_pending_sink();
+    /// pin_mut!(sink);
+    ///
+    /// let cx = &mut task::no_spawn_context();
+    ///
+    /// assert!(sink.reborrow().poll_ready(cx).is_pending());
+    /// assert!(sink.reborrow().poll_ready(cx).is_ready());
+    /// sink.reborrow().start_send(1).unwrap();
+    /// assert!(sink.reborrow().poll_flush(cx).is_pending());
+    /// assert!(sink.reborrow().poll_flush(cx).is_ready());
+    /// assert_eq!(sink.get_ref(), &[1]);
+    /// ```
+    fn interleave_pending_sink(self) -> InterleavePending<Self>
+    where
+        Self: Sized,
+    {
+        InterleavePending::new(self)
+    }
+}
+
+impl<Si> SinkTestExt for Si where Si: Sink {}
@@ -0,0 +1,42 @@
+//! Additional combinators for testing streams.
+
+pub use crate::interleave_pending::InterleavePending;
+
+use futures_core::stream::Stream;
+
+/// Additional combinators for testing streams.
+pub trait StreamTestExt: Stream {
+    /// Introduces an extra [`Poll::Pending`](futures_core::task::Poll::Pending)
+    /// in between each item of the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(futures_api, pin)]
+    /// use futures::task::Poll;
+    /// use futures::stream::{self, Stream};
+    /// use futures_test::task;
+    /// use futures_test::stream::StreamTestExt;
+    /// use pin_utils::pin_mut;
+    ///
+    /// let stream = stream::iter(vec![1, 2]).interleave_pending();
+    /// pin_mut!(stream);
+    ///
+    /// let cx = &mut task::no_spawn_context();
+    ///
+    /// assert_eq!(stream.reborrow().poll_next(cx), Poll::Pending);
+    /// assert_eq!(stream.reborrow().poll_next(cx), Poll::Ready(Some(1)));
+    /// assert_eq!(stream.reborrow().poll_next(cx), Poll::Pending);
+    /// assert_eq!(stream.reborrow().poll_next(cx), Poll::Ready(Some(2)));
+    /// assert_eq!(stream.reborrow().poll_next(cx), Poll::Pending);
+    /// assert_eq!(stream.reborrow().poll_next(cx), Poll::Ready(None));
+    /// ```
+    fn interleave_pending(self) -> InterleavePending<Self>
+    where
+        Self: Sized,
+    {
+        InterleavePending::new(self)
+    }
+}
+
+impl<St> StreamTestExt for St where St: Stream {}
@@ -7,7 +7,12 @@
 //!
 //! Commonly needed [`task::Context`](futures_core::task::Context)s can be
 //! created via the functions [`no_spawn_context`],
-//! [`noop_context`] and [`panic_context`]. For more advanced use cases, you
+//! [`noop_context`] and [`panic_context`], or the
+//! [`WakeCounter::context`] and [`RecordSpawner::context`] methods. A
+//! different waker or spawner can be swapped into any of them with
+//! [`with_waker`](futures_core::task::Context::with_waker) and
+//! [`with_spawner`](futures_core::task::Context::with_spawner). For more
+//! advanced use cases, you
 //! can create your own task context via
 //! [`task::Context::new`](futures_core::task::Context::new) and make use
 //! of the various provided test wakers and spawners:
@@ -19,13 +24,16 @@
 //!   [`wake`](futures_core::task::LocalWaker) is called.
 //! - [`WakeCounter::local_waker`] creates a waker that increments
 //!   a counter whenever [`wake`](futures_core::task::LocalWaker) is called.
+//!   [`new_count_waker`] creates such a waker on its own, together with an
+//!   [`AwokenCount`] handle which can be read from any thread.
 //!
 //! Test spawners:
 //! - [`NoopSpawner`] ignores calls to
 //!   [`spawn`](futures_core::task::Spawn::spawn)
 //! - [`PanicSpawner`] panics if [`spawn`](futures_core::task::Spawn::spawn) is
 //!   called.
-//! - [`RecordSpawner`] records the spawned futures.
+//! - [`RecordSpawner`] records the spawned futures, which the test can then
+//!   take with [`RecordSpawner::take_spawned`] and drive itself.
 //!
 //! For convenience there additionally exist various functions that directly
 //! return waker/spawner references: [`noop_local_waker_ref`],
@@ -38,16 +46,18 @@ mod noop_spawner;
 pub use self::noop_spawner::{noop_spawner_mut, NoopSpawner};
 
 mod noop_waker;
-pub use self::noop_waker::{noop_local_waker, noop_local_waker_ref, NoopWake};
+pub use self::noop_waker::NoopWake;
+pub use futures_util::task::{noop_local_waker, noop_local_waker_ref};
 
 mod panic_spawner;
 pub use self::panic_spawner::{panic_spawner_mut, PanicSpawner};
 
 mod panic_waker;
-pub use self::panic_waker::{panic_local_waker, panic_local_waker_ref, PanicWake};
+pub use self::panic_waker::PanicWake;
+pub use futures_util::task::{panic_local_waker, panic_local_waker_ref};
 
 mod record_spawner;
 pub use self::record_spawner::RecordSpawner;
 
 mod wake_counter;
-pub use self::wake_counter::WakeCounter;
+pub use self::wake_counter::{new_count_waker, AwokenCount, WakeCounter};
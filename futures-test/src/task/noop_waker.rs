@@ -1,6 +1,4 @@
-use futures_core::task::{LocalWaker, UnsafeWake, Wake, Waker};
-use std::cell::UnsafeCell;
-use std::ptr::NonNull;
+use futures_core::task::Wake;
 use std::sync::Arc;
 
 /// An implementation of [`Wake`](futures_core::task::Wake) that does nothing
@@ -10,10 +8,13 @@ use std::sync::Arc;
 ///
 /// ```
 /// #![feature(futures_api)]
-/// use futures_test::task::{panic_context, noop_local_waker_ref};
+/// use futures::task::local_waker_from_nonlocal;
+/// use futures_test::task::{panic_context, NoopWake};
+/// use std::sync::Arc;
 ///
+/// let waker = local_waker_from_nonlocal(Arc::new(NoopWake::new()));
 /// let mut cx = panic_context();
-/// let cx = &mut cx.with_waker(noop_local_waker_ref());
+/// let cx = &mut cx.with_waker(&waker);
 ///
 /// cx.waker().wake();
 /// ```
@@ -38,54 +39,3 @@ impl Default for NoopWake {
 impl Wake for NoopWake {
     fn wake(_arc_self: &Arc<Self>) {}
 }
-
-unsafe impl UnsafeWake for NoopWake {
-    unsafe fn clone_raw(&self) -> Waker {
-        noop_waker()
-    }
-
-    unsafe fn drop_raw(&self) {}
-
-    unsafe fn wake(&self) {}
-}
-
-fn noop_unsafe_wake() -> NonNull<dyn UnsafeWake> {
-    static mut INSTANCE: NoopWake = NoopWake { _reserved: () };
-    unsafe { NonNull::new_unchecked(&mut INSTANCE as *mut dyn UnsafeWake) }
-}
-
-fn noop_waker() -> Waker {
-    unsafe { Waker::new(noop_unsafe_wake()) }
-}
-
-/// Create a new [`LocalWaker`](futures_core::task::LocalWaker) referencing a
-/// singleton instance of [`NoopWake`].
-pub fn noop_local_waker() -> LocalWaker {
-    unsafe { LocalWaker::new(noop_unsafe_wake()) }
-}
-
-/// Get a thread local reference to a
-/// [`LocalWaker`](futures_core::task::LocalWaker) referencing a singleton
-/// instance of [`NoopWake`].
-///
-/// # Examples
-///
-/// ```
-/// #![feature(async_await, futures_api)]
-/// use futures::task;
-/// use futures_test::task::{noop_local_waker_ref, noop_spawner_mut};
-///
-/// let mut cx = task::Context::new(
-///     noop_local_waker_ref(),
-///     noop_spawner_mut(),
-/// );
-///
-/// cx.waker().wake();
-/// ```
-pub fn noop_local_waker_ref() -> &'static LocalWaker {
-    thread_local! {
-        static LOCAL_WAKER_INSTANCE: UnsafeCell<LocalWaker> =
-            UnsafeCell::new(noop_local_waker());
-    }
-    LOCAL_WAKER_INSTANCE.with(|l| unsafe { &*l.get() })
-}
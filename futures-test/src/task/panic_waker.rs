@@ -1,6 +1,4 @@
-use futures_core::task::{LocalWaker, UnsafeWake, Wake, Waker};
-use std::cell::UnsafeCell;
-use std::ptr::NonNull;
+use futures_core::task::Wake;
 use std::sync::Arc;
 
 /// An implementation of [`Wake`](futures_core::task::Wake) that panics when
@@ -10,10 +8,13 @@ use std::sync::Arc;
 ///
 /// ```should_panic
 /// #![feature(futures_api)]
-/// use futures_test::task::{noop_context, panic_local_waker_ref};
+/// use futures::task::local_waker_from_nonlocal;
+/// use futures_test::task::{noop_context, PanicWake};
+/// use std::sync::Arc;
 ///
+/// let waker = local_waker_from_nonlocal(Arc::new(PanicWake::new()));
 /// let mut cx = noop_context();
-/// let cx = &mut cx.with_waker(panic_local_waker_ref());
+/// let cx = &mut cx.with_waker(&waker);
 ///
 /// cx.waker().wake(); // Will panic
 /// ```
@@ -40,56 +41,3 @@ impl Wake for PanicWake {
         panic!("should not be woken")
     }
 }
-
-unsafe impl UnsafeWake for PanicWake {
-    unsafe fn clone_raw(&self) -> Waker {
-        panic_waker()
-    }
-
-    unsafe fn drop_raw(&self) {}
-
-    unsafe fn wake(&self) {
-        panic!("should not be woken")
-    }
-}
-
-fn panic_unsafe_wake() -> NonNull<dyn UnsafeWake> {
-    static mut INSTANCE: PanicWake = PanicWake { _reserved: () };
-    unsafe { NonNull::new_unchecked(&mut INSTANCE as *mut dyn UnsafeWake) }
-}
-
-fn panic_waker() -> Waker {
-    unsafe { Waker::new(panic_unsafe_wake()) }
-}
-
-/// Create a new [`LocalWaker`](futures_core::task::LocalWaker) referencing
-/// a singleton instance of [`PanicWake`].
-pub fn panic_local_waker() -> LocalWaker {
-    unsafe { LocalWaker::new(panic_unsafe_wake()) }
-}
-
-/// Get a thread local reference to a
-/// [`LocalWaker`](futures_core::task::LocalWaker) referencing a singleton
-/// instance of [`PanicWake`].
-///
-/// # Examples
-///
-/// ```should_panic
-/// #![feature(async_await, futures_api)]
-/// use futures::task;
-/// use futures_test::task::{panic_local_waker_ref, panic_spawner_mut};
-///
-/// let mut cx = task::Context::new(
-///     panic_local_waker_ref(),
-///     panic_spawner_mut(),
-/// );
-///
-/// cx.waker().wake(); // Will panic
-/// ```
-pub fn panic_local_waker_ref() -> &'static LocalWaker {
-    thread_local! {
-        static LOCAL_WAKER_INSTANCE: UnsafeCell<LocalWaker> =
-            UnsafeCell::new(panic_local_waker());
-    }
-    LOCAL_WAKER_INSTANCE.with(|l| unsafe { &*l.get() })
-}
@@ -1,5 +1,7 @@
+use crate::task::noop_local_waker_ref;
 use futures_core::future::FutureObj;
-use futures_core::task::{Spawn, SpawnObjError};
+use futures_core::task::{Context, Spawn, SpawnObjError};
+use std::mem;
 
 /// An implementation of [`Spawn`](futures_core::task::Spawn) that records
 /// any [`Future`](futures_core::future::Future)s spawned on it.
@@ -38,6 +40,58 @@ impl RecordSpawner {
     pub fn spawned(&self) -> &[FutureObj<'static, ()>] {
         &self.spawned
     }
+
+    /// Take the futures that were spawned onto this [`Spawn`] so far, so they
+    /// can be driven to completion by the test.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, futures_api)]
+    /// use futures::executor::block_on;
+    /// use futures::task::SpawnExt;
+    /// use futures_test::task::RecordSpawner;
+    /// use std::sync::Arc;
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    ///
+    /// let ran = Arc::new(AtomicBool::new(false));
+    /// let mut recorder = RecordSpawner::new();
+    /// let flag = ran.clone();
+    /// recorder.spawn(async move { flag.store(true, Ordering::SeqCst); }).unwrap();
+    ///
+    /// let spawned = recorder.take_spawned();
+    /// assert_eq!(spawned.len(), 1);
+    /// assert!(recorder.spawned().is_empty());
+    ///
+    /// for future in spawned {
+    ///     block_on(future);
+    /// }
+    /// assert!(ran.load(Ordering::SeqCst));
+    /// ```
+    pub fn take_spawned(&mut self) -> Vec<FutureObj<'static, ()>> {
+        mem::replace(&mut self.spawned, Vec::new())
+    }
+
+    /// Create a new [`task::Context`](futures_core::task::Context) which
+    /// records spawned futures in this [`RecordSpawner`], and whose
+    /// [waker](futures_core::task::Context::waker) ignores any calls to
+    /// `wake`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, futures_api)]
+    /// use futures::task::SpawnExt;
+    /// use futures_test::task::RecordSpawner;
+    ///
+    /// let mut recorder = RecordSpawner::new();
+    /// recorder.context().spawner().spawn(async { }).unwrap();
+    ///
+    /// assert_eq!(recorder.spawned().len(), 1);
+    /// ```
+    pub fn context(&mut self) -> Context<'_> {
+        Context::new(noop_local_waker_ref(), self)
+    }
 }
 
 impl Spawn for RecordSpawner {
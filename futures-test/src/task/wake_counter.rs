@@ -1,4 +1,5 @@
-use futures_core::task::{self, LocalWaker, Wake};
+use crate::task::panic_spawner_mut;
+use futures_core::task::{self, Context, LocalWaker, Wake};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
@@ -56,6 +57,36 @@ impl WakeCounter {
     pub fn count(&self) -> usize {
         self.inner.count.load(Ordering::SeqCst)
     }
+
+    /// Creates an [`AwokenCount`] handle which reads the same counter, and
+    /// can be sent to other threads.
+    pub fn awoken_count(&self) -> AwokenCount {
+        AwokenCount {
+            inner: self.inner.clone(),
+        }
+    }
+
+    /// Create a new [`task::Context`](futures_core::task::Context) which uses
+    /// the [`LocalWaker`] of this [`WakeCounter`], and a
+    /// [spawner](futures_core::task::Context::spawner) which will panic if
+    /// used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(futures_api)]
+    /// use futures_test::task::WakeCounter;
+    ///
+    /// let wake_counter = WakeCounter::new();
+    /// let cx = &mut wake_counter.context();
+    ///
+    /// cx.waker().wake();
+    ///
+    /// assert_eq!(wake_counter.count(), 1);
+    /// ```
+    pub fn context(&self) -> Context<'_> {
+        Context::new(&self.local_waker, panic_spawner_mut())
+    }
 }
 
 impl Default for WakeCounter {
@@ -64,6 +95,55 @@ impl Default for WakeCounter {
     }
 }
 
+/// A handle to the number of times the [`LocalWaker`] created by
+/// [`new_count_waker`] or a [`WakeCounter`] has been woken.
+///
+/// Unlike the waker, the handle is `Clone + Send`, so the count can be read
+/// from any thread.
+#[derive(Clone, Debug)]
+pub struct AwokenCount {
+    inner: Arc<Inner>,
+}
+
+impl AwokenCount {
+    /// Get the number of times the waker has been woken
+    pub fn get(&self) -> usize {
+        self.inner.count.load(Ordering::SeqCst)
+    }
+}
+
+impl PartialEq<usize> for AwokenCount {
+    fn eq(&self, other: &usize) -> bool {
+        self.get() == *other
+    }
+}
+
+/// Create a new [`LocalWaker`] which counts the number of times it is woken,
+/// together with an [`AwokenCount`] handle to read that count.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(futures_api)]
+/// use futures_test::task::{new_count_waker, panic_context};
+///
+/// let (local_waker, count) = new_count_waker();
+/// let mut cx = panic_context();
+/// let cx = &mut cx.with_waker(&local_waker);
+///
+/// assert_eq!(count, 0);
+///
+/// cx.waker().wake();
+/// cx.waker().wake();
+///
+/// assert_eq!(count, 2);
+/// ```
+pub fn new_count_waker() -> (LocalWaker, AwokenCount) {
+    let wake_counter = WakeCounter::new();
+    let count = wake_counter.awoken_count();
+    (wake_counter.local_waker, count)
+}
+
 impl Wake for Inner {
     fn wake(arc_self: &Arc<Self>) {
         arc_self.count.fetch_add(1, Ordering::SeqCst);
@@ -0,0 +1,32 @@
+#![feature(test, futures_api)]
+
+use futures::executor::block_on;
+use futures::io::{self, AsyncBufReadExt, AsyncReadExt, BufReader};
+use std::io::Cursor;
+use test::Bencher;
+
+const LEN: usize = 1024 * 1024;
+
+fn reader() -> BufReader<Cursor<Vec<u8>>> {
+    BufReader::new(Cursor::new(vec![0x5a; LEN]))
+}
+
+#[bench]
+fn copy_into_buf_reader(b: &mut Bencher) {
+    b.bytes = LEN as u64;
+    b.iter(|| {
+        let mut reader = reader();
+        let copied = block_on(reader.copy_into(&mut io::sink())).unwrap();
+        assert_eq!(copied, LEN as u64);
+    });
+}
+
+#[bench]
+fn copy_buf_into_buf_reader(b: &mut Bencher) {
+    b.bytes = LEN as u64;
+    b.iter(|| {
+        let mut reader = reader();
+        let copied = block_on(reader.copy_buf_into(&mut io::sink())).unwrap();
+        assert_eq!(copied, LEN as u64);
+    });
+}
@@ -0,0 +1,52 @@
+#![feature(test, futures_api)]
+
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future::FutureExt;
+use futures::task::{self, Poll, no_spawner_mut, noop_local_waker_ref};
+use std::sync::{Arc, Barrier};
+use std::thread;
+use test::Bencher;
+
+#[bench]
+fn contended_polls(b: &mut Bencher) {
+    const THREADS: usize = 16;
+    const CLONES_PER_THREAD: usize = 32;
+    const POLLS: usize = 100;
+
+    b.iter(|| {
+        let (tx, rx) = oneshot::channel::<u32>();
+        let shared = rx.shared();
+        let barrier = Arc::new(Barrier::new(THREADS + 1));
+
+        let threads = (0..THREADS).map(|_| {
+            let mut clones = vec![shared.clone(); CLONES_PER_THREAD];
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                let mut cx = task::Context::new(
+                    noop_local_waker_ref(),
+                    no_spawner_mut(),
+                );
+
+                // Every clone registers its waker again on every poll, while
+                // all the other threads do the same.
+                for _ in 0..POLLS {
+                    for clone in &mut clones {
+                        assert_eq!(clone.poll_unpin(&mut cx), Poll::Pending);
+                    }
+                }
+
+                barrier.wait();
+                for clone in clones {
+                    assert_eq!(block_on(clone), Ok(1));
+                }
+            })
+        }).collect::<Vec<_>>();
+
+        barrier.wait();
+        tx.send(1).unwrap();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+    });
+}
@@ -6,6 +6,10 @@
 /// While `join!(a, b)` is similar to `(await!(a), await!(b))`,
 /// `join!` polls both futures concurrently and therefore is more efficent.
 ///
+/// The arguments can be any expressions evaluating to futures. The futures are
+/// moved into the `join!` and pinned on the stack, so they don't have to be
+/// [`Unpin`](core::marker::Unpin) and can borrow local data.
+///
 /// This macro is only usable inside of async functions, closures, and blocks.
 ///
 /// # Examples
@@ -21,39 +25,36 @@
 /// assert_eq!(join!(a, b), (1, 2));
 /// # });
 /// ```
+///
+/// The futures don't have to be variables:
+///
+/// ```
+/// #![feature(pin, async_await, await_macro, futures_api)]
+/// # futures::executor::block_on(async {
+/// use futures::{join, future};
+///
+/// let data = vec![1, 2, 3];
+///
+/// let (len, sum) = join!(
+///     async { data.len() },
+///     future::ready(data.iter().sum::<i32>()),
+/// );
+/// assert_eq!((len, sum), (3, 6));
+/// # });
+/// ```
 #[macro_export]
 macro_rules! join {
-    ($($fut:ident),*) => { {
-        $(
-            // Move future into a local so that it is pinned in one place and
-            // is no longer accessible by the end user.
-            let mut $fut = $crate::future::maybe_done($fut);
-        )*
-        await!($crate::future::poll_fn(move |cx| {
-            let mut all_done = true;
-            $(
-                if $crate::core_reexport::future::Future::poll(
-                    unsafe { $crate::core_reexport::mem::PinMut::new_unchecked(&mut $fut) }, cx).is_pending()
-                {
-                    all_done = false;
-                }
-            )*
-            if all_done {
-                $crate::core_reexport::task::Poll::Ready(($(
-                    unsafe { $crate::core_reexport::mem::PinMut::new_unchecked(&mut $fut) }.take_output().unwrap(),
-                )*))
-            } else {
-                $crate::core_reexport::task::Poll::Pending
-            }
-        }))
-    } }
+    ($($fut:expr),* $(,)*) => {
+        $crate::__join!(@declare join [] $($fut,)*)
+    };
 }
 
 /// Polls multiple futures simultaneously, resolving to a [`Result`] containing
 /// either a tuple of the successful outputs or an error.
 ///
 /// `try_join!` is similar to [`join!`], but completes immediately if any of
-/// the futures return an error.
+/// the futures return an error. The remaining futures are dropped without
+/// being polled any further.
 ///
 /// This macro is only usable inside of async functions, closures, and blocks.
 ///
@@ -90,26 +91,71 @@ macro_rules! join {
 /// ```
 #[macro_export]
 macro_rules! try_join {
-    ($($fut:ident),*) => { {
+    ($($fut:expr),* $(,)*) => {
+        $crate::__join!(@declare try_join [] $($fut,)*)
+    };
+}
+
+// The implementation of `join!` and `try_join!`.
+//
+// `@declare` gives every future its own local, one future per step so that
+// hygiene keeps the `__fut` locals of the different steps apart, and then
+// hands them to the `join` or `try_join` rule.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __join {
+    (@declare $kind:ident [$($futs:tt)*] $fut:expr, $($rest:tt)*) => {
+        $crate::__join!(@declare $kind [$($futs)* (__fut, $fut)] $($rest)*)
+    };
+    (@declare $kind:ident [$($futs:tt)*]) => {
+        $crate::__join!(@$kind $($futs)*)
+    };
+
+    (@join $(($name:ident, $fut:expr))*) => { {
+        $(
+            // Move future into a local so that it is pinned in one place and
+            // is no longer accessible by the end user.
+            let mut $name = $crate::future::maybe_done($fut);
+        )*
+        await!($crate::future::poll_fn(move |cx| {
+            let mut all_done = true;
+            $(
+                if $crate::core_reexport::future::Future::poll(
+                    unsafe { $crate::core_reexport::mem::PinMut::new_unchecked(&mut $name) }, cx).is_pending()
+                {
+                    all_done = false;
+                }
+            )*
+            if all_done {
+                $crate::core_reexport::task::Poll::Ready(($(
+                    unsafe { $crate::core_reexport::mem::PinMut::new_unchecked(&mut $name) }.take_output().unwrap(),
+                )*))
+            } else {
+                $crate::core_reexport::task::Poll::Pending
+            }
+        }))
+    } };
+
+    (@try_join $(($name:ident, $fut:expr))*) => { {
         $(
             // Move future into a local so that it is pinned in one place and
             // is no longer accessible by the end user.
-            let mut $fut = $crate::future::maybe_done($fut);
+            let mut $name = $crate::future::maybe_done($fut);
         )*
 
         let res: $crate::core_reexport::result::Result<_, _> = await!($crate::future::poll_fn(move |cx| {
             let mut all_done = true;
             $(
                 if $crate::core_reexport::future::Future::poll(
-                    unsafe { $crate::core_reexport::mem::PinMut::new_unchecked(&mut $fut) }, cx).is_pending()
+                    unsafe { $crate::core_reexport::mem::PinMut::new_unchecked(&mut $name) }, cx).is_pending()
                 {
                     all_done = false;
-                } else if unsafe { $crate::core_reexport::mem::PinMut::new_unchecked(&mut $fut) }.output_mut().unwrap().is_err() {
+                } else if unsafe { $crate::core_reexport::mem::PinMut::new_unchecked(&mut $name) }.output_mut().unwrap().is_err() {
                     // `.err().unwrap()` rather than `.unwrap_err()` so that we don't introduce
                     // a `T: Debug` bound.
                     return $crate::core_reexport::task::Poll::Ready(
                         $crate::core_reexport::result::Result::Err(
-                            unsafe { $crate::core_reexport::mem::PinMut::new_unchecked(&mut $fut) }.take_output().unwrap().err().unwrap()
+                            unsafe { $crate::core_reexport::mem::PinMut::new_unchecked(&mut $name) }.take_output().unwrap().err().unwrap()
                         )
                     );
                 }
@@ -119,7 +165,7 @@ macro_rules! try_join {
                     $crate::core_reexport::result::Result::Ok(($(
                         // `.ok().unwrap()` rather than `.unwrap()` so that we don't introduce
                         // an `E: Debug` bound.
-                        unsafe { $crate::core_reexport::mem::PinMut::new_unchecked(&mut $fut) }.take_output().unwrap().ok().unwrap(),
+                        unsafe { $crate::core_reexport::mem::PinMut::new_unchecked(&mut $name) }.take_output().unwrap().ok().unwrap(),
                     )*))
                 )
             } else {
@@ -128,5 +174,5 @@ macro_rules! try_join {
         }));
 
         res
-    } }
+    } };
 }
@@ -21,6 +21,18 @@ mod join;
 // Primary export is a macro
 #[macro_use]
 mod select;
+#[doc(hidden)]
+pub use self::select::{poll_select_arm, SelectResult};
+
+mod random;
+#[doc(hidden)]
+pub use self::random::shuffle;
+
+// Primary export is a macro
+#[macro_use]
+mod stream_select;
+#[doc(hidden)]
+pub use self::stream_select::{poll_stream_select, poll_stream_select_source};
 
 // Primary export is a macro
 #[macro_use]
@@ -9,7 +9,30 @@ use futures_core::task::{self, Poll};
 /// Similarly, when using this macro, it must be ensured that [`wake`](std::task::Waker::wake) 
 /// is called somewhere when further progress can be made.
 ///
+/// When the enclosing future is polled again, execution resumes right after
+/// the `pending!()`.
+///
 /// This macro is only usable inside of async functions, closures, and blocks.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(pin, async_await, await_macro, futures_api)]
+/// # futures::executor::block_on(async {
+/// use futures::{pending, poll};
+/// use futures::task::Poll;
+/// use pin_utils::pin_mut;
+///
+/// let yield_once = async {
+///     pending!();
+///     1
+/// };
+/// pin_mut!(yield_once);
+///
+/// assert_eq!(poll!(&mut yield_once), Poll::Pending);
+/// assert_eq!(poll!(&mut yield_once), Poll::Ready(1));
+/// # });
+/// ```
 #[macro_export]
 macro_rules! pending {
     () => {
@@ -6,7 +6,28 @@ use futures_core::task::{self, Poll};
 /// A macro which returns the result of polling a future once within the
 /// current `async` context.
 ///
+/// The future is polled with the context of the task running the enclosing
+/// `async` block, so it will wake that task up once it can make progress. The
+/// future has to be [`Unpin`](core::marker::Unpin), pass a `&mut` reference
+/// to poll it without consuming it, and pin it first if it isn't `Unpin`.
+///
 /// This macro is only usable inside of `async` functions, closures, and blocks.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(pin, async_await, await_macro, futures_api)]
+/// # futures::executor::block_on(async {
+/// use futures::{future, poll};
+/// use futures::task::Poll;
+///
+/// let mut a = future::ready(1);
+/// let mut b = future::empty::<i32>();
+///
+/// assert_eq!(poll!(&mut a), Poll::Ready(1));
+/// assert_eq!(poll!(&mut b), Poll::Pending);
+/// # });
+/// ```
 #[macro_export]
 macro_rules! poll {
     ($x:expr) => {
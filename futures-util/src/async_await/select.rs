@@ -1,11 +1,49 @@
 //! The `select` macro.
 
+use core::marker::Unpin;
+use core::mem::PinMut;
+use futures_core::future::{Future, FusedFuture};
+use futures_core::task::{self, Poll};
+
 /// Polls multiple futures simultaneously, executing the branch for the future
 /// that finishes first.
 ///
+/// Every branch has the form `pattern = future => handler`. When `future`
+/// completes, its output is bound to `pattern` and `handler` is executed.
 /// `select!` can select over futures with different output types, but each
-/// branch has to have the same return type. Inside each branch, the respective
-/// future's output is available via a variable with the same name as the future.
+/// handler has to have the same type, which is the type of the whole
+/// `select!`.
+///
+/// The futures have to implement [`FusedFuture`](futures_core::future::FusedFuture),
+/// so that `select!` can skip the ones which already completed, and
+/// [`Unpin`](core::marker::Unpin) if they are given by name:
+///
+/// - If the future expression is a plain variable, the future is borrowed
+///   mutably rather than moved into the `select!`. A future that didn't
+///   complete keeps its state and can be selected on again, e.g. in the next
+///   iteration of a loop, or awaited on its own.
+/// - Any other expression is evaluated once, every time the `select!` is
+///   entered, and the resulting future is pinned on the stack. This is what
+///   you want for futures like the ones returned by
+///   [`select_next_some`](crate::stream::StreamExt::select_next_some).
+///
+/// Use [`fuse`](crate::future::FutureExt::fuse) to make a future usable by
+/// `select!`.
+///
+/// The branches are polled in a random order each time the `select!` is
+/// polled, so that a future which is always ready can't starve the others.
+///
+/// Besides the future branches, there are two special branches:
+///
+/// - `complete => handler` is executed when all of the futures have
+///   completed. Without it, `select!` panics in that case, unless there is a
+///   `default` branch.
+/// - `default => handler` is executed if none of the futures is ready when
+///   they are polled, instead of waiting for one of them to become ready.
+///
+/// The handlers run after the winning future has been polled to completion,
+/// so they can use `await!`, `break`, `continue` or `return` like any other
+/// code in the surrounding `async` block.
 ///
 /// This macro is only usable inside of async functions, closures, and blocks.
 ///
@@ -15,57 +53,351 @@
 /// #![feature(pin, async_await, await_macro, futures_api)]
 /// # futures::executor::block_on(async {
 /// use futures::{select, future};
-/// let mut a = future::ready(4);
-/// let mut b: future::Empty<()> = future::empty();
+/// use futures::future::FutureExt;
+///
+/// let mut a = future::ready(4).fuse();
+/// let mut b = future::empty::<()>().fuse();
 ///
 /// let res = select! {
-///     a => a + 1,
-///     b => 0,
+///     a_res = a => a_res + 1,
+///     _ = b => 0,
 /// };
 /// assert_eq!(res, 5);
 /// # });
 /// ```
+///
+/// Selecting in a loop until all of the futures completed:
+///
+/// ```
+/// #![feature(pin, async_await, await_macro, futures_api)]
+/// # futures::executor::block_on(async {
+/// use futures::{select, future};
+/// use futures::future::FutureExt;
+///
+/// let mut a = future::ready(4).fuse();
+/// let mut b = future::ready(6).fuse();
+/// let mut total = 0;
+///
+/// loop {
+///     select! {
+///         a = a => total += a,
+///         b = b => total += b,
+///         complete => break,
+///         default => unreachable!(), // never runs, the futures are ready
+///     };
+/// }
+/// assert_eq!(total, 10);
+/// # });
+/// ```
 #[macro_export]
 macro_rules! select {
     () => {
         compile_error!("The `select!` macro requires at least one branch")
     };
-    ($(
-        $name:ident => $body:expr,
-    )*) => { {
-        // Require all arguments to be `Unpin` so that we don't have to pin them,
-        // allowing uncompleted futures to be reused by the caller after the
-        // `select!` resolves.
-        $(
-            $crate::async_await::assert_unpin(&$name);
-        )*
-
-        #[allow(bad_style)]
-        enum __PrivResult<$($name,)*> {
-            $(
-                $name($name),
-            )*
-        }
+    ($($tokens:tt)*) => {
+        $crate::__select!(@parse true [] [] [] $($tokens)*)
+    };
+}
+
+/// Polls multiple futures simultaneously, executing the branch for the future
+/// that finishes first, preferring the branches written first.
+///
+/// `select_biased!` is exactly like [`select!`], except that the branches are
+/// always polled in the order in which they are written instead of in a
+/// random order. If several futures are ready at the same time, the first one
+/// wins. This is useful to give some events priority over others, e.g. to
+/// check a shutdown signal before the next item of a stream, and to make
+/// tests deterministic.
+///
+/// Beware that a future which is always ready starves all the branches after
+/// it: when selecting in a loop, they're never polled. Use [`select!`] unless
+/// you need the order.
+///
+/// This macro is only usable inside of async functions, closures, and blocks.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(pin, async_await, await_macro, futures_api)]
+/// # futures::executor::block_on(async {
+/// use futures::{select_biased, future};
+/// use futures::future::FutureExt;
+///
+/// let mut a = future::ready(1).fuse();
+/// let mut b = future::ready(2).fuse();
+///
+/// let res = select_biased! {
+///     a = a => a,
+///     b = b => b,
+/// };
+/// assert_eq!(res, 1);
+/// # });
+/// ```
+#[macro_export]
+macro_rules! select_biased {
+    () => {
+        compile_error!("The `select_biased!` macro requires at least one branch")
+    };
+    ($($tokens:tt)*) => {
+        $crate::__select!(@parse false [] [] [] $($tokens)*)
+    };
+}
+
+// The implementation of `select!` and `select_biased!`, the first token of
+// `@parse` says whether the branches are shuffled before polling them.
+//
+// `@parse` munches the branches one by one, sorting them into the future
+// branches and the `complete` and `default` handlers. Each future branch is
+// tagged with whether its future is a variable that has to be borrowed or an
+// expression that has to be stored and pinned.
+//
+// `@declare` then goes through the future branches and declares the locals
+// each of them needs. Every step is a separate macro expansion, so hygiene
+// makes the `__fut` and `__out` locals of the different branches distinct.
+//
+// The final `@declare` step polls the branches in a `poll_fn`, writing the
+// output of the winning future to its `__out` local, and only runs the
+// handler once the `poll_fn` has completed.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __select {
+    // All branches are parsed.
+    (@parse $random:tt $arms:tt $complete:tt $default:tt) => {
+        $crate::__select!(@declare $random [] [] $arms $complete $default)
+    };
+
+    // Commas between the branches.
+    (@parse $random:tt $arms:tt $complete:tt $default:tt , $($rest:tt)*) => {
+        $crate::__select!(@parse $random $arms $complete $default $($rest)*)
+    };
+
+    // `complete =>` branches.
+    (@parse $random:tt $arms:tt [] $default:tt
+        complete => $body:block $($rest:tt)*
+    ) => {
+        $crate::__select!(@parse $random $arms [$body] $default $($rest)*)
+    };
+    (@parse $random:tt $arms:tt [] $default:tt
+        complete => $body:expr, $($rest:tt)*
+    ) => {
+        $crate::__select!(@parse $random $arms [$body] $default $($rest)*)
+    };
+    (@parse $random:tt $arms:tt [] $default:tt
+        complete => $body:expr
+    ) => {
+        $crate::__select!(@parse $random $arms [$body] $default)
+    };
+    (@parse $random:tt $arms:tt [$($complete:tt)+] $default:tt
+        complete => $($rest:tt)*
+    ) => {
+        compile_error!("`select!` can't have more than one `complete` branch")
+    };
+
+    // `default =>` branches.
+    (@parse $random:tt $arms:tt $complete:tt []
+        default => $body:block $($rest:tt)*
+    ) => {
+        $crate::__select!(@parse $random $arms $complete [$body] $($rest)*)
+    };
+    (@parse $random:tt $arms:tt $complete:tt []
+        default => $body:expr, $($rest:tt)*
+    ) => {
+        $crate::__select!(@parse $random $arms $complete [$body] $($rest)*)
+    };
+    (@parse $random:tt $arms:tt $complete:tt []
+        default => $body:expr
+    ) => {
+        $crate::__select!(@parse $random $arms $complete [$body])
+    };
+    (@parse $random:tt $arms:tt $complete:tt [$($default:tt)+]
+        default => $($rest:tt)*
+    ) => {
+        compile_error!("`select!` can't have more than one `default` branch")
+    };
+
+    // Future branches with a variable, which is borrowed.
+    (@parse $random:tt [$($arms:tt)*] $complete:tt $default:tt
+        $p:pat = $fut:ident => $body:block $($rest:tt)*
+    ) => {
+        $crate::__select!(@parse $random [$($arms)* (borrow $p, $fut, $body)]
+            $complete $default $($rest)*)
+    };
+    (@parse $random:tt [$($arms:tt)*] $complete:tt $default:tt
+        $p:pat = $fut:ident => $body:expr, $($rest:tt)*
+    ) => {
+        $crate::__select!(@parse $random [$($arms)* (borrow $p, $fut, $body)]
+            $complete $default $($rest)*)
+    };
+    (@parse $random:tt [$($arms:tt)*] $complete:tt $default:tt
+        $p:pat = $fut:ident => $body:expr
+    ) => {
+        $crate::__select!(@parse $random [$($arms)* (borrow $p, $fut, $body)]
+            $complete $default)
+    };
 
-        let __priv_res = await!($crate::future::poll_fn(|cx| {
-            $(
-                match $crate::core_reexport::future::Future::poll(
-                    $crate::core_reexport::mem::PinMut::new(&mut $name), cx)
-                {
-                    $crate::core_reexport::task::Poll::Ready(x) =>
-                        return $crate::core_reexport::task::Poll::Ready(__PrivResult::$name(x)),
-                    $crate::core_reexport::task::Poll::Pending => {},
+    // Future branches with any other expression, which is pinned.
+    (@parse $random:tt [$($arms:tt)*] $complete:tt $default:tt
+        $p:pat = $fut:expr => $body:block $($rest:tt)*
+    ) => {
+        $crate::__select!(@parse $random [$($arms)* (pin $p, $fut, $body)]
+            $complete $default $($rest)*)
+    };
+    (@parse $random:tt [$($arms:tt)*] $complete:tt $default:tt
+        $p:pat = $fut:expr => $body:expr, $($rest:tt)*
+    ) => {
+        $crate::__select!(@parse $random [$($arms)* (pin $p, $fut, $body)]
+            $complete $default $($rest)*)
+    };
+    (@parse $random:tt [$($arms:tt)*] $complete:tt $default:tt
+        $p:pat = $fut:expr => $body:expr
+    ) => {
+        $crate::__select!(@parse $random [$($arms)* (pin $p, $fut, $body)]
+            $complete $default)
+    };
+
+    (@parse $random:tt $arms:tt $complete:tt $default:tt $($rest:tt)+) => {
+        compile_error!("Expected a `pattern = future => handler`, `complete => handler` \
+                        or `default => handler` branch")
+    };
+
+    // Declare the locals of the next future branch.
+    (@declare $random:tt [$($decls:tt)*] [$($arms:tt)*]
+        [(borrow $p:pat, $fut:ident, $body:tt) $($rest:tt)*] $complete:tt $default:tt
+    ) => {
+        $crate::__select!(@declare $random
+            [
+                $($decls)*
+                let mut __out = $crate::core_reexport::option::Option::None;
+            ]
+            [$($arms)* ($p, $fut, __out, $body)]
+            [$($rest)*] $complete $default)
+    };
+    (@declare $random:tt [$($decls:tt)*] [$($arms:tt)*]
+        [(pin $p:pat, $fut:expr, $body:tt) $($rest:tt)*] $complete:tt $default:tt
+    ) => {
+        $crate::__select!(@declare $random
+            [
+                $($decls)*
+                let mut __fut = $fut;
+                // Safety: the original `__fut` is shadowed, so it's never
+                // moved again.
+                let mut __fut = unsafe {
+                    $crate::core_reexport::mem::PinMut::new_unchecked(&mut __fut)
+                };
+                let mut __out = $crate::core_reexport::option::Option::None;
+            ]
+            [$($arms)* ($p, __fut, __out, $body)]
+            [$($rest)*] $complete $default)
+    };
+
+    // Everything is declared, poll the futures and run the handler.
+    (@declare $random:tt [$($decls:tt)*] [$(($p:pat, $fut:ident, $out:ident, $body:tt))*]
+        [] [$($complete:tt)*] [$($default:tt)*]
+    ) => { {
+        $($decls)*
+
+        let __select_result = await!($crate::future::poll_fn(|cx| {
+            let mut __any_pending = false;
+            {
+                let __arms: &mut [&mut dyn FnMut(&mut $crate::core_reexport::task::Context)
+                    -> $crate::core_reexport::option::Option<$crate::core_reexport::task::Poll<()>>
+                ] = &mut [$(
+                    &mut |cx| $crate::async_await::poll_select_arm(&mut $fut, &mut $out, cx),
+                )*];
+                if $random {
+                    $crate::async_await::shuffle(__arms);
+                }
+                for __arm in __arms.iter_mut() {
+                    match (*__arm)(cx) {
+                        $crate::core_reexport::option::Option::Some(
+                            $crate::core_reexport::task::Poll::Ready(()),
+                        ) => return $crate::core_reexport::task::Poll::Ready(
+                            $crate::async_await::SelectResult::Ready,
+                        ),
+                        $crate::core_reexport::option::Option::Some(
+                            $crate::core_reexport::task::Poll::Pending,
+                        ) => __any_pending = true,
+                        $crate::core_reexport::option::Option::None => {}
+                    }
                 }
-            )*
-            $crate::core_reexport::task::Poll::Pending
+            }
+
+            if __any_pending {
+                if $crate::__select!(@is_some [$($default)*]) {
+                    $crate::core_reexport::task::Poll::Ready(
+                        $crate::async_await::SelectResult::Default,
+                    )
+                } else {
+                    $crate::core_reexport::task::Poll::Pending
+                }
+            } else if $crate::__select!(@is_some [$($complete)*]) {
+                $crate::core_reexport::task::Poll::Ready(
+                    $crate::async_await::SelectResult::Complete,
+                )
+            } else if $crate::__select!(@is_some [$($default)*]) {
+                $crate::core_reexport::task::Poll::Ready(
+                    $crate::async_await::SelectResult::Default,
+                )
+            } else {
+                panic!("all futures in select! were completed, \
+                        but no `complete =>` handler was provided")
+            }
         }));
-        match __priv_res {
-            $(
-                __PrivResult::$name($name) => {
-                    let _ = $name; // Suppress "unused" warning for binding name
-                    $body
+
+        match __select_result {
+            $crate::async_await::SelectResult::Ready => {
+                $(
+                    if let $crate::core_reexport::option::Option::Some(__value) = $out {
+                        let $p = __value;
+                        $body
+                    } else
+                )* {
+                    unreachable!()
                 }
-            )*
+            }
+            $crate::async_await::SelectResult::Complete => {
+                $crate::__select!(@handler [$($complete)*])
+            }
+            $crate::async_await::SelectResult::Default => {
+                $crate::__select!(@handler [$($default)*])
+            }
         }
     } };
+
+    (@is_some []) => { false };
+    (@is_some [$($body:tt)+]) => { true };
+
+    (@handler []) => { unreachable!() };
+    (@handler [$body:tt]) => { $body };
+}
+
+#[doc(hidden)]
+#[derive(Debug)]
+pub enum SelectResult {
+    Ready,
+    Complete,
+    Default,
+}
+
+/// Polls a single `select!` branch, storing the output in `out` if the future
+/// is ready, and returning `None` if the future already completed before.
+#[doc(hidden)]
+#[inline]
+pub fn poll_select_arm<Fut>(
+    fut: &mut Fut,
+    out: &mut Option<Fut::Output>,
+    cx: &mut task::Context,
+) -> Option<Poll<()>>
+    where Fut: Future + FusedFuture + Unpin,
+{
+    if fut.is_terminated() {
+        return None;
+    }
+    match PinMut::new(fut).poll(cx) {
+        Poll::Ready(output) => {
+            *out = Some(output);
+            Some(Poll::Ready(()))
+        }
+        Poll::Pending => Some(Poll::Pending),
+    }
 }
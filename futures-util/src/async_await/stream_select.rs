@@ -0,0 +1,132 @@
+//! The `stream_select` macro.
+
+use crate::async_await::shuffle;
+use crate::stream::Fuse;
+use core::marker::Unpin;
+use core::mem::PinMut;
+use futures_core::stream::{Stream, FusedStream};
+use futures_core::task::{self, Poll};
+
+/// Merges several streams, possibly with different item types, into a single
+/// stream which tags every item with the stream it came from.
+///
+/// Every source is written as `Tag = stream`, where `Tag` is a function or
+/// tuple-like enum variant which is applied to every item of `stream`.
+/// Usually `Tag` is a variant of an enum listing the different kinds of items.
+///
+/// The streams are polled in a random order each time the merged stream is
+/// polled, so that a stream which is always ready can't starve the others.
+/// The merged stream ends when all of the sources ended.
+///
+/// The sources are moved into the merged stream and have to be
+/// [`Unpin`](core::marker::Unpin). To keep using a stream once the merged
+/// stream is dropped, pass a `&mut` reference to it.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(pin, futures_api)]
+/// use futures::executor::block_on;
+/// use futures::stream::{self, StreamExt};
+/// use futures::stream_select;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Item {
+///     Number(u32),
+///     Name(&'static str),
+/// }
+///
+/// let mut numbers = stream::iter(vec![1, 2]);
+/// let mut names = stream::iter(vec!["a", "b"]);
+///
+/// let mut merged: Vec<Item> = block_on(stream_select! {
+///     Item::Number = &mut numbers,
+///     Item::Name = &mut names,
+/// }.collect());
+/// merged.sort_by_key(|item| format!("{:?}", item));
+///
+/// assert_eq!(merged, vec![
+///     Item::Name("a"),
+///     Item::Name("b"),
+///     Item::Number(1),
+///     Item::Number(2),
+/// ]);
+/// assert_eq!(block_on(numbers.next()), None);
+/// ```
+#[macro_export]
+macro_rules! stream_select {
+    ($($tag:path = $stream:expr),+ $(,)*) => {
+        $crate::__stream_select!(@declare [] [] $($tag = $stream,)+)
+    };
+}
+
+// The implementation of `stream_select!`, which declares a local for every
+// source with one step per source like `select!`, and then moves them all into
+// a `poll_fn` stream.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __stream_select {
+    (@declare [$($decls:tt)*] [$($sources:tt)*] $tag:path = $stream:expr, $($rest:tt)*) => {
+        $crate::__stream_select!(@declare
+            [
+                $($decls)*
+                let mut __stream = $crate::stream::StreamExt::fuse($stream);
+            ]
+            [$($sources)* ($tag, __stream)]
+            $($rest)*)
+    };
+    (@declare [$($decls:tt)*] [$(($tag:path, $stream:ident))*]) => { {
+        $($decls)*
+        $crate::stream::poll_fn(move |cx| {
+            $crate::async_await::poll_stream_select(&mut [$(
+                &mut |cx| $crate::async_await::poll_stream_select_source(&mut $stream, $tag, cx),
+            )*], cx)
+        })
+    } };
+}
+
+/// Polls the sources of a `stream_select!` in a random order, returning the
+/// first item, or `None` once all sources ended.
+#[doc(hidden)]
+pub fn poll_stream_select<T>(
+    sources: &mut [&mut dyn FnMut(&mut task::Context) -> Option<Poll<T>>],
+    cx: &mut task::Context,
+) -> Poll<Option<T>> {
+    shuffle(sources);
+
+    let mut any_pending = false;
+    for source in sources.iter_mut() {
+        match (*source)(cx) {
+            Some(Poll::Ready(item)) => return Poll::Ready(Some(item)),
+            Some(Poll::Pending) => any_pending = true,
+            None => {}
+        }
+    }
+
+    if any_pending {
+        Poll::Pending
+    } else {
+        Poll::Ready(None)
+    }
+}
+
+/// Polls a single `stream_select!` source, tagging its next item, and
+/// returning `None` if the source has ended.
+#[doc(hidden)]
+pub fn poll_stream_select_source<St, F, T>(
+    stream: &mut Fuse<St>,
+    tag: F,
+    cx: &mut task::Context,
+) -> Option<Poll<T>>
+    where St: Stream + Unpin,
+          F: FnOnce(St::Item) -> T,
+{
+    if stream.is_terminated() {
+        return None;
+    }
+    match PinMut::new(stream).poll_next(cx) {
+        Poll::Ready(Some(item)) => Some(Poll::Ready(tag(item))),
+        Poll::Ready(None) => None,
+        Poll::Pending => Some(Poll::Pending),
+    }
+}
@@ -1,7 +1,9 @@
 /// Converts a futures 0.3 [`TryFuture`](futures_core::future::TryFuture),
 /// [`TryStream`](futures_core::stream::TryStream) or
 /// [`Sink`](futures_sink::Sink) into a futures 0.1 [`Future`](futures::Future),
-/// [`Stream`](futures::Stream) or [`Sink`](futures::Sink) and vice versa.
+/// [`Stream`](futures::Stream) or [`Sink`](futures::Sink).
+///
+/// See [`Compat01As03`](super::Compat01As03) for the other direction.
 #[derive(Debug)]
 #[must_use = "futures do nothing unless polled"]
 pub struct Compat<T, Sp> {
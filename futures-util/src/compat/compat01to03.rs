@@ -1,50 +1,186 @@
-use super::Compat;
 use futures::{
     executor::{
         self as executor01, Notify as Notify01, NotifyHandle as NotifyHandle01,
-        UnsafeNotify as UnsafeNotify01,
+        Spawn as Spawn01, UnsafeNotify as UnsafeNotify01,
     },
-    Async as Async01, Future as Future01, Stream as Stream01,
+    Async as Async01, AsyncSink as AsyncSink01, Future as Future01,
+    Sink as Sink01, Stream as Stream01,
 };
 use futures_core::{task as task03, Future as Future03, Stream as Stream03};
+use futures_sink::Sink as Sink03;
+use std::marker::Unpin;
 use std::mem::PinMut;
 
-impl<Fut: Future01> Future03 for Compat<Fut, ()> {
+/// Converts a futures 0.1 [`Future`](futures::Future),
+/// [`Stream`](futures::Stream) or tokio-io I/O object into a futures 0.3
+/// [`Future`](futures_core::Future), [`Stream`](futures_core::Stream) or
+/// futures-io I/O object.
+///
+/// The inner object is run as a futures 0.1 task, so everything that uses the
+/// current task, like `task::current()` and task-local data, works as it
+/// would on a futures 0.1 executor. Notifications of that task wake the waker
+/// of the futures 0.3 task that polls it.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct Compat01As03<T> {
+    inner: Spawn01<T>,
+}
+
+// The inner object is a futures 0.1 type, those are never pinned.
+impl<T> Unpin for Compat01As03<T> {}
+
+impl<T> Compat01As03<T> {
+    crate fn new(object: T) -> Compat01As03<T> {
+        Compat01As03 { inner: executor01::spawn(object) }
+    }
+
+    /// Gets a reference to the inner object.
+    pub fn get_ref(&self) -> &T {
+        self.inner.get_ref()
+    }
+
+    /// Gets a mutable reference to the inner object.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.inner.get_mut()
+    }
+
+    /// Returns the inner object.
+    pub fn into_inner(self) -> T {
+        self.inner.into_inner()
+    }
+
+    // Calls `f` from within the futures 0.1 task of the inner object, with
+    // `waker` as the task's notifier.
+    fn in_notify<R>(
+        &mut self,
+        waker: &task03::Waker,
+        f: impl FnOnce(&mut T) -> R,
+    ) -> R {
+        let notify = &WakerToHandle(waker);
+        self.inner.poll_fn_notify(notify, 0, f)
+    }
+}
+
+fn poll_01_to_03<T, E>(x: Result<Async01<T>, E>) -> task03::Poll<Result<T, E>> {
+    match x {
+        Ok(Async01::Ready(t)) => task03::Poll::Ready(Ok(t)),
+        Ok(Async01::NotReady) => task03::Poll::Pending,
+        Err(e) => task03::Poll::Ready(Err(e)),
+    }
+}
+
+impl<Fut: Future01> Future03 for Compat01As03<Fut> {
     type Output = Result<Fut::Item, Fut::Error>;
 
     fn poll(
-        self: PinMut<Self>,
+        mut self: PinMut<Self>,
         cx: &mut task03::Context,
     ) -> task03::Poll<Self::Output> {
-        let notify = &WakerToHandle(cx.waker());
-
-        executor01::with_notify(notify, 0, move || {
-            match unsafe { PinMut::get_mut_unchecked(self) }.inner.poll() {
-                Ok(Async01::Ready(t)) => task03::Poll::Ready(Ok(t)),
-                Ok(Async01::NotReady) => task03::Poll::Pending,
-                Err(e) => task03::Poll::Ready(Err(e)),
-            }
-        })
+        poll_01_to_03(self.in_notify(cx.waker(), |f| f.poll()))
     }
 }
 
-impl<St: Stream01> Stream03 for Compat<St, ()> {
+impl<St: Stream01> Stream03 for Compat01As03<St> {
     type Item = Result<St::Item, St::Error>;
 
     fn poll_next(
-        self: PinMut<Self>,
+        mut self: PinMut<Self>,
         cx: &mut task03::Context,
     ) -> task03::Poll<Option<Self::Item>> {
-        let notify = &WakerToHandle(cx.waker());
-
-        executor01::with_notify(notify, 0, move || {
-            match unsafe { PinMut::get_mut_unchecked(self) }.inner.poll() {
-                Ok(Async01::Ready(Some(t))) => task03::Poll::Ready(Some(Ok(t))),
-                Ok(Async01::Ready(None)) => task03::Poll::Ready(None),
-                Ok(Async01::NotReady) => task03::Poll::Pending,
-                Err(e) => task03::Poll::Ready(Some(Err(e))),
+        match self.in_notify(cx.waker(), |f| f.poll()) {
+            Ok(Async01::Ready(Some(t))) => task03::Poll::Ready(Some(Ok(t))),
+            Ok(Async01::Ready(None)) => task03::Poll::Ready(None),
+            Ok(Async01::NotReady) => task03::Poll::Pending,
+            Err(e) => task03::Poll::Ready(Some(Err(e))),
+        }
+    }
+}
+
+/// Converts a futures 0.1 [`Sink`](futures::Sink) into a futures 0.3
+/// [`Sink`](futures_sink::Sink).
+///
+/// This is created by the
+/// [`sink_compat`](super::Sink01CompatExt::sink_compat) method. Like
+/// [`Compat01As03`], it runs the inner sink as a futures 0.1 task.
+#[derive(Debug)]
+#[must_use = "sinks do nothing unless polled"]
+pub struct Sink01As03<Si: Sink01> {
+    inner: Compat01As03<Si>,
+    // An item that was accepted by `start_send`, but not by the inner sink
+    // yet.
+    buffer: Option<Si::SinkItem>,
+}
+
+// We never project pinning to fields
+impl<Si: Sink01> Unpin for Sink01As03<Si> {}
+
+impl<Si: Sink01> Sink01As03<Si> {
+    crate fn new(inner: Si) -> Sink01As03<Si> {
+        Sink01As03 { inner: Compat01As03::new(inner), buffer: None }
+    }
+
+    /// Returns the inner sink.
+    ///
+    /// An item that the inner sink hasn't accepted yet is dropped, so this
+    /// should only be called after the sink has been flushed.
+    pub fn into_inner(self) -> Si {
+        self.inner.into_inner()
+    }
+
+    // Hands the buffered item to the inner sink.
+    fn poll_start_send_buffered(&mut self, cx: &mut task03::Context)
+        -> task03::Poll<Result<(), Si::SinkError>>
+    {
+        if let Some(item) = self.buffer.take() {
+            match self.inner.in_notify(cx.waker(), |s| s.start_send(item)) {
+                Ok(AsyncSink01::Ready) => {}
+                Ok(AsyncSink01::NotReady(item)) => {
+                    self.buffer = Some(item);
+                    return task03::Poll::Pending;
+                }
+                Err(e) => return task03::Poll::Ready(Err(e)),
             }
-        })
+        }
+        task03::Poll::Ready(Ok(()))
+    }
+}
+
+impl<Si: Sink01> Sink03 for Sink01As03<Si> {
+    type SinkItem = Si::SinkItem;
+    type SinkError = Si::SinkError;
+
+    fn poll_ready(
+        mut self: PinMut<Self>,
+        cx: &mut task03::Context,
+    ) -> task03::Poll<Result<(), Self::SinkError>> {
+        self.poll_start_send_buffered(cx)
+    }
+
+    fn start_send(
+        mut self: PinMut<Self>,
+        item: Self::SinkItem,
+    ) -> Result<(), Self::SinkError> {
+        // The 0.1 `start_send` needs a task to be notified if it can't take
+        // the item, so the item is only handed over in the next poll.
+        debug_assert!(self.buffer.is_none());
+        self.buffer = Some(item);
+        Ok(())
+    }
+
+    fn poll_flush(
+        mut self: PinMut<Self>,
+        cx: &mut task03::Context,
+    ) -> task03::Poll<Result<(), Self::SinkError>> {
+        try_ready!(self.poll_start_send_buffered(cx));
+        poll_01_to_03(self.inner.in_notify(cx.waker(), |s| s.poll_complete()))
+    }
+
+    fn poll_close(
+        mut self: PinMut<Self>,
+        cx: &mut task03::Context,
+    ) -> task03::Poll<Result<(), Self::SinkError>> {
+        try_ready!(self.poll_start_send_buffered(cx));
+        poll_01_to_03(self.inner.in_notify(cx.waker(), |s| s.close()))
     }
 }
 
@@ -77,3 +213,63 @@ unsafe impl UnsafeNotify01 for NotifyWaker {
         drop(Box::from_raw(ptr as *mut dyn UnsafeNotify01));
     }
 }
+
+#[cfg(feature = "io-compat")]
+mod io {
+    use super::*;
+    use futures_io::{
+        AsyncRead as AsyncRead03, AsyncWrite as AsyncWrite03, Initializer,
+    };
+    use std::io::{self, Read, Write};
+    use tokio_io::{AsyncRead as AsyncRead01, AsyncWrite as AsyncWrite01};
+
+    fn io_to_poll<T>(x: io::Result<T>) -> task03::Poll<io::Result<T>> {
+        match x {
+            Ok(t) => task03::Poll::Ready(Ok(t)),
+            Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => task03::Poll::Pending,
+            Err(e) => task03::Poll::Ready(Err(e)),
+        }
+    }
+
+    // The inner I/O object is always called from within its futures 0.1 task,
+    // so an object that registers for readiness before returning
+    // `WouldBlock` always finds a task to notify, even when it isn't polled by
+    // a futures 0.1 executor.
+    impl<R: AsyncRead01> AsyncRead03 for Compat01As03<R> {
+        unsafe fn initializer(&self) -> Initializer {
+            // An empty buffer is enough to find out whether `R` relies on
+            // the buffer being initialized.
+            if self.get_ref().prepare_uninitialized_buffer(&mut []) {
+                Initializer::zeroing()
+            } else {
+                Initializer::nop()
+            }
+        }
+
+        fn poll_read(&mut self, cx: &mut task03::Context, buf: &mut [u8])
+            -> task03::Poll<io::Result<usize>>
+        {
+            io_to_poll(self.in_notify(cx.waker(), |r| r.read(buf)))
+        }
+    }
+
+    impl<W: AsyncWrite01> AsyncWrite03 for Compat01As03<W> {
+        fn poll_write(&mut self, cx: &mut task03::Context, buf: &[u8])
+            -> task03::Poll<io::Result<usize>>
+        {
+            io_to_poll(self.in_notify(cx.waker(), |w| w.write(buf)))
+        }
+
+        fn poll_flush(&mut self, cx: &mut task03::Context)
+            -> task03::Poll<io::Result<()>>
+        {
+            io_to_poll(self.in_notify(cx.waker(), |w| w.flush()))
+        }
+
+        fn poll_close(&mut self, cx: &mut task03::Context)
+            -> task03::Poll<io::Result<()>>
+        {
+            poll_01_to_03(self.in_notify(cx.waker(), |w| w.shutdown()))
+        }
+    }
+}
@@ -103,9 +103,72 @@ where
     T: Unpin,
     E: task03::Spawn,
     F: FnOnce(PinMut<T>, &mut task03::Context) -> R,
+{
+    with_context_mut(compat, |inner, cx| f(PinMut::new(inner), cx))
+}
+
+fn with_context_mut<T, E, R, F>(compat: &mut Compat<T, E>, f: F) -> R
+where
+    E: task03::Spawn,
+    F: FnOnce(&mut T, &mut task03::Context) -> R,
 {
     let waker = current_as_waker();
     let spawn = compat.spawn.as_mut().unwrap();
     let mut cx = task03::Context::new(&waker, spawn);
-    f(PinMut::new(&mut compat.inner), &mut cx)
+    f(&mut compat.inner, &mut cx)
+}
+
+#[cfg(feature = "io-compat")]
+mod io {
+    use super::*;
+    use futures_io::{AsyncRead as AsyncRead03, AsyncWrite as AsyncWrite03};
+    use std::io::{self, Read, Write};
+    use tokio_io::{AsyncRead as AsyncRead01, AsyncWrite as AsyncWrite01};
+
+    fn poll_to_io<T>(x: task03::Poll<io::Result<T>>) -> io::Result<T> {
+        match x {
+            task03::Poll::Ready(result) => result,
+            task03::Poll::Pending => Err(io::ErrorKind::WouldBlock.into()),
+        }
+    }
+
+    // Like the `std::io` methods of tokio's own I/O objects, these have to be
+    // called from within a futures 0.1 task, the current task is notified
+    // once an operation that returned `WouldBlock` can make progress.
+    impl<R: AsyncRead03, Sp: task03::Spawn> Read for Compat<R, Sp> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            poll_to_io(with_context_mut(self, |inner, cx| inner.poll_read(cx, buf)))
+        }
+    }
+
+    impl<R: AsyncRead03, Sp: task03::Spawn> AsyncRead01 for Compat<R, Sp> {
+        unsafe fn prepare_uninitialized_buffer(&self, buf: &mut [u8]) -> bool {
+            let initializer = self.inner.initializer();
+            let does_init = initializer.should_initialize();
+            if does_init {
+                initializer.initialize(buf);
+            }
+            does_init
+        }
+    }
+
+    impl<W: AsyncWrite03, Sp: task03::Spawn> Write for Compat<W, Sp> {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            poll_to_io(with_context_mut(self, |inner, cx| inner.poll_write(cx, buf)))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            poll_to_io(with_context_mut(self, |inner, cx| inner.poll_flush(cx)))
+        }
+    }
+
+    impl<W: AsyncWrite03, Sp: task03::Spawn> AsyncWrite01 for Compat<W, Sp> {
+        fn shutdown(&mut self) -> Poll01<(), io::Error> {
+            with_context_mut(self, |inner, cx| match inner.poll_close(cx) {
+                task03::Poll::Ready(Ok(())) => Ok(Async01::Ready(())),
+                task03::Poll::Pending => Ok(Async01::NotReady),
+                task03::Poll::Ready(Err(e)) => Err(e),
+            })
+        }
+    }
 }
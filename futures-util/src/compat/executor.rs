@@ -1,4 +1,3 @@
-
 use super::Compat;
 use crate::{TryFutureExt, FutureExt, future::UnitError};
 use futures::future::Executor as Executor01;
@@ -10,28 +9,55 @@ use futures_core::future::FutureObj;
 /// [`Executor`](futures::future::Executor).
 pub type Executor01Future = Compat<UnitError<FutureObj<'static, ()>>, Box<dyn Spawn03 + Send>>;
 
+/// A future that can run on a futures 0.1
+/// [`Executor`](futures::future::Executor) which doesn't require its futures
+/// to be `Send`.
+pub type Executor01LocalFuture = Compat<UnitError<FutureObj<'static, ()>>, Box<dyn Spawn03>>;
+
 /// Extension trait for futures 0.1 [`Executor`](futures::future::Executor).
 pub trait Executor01CompatExt: Executor01<Executor01Future> +
-                               Clone + Send + 'static
+                               Clone + 'static
 {
     /// Converts a futures 0.1 [`Executor`](futures::future::Executor) into a
-    /// futures 0.3 [`Executor`](futures_core::task::Executor).
+    /// futures 0.3 [`Spawn`](futures_core::task::Spawn).
+    ///
+    /// Futures spawned through the returned spawner are polled with a boxed
+    /// clone of it, so the tasks they spawn end up on the same executor.
     fn compat(self) -> Executor01As03<Self>
-        where Self: Sized;
+        where Self: Send + Sized;
+
+    /// Converts a futures 0.1 [`Executor`](futures::future::Executor) that
+    /// isn't `Send` into a futures 0.3 [`Spawn`](futures_core::task::Spawn).
+    ///
+    /// This works like [`compat`](Executor01CompatExt::compat) for executors
+    /// which run their futures on the current thread, such as `tokio`'s
+    /// `current_thread::TaskExecutor`.
+    fn compat_local(self) -> Executor01LocalAs03<Self>
+        where Self: Executor01<Executor01LocalFuture> + Sized;
 }
 
 impl<Ex> Executor01CompatExt for Ex
-where Ex: Executor01<Executor01Future> + Clone + Send + 'static
+where Ex: Executor01<Executor01Future> + Clone + 'static
 {
-    fn compat(self) -> Executor01As03<Self> {
+    fn compat(self) -> Executor01As03<Self>
+        where Self: Send,
+    {
         Executor01As03 {
             executor01: self,
         }
     }
+
+    fn compat_local(self) -> Executor01LocalAs03<Self>
+        where Self: Executor01<Executor01LocalFuture>,
+    {
+        Executor01LocalAs03 {
+            executor01: self,
+        }
+    }
 }
 
 /// Converts a futures 0.1 [`Executor`](futures::future::Executor) into a
-/// futures 0.3 [`Executor`](futures_core::task::Executor).
+/// futures 0.3 [`Spawn`](futures_core::task::Spawn).
 #[derive(Clone)]
 pub struct Executor01As03<Ex> {
     executor01: Ex
@@ -45,20 +71,55 @@ where Ex: Executor01<Executor01Future>,
         &mut self,
         future: FutureObj<'static, ()>,
     ) -> Result<(), task03::SpawnObjError> {
-        let exec: Box<dyn Spawn03 + Send> = Box::new(self.clone());
-        let future = future.unit_error().compat(exec);
+        let spawner: Box<dyn Spawn03 + Send> = Box::new(self.clone());
+        execute(&mut self.executor01, future, spawner)
+    }
+}
+
+/// Converts a futures 0.1 [`Executor`](futures::future::Executor) that isn't
+/// `Send` into a futures 0.3 [`Spawn`](futures_core::task::Spawn).
+#[derive(Clone)]
+pub struct Executor01LocalAs03<Ex> {
+    executor01: Ex
+}
+
+impl<Ex> Spawn03 for Executor01LocalAs03<Ex>
+where Ex: Executor01<Executor01LocalFuture>,
+      Ex: Clone + 'static,
+{
+    fn spawn_obj(
+        &mut self,
+        future: FutureObj<'static, ()>,
+    ) -> Result<(), task03::SpawnObjError> {
+        let spawner: Box<dyn Spawn03> = Box::new(self.clone());
+        execute(&mut self.executor01, future, spawner)
+    }
+}
+
+fn execute<Ex, Sp>(
+    executor01: &mut Ex,
+    future: FutureObj<'static, ()>,
+    spawner: Sp,
+) -> Result<(), task03::SpawnObjError>
+    where Ex: Executor01<Compat<UnitError<FutureObj<'static, ()>>, Sp>>,
+          Sp: Spawn03,
+{
+    let future = future.unit_error().compat(spawner);
 
-        match self.executor01.execute(future) {
-            Ok(()) => Ok(()),
-            Err(err) => {
-                use futures_core::task::{SpawnObjError, SpawnErrorKind};
+    match executor01.execute(future) {
+        Ok(()) => Ok(()),
+        Err(err) => {
+            use futures_core::task::{SpawnObjError, SpawnErrorKind};
 
-                let fut = err.into_future().into_inner().unwrap_or_else(|_| ());
-                Err(SpawnObjError {
-                    kind: SpawnErrorKind::shutdown(),
-                    future: Box::new(fut).into(),
-                })
-            }
+            // The executor hands back the future it couldn't run, so the
+            // original task can be returned to the caller. `SpawnErrorKind`
+            // can only describe a shut down executor so far, so an executor
+            // at its capacity is reported the same way.
+            let future = err.into_future().into_inner().into_inner();
+            Err(SpawnObjError {
+                kind: SpawnErrorKind::shutdown(),
+                future,
+            })
         }
     }
 }
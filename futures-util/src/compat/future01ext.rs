@@ -1,4 +1,4 @@
-use super::Compat;
+use super::Compat01As03;
 use futures::Future as Future01;
 
 impl<Fut: Future01> Future01CompatExt for Fut {}
@@ -8,7 +8,11 @@ pub trait Future01CompatExt: Future01 {
     /// Converts a futures 0.1 [`Future<Item = T, Error = E>`](futures::Future)
     /// into a futures 0.3 [`Future<Output = Result<T,
     /// E>>`](futures_core::Future).
-    fn compat(self) -> Compat<Self, ()> where Self: Sized {
-        Compat::new(self, None)
+    ///
+    /// The future is run as a futures 0.1 task, notifying that task wakes the
+    /// waker of the futures 0.3 task it is polled from. This makes it
+    /// possible to `await!` futures 0.1 futures on any futures 0.3 executor.
+    fn compat(self) -> Compat01As03<Self> where Self: Sized {
+        Compat01As03::new(self)
     }
 }
@@ -0,0 +1,38 @@
+use super::Compat01As03;
+use tokio_io::{AsyncRead as AsyncRead01, AsyncWrite as AsyncWrite01};
+
+impl<R: AsyncRead01> AsyncRead01CompatExt for R {}
+
+/// Extension trait for tokio-io [`AsyncRead`](tokio_io::AsyncRead)
+pub trait AsyncRead01CompatExt: AsyncRead01 {
+    /// Converts a tokio-io [`AsyncRead`](tokio_io::AsyncRead) into a
+    /// futures-io 0.3 [`AsyncRead`](futures_io::AsyncRead).
+    ///
+    /// If `Self` also implements tokio-io's
+    /// [`AsyncWrite`](tokio_io::AsyncWrite), the result implements
+    /// futures-io's [`AsyncWrite`](futures_io::AsyncWrite) as well.
+    ///
+    /// A `WouldBlock` error is turned into `Poll::Pending`. The I/O object is
+    /// always called from within a futures 0.1 task that wakes the waker of
+    /// the 0.3 context, so objects that register for readiness before
+    /// returning `WouldBlock` work no matter which executor polls them.
+    fn compat(self) -> Compat01As03<Self> where Self: Sized {
+        Compat01As03::new(self)
+    }
+}
+
+impl<W: AsyncWrite01> AsyncWrite01CompatExt for W {}
+
+/// Extension trait for tokio-io [`AsyncWrite`](tokio_io::AsyncWrite)
+pub trait AsyncWrite01CompatExt: AsyncWrite01 {
+    /// Converts a tokio-io [`AsyncWrite`](tokio_io::AsyncWrite) into a
+    /// futures-io 0.3 [`AsyncWrite`](futures_io::AsyncWrite).
+    ///
+    /// This works like
+    /// [`AsyncRead01CompatExt::compat`](AsyncRead01CompatExt::compat),
+    /// `poll_close` is mapped to
+    /// [`shutdown`](tokio_io::AsyncWrite::shutdown).
+    fn compat_write(self) -> Compat01As03<Self> where Self: Sized {
+        Compat01As03::new(self)
+    }
+}
@@ -3,12 +3,17 @@
 #![allow(missing_debug_implementations)]
 
 mod executor;
-pub use self::executor::{Executor01CompatExt, Executor01Future, Executor01As03};
+pub use self::executor::{
+    Executor01CompatExt, Executor01Future, Executor01As03,
+    Executor01LocalFuture, Executor01LocalAs03,
+};
 
 mod compat;
 pub use self::compat::Compat;
 
 mod compat01to03;
+pub use self::compat01to03::{Compat01As03, Sink01As03};
+
 mod compat03to01;
 
 mod future01ext;
@@ -17,7 +22,15 @@ pub use self::future01ext::Future01CompatExt;
 mod stream01ext;
 pub use self::stream01ext::Stream01CompatExt;
 
+mod sink01ext;
+pub use self::sink01ext::Sink01CompatExt;
+
+#[cfg(feature = "io-compat")]
+mod io01ext;
+#[cfg(feature = "io-compat")]
+pub use self::io01ext::{AsyncRead01CompatExt, AsyncWrite01CompatExt};
+
 #[cfg(feature = "tokio-compat")]
 mod tokio;
 #[cfg(feature = "tokio-compat")]
-pub use self::tokio::TokioDefaultSpawn;
+pub use self::tokio::{TokioDefaultSpawn, TokioExecutorAs03, TokioExecutorCompatExt};
@@ -0,0 +1,20 @@
+use super::Sink01As03;
+use futures::Sink as Sink01;
+
+impl<Si: Sink01> Sink01CompatExt for Si {}
+
+/// Extension trait for futures 0.1 [`Sink`](futures::Sink)
+pub trait Sink01CompatExt: Sink01 {
+    /// Converts a futures 0.1 [`Sink<SinkItem = T, SinkError = E>`](futures::Sink)
+    /// into a futures 0.3 [`Sink<SinkItem = T, SinkError =
+    /// E>`](futures_sink::Sink).
+    ///
+    /// The item sent with `start_send` is only handed to the 0.1 sink the
+    /// next time the sink is polled, that is, in `poll_ready`, `poll_flush` or
+    /// `poll_close`. If the 0.1 sink returns it with `AsyncSink::NotReady`,
+    /// it is kept until the 0.1 sink accepts it. `poll_flush` and
+    /// `poll_close` map to `poll_complete` and `close`.
+    fn sink_compat(self) -> Sink01As03<Self> where Self: Sized {
+        Sink01As03::new(self)
+    }
+}
@@ -1,4 +1,4 @@
-use super::Compat;
+use super::Compat01As03;
 use futures::Stream as Stream01;
 
 impl<St: Stream01> Stream01CompatExt for St {}
@@ -8,7 +8,12 @@ pub trait Stream01CompatExt: Stream01 {
     /// Converts a futures 0.1 [`Stream<Item = T, Error = E>`](futures::Stream)
     /// into a futures 0.3 [`Stream<Item = Result<T,
     /// E>>`](futures_core::Stream).
-    fn compat(self) -> Compat<Self, ()> where Self: Sized {
-        Compat::new(self, None)
+    ///
+    /// `Async::Ready(Some(t))` is yielded as `Some(Ok(t))`, an error as
+    /// `Some(Err(e))`, and `Async::Ready(None)` ends the stream. As in futures
+    /// 0.1, an error doesn't end the stream, so it can be polled again
+    /// afterwards.
+    fn compat(self) -> Compat01As03<Self> where Self: Sized {
+        Compat01As03::new(self)
     }
 }
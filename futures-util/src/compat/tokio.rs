@@ -1,11 +1,17 @@
 use crate::{future::FutureExt, try_future::TryFutureExt};
-use futures_core::future::FutureObj;
-use futures_core::task::{Spawn, SpawnErrorKind, SpawnObjError};
-use tokio_executor::{DefaultExecutor, Executor as TokioExecutor};
+use futures_core::future::{Future, FutureObj};
+use futures_core::task::{self, Poll, Spawn, SpawnErrorKind, SpawnObjError};
+use std::mem::PinMut;
+use std::sync::{Arc, Mutex};
+use tokio_executor::{DefaultExecutor, Executor as TokioExecutor, SpawnError};
 
 /// A spawner that delegates to `tokio`'s
-/// [`DefaultExecutor`](tokio_executor::DefaultExecutor), will panic if used in
-/// the context of a task that is not running on `tokio`'s executor.
+/// [`DefaultExecutor`](tokio_executor::DefaultExecutor).
+///
+/// Spawning fails with a
+/// [`SpawnErrorKind::shutdown`](futures_core::task::SpawnErrorKind::shutdown)
+/// error when used outside of `tokio`'s executor or when the executor can't
+/// accept the task, the error carries the task back to the caller.
 ///
 /// *NOTE* The future of this struct in `futures` is uncertain. It may be
 /// deprecated before or soon after the initial 0.3 release and moved to a
@@ -50,25 +56,108 @@ impl Spawn for TokioDefaultSpawn {
         &mut self,
         task: FutureObj<'static, ()>,
     ) -> Result<(), SpawnObjError> {
-        let fut = Box::new(task.unit_error().compat(*self));
-        DefaultExecutor::current().spawn(fut).map_err(|err| {
-            panic!(
-                "tokio failed to spawn and doesn't return the future: {:?}",
-                err
-            )
-        })
+        spawn_on(&mut DefaultExecutor::current(), task, *self)
+    }
+
+    fn status(&self) -> Result<(), SpawnErrorKind> {
+        DefaultExecutor::current().status()
+            .map_err(|err| spawn_error_kind(&err))
+    }
+}
+
+/// Extension trait for `tokio`
+/// [`Executor`](tokio_executor::Executor)s.
+pub trait TokioExecutorCompatExt: TokioExecutor + Clone + Send + 'static {
+    /// Converts a `tokio` [`Executor`](tokio_executor::Executor) into a
+    /// futures 0.3 [`Spawn`](futures_core::task::Spawn).
+    ///
+    /// Futures spawned through the returned spawner are polled with a clone
+    /// of it, so the tasks they spawn end up on the same executor. Spawn
+    /// failures hand the task back like [`TokioDefaultSpawn`] does.
+    ///
+    /// `tokio`'s `Executor` trait only accepts `Send` futures, so the
+    /// executor has to be `Send` to be carried along. Executors that aren't,
+    /// such as `current_thread::TaskExecutor`, also implement the futures 0.1
+    /// [`Executor`](futures::future::Executor) trait and can be converted
+    /// with [`Executor01CompatExt::compat_local`](super::Executor01CompatExt::compat_local)
+    /// instead.
+    fn compat_tokio(self) -> TokioExecutorAs03<Self>
+        where Self: Sized;
+}
+
+impl<Ex> TokioExecutorCompatExt for Ex
+where Ex: TokioExecutor + Clone + Send + 'static
+{
+    fn compat_tokio(self) -> TokioExecutorAs03<Self> {
+        TokioExecutorAs03 { executor: self }
+    }
+}
+
+/// Converts a `tokio` [`Executor`](tokio_executor::Executor) into a
+/// futures 0.3 [`Spawn`](futures_core::task::Spawn).
+#[derive(Debug, Clone)]
+pub struct TokioExecutorAs03<Ex> {
+    executor: Ex,
+}
+
+impl<Ex> Spawn for TokioExecutorAs03<Ex>
+where Ex: TokioExecutor + Clone + Send + 'static
+{
+    fn spawn_obj(
+        &mut self,
+        task: FutureObj<'static, ()>,
+    ) -> Result<(), SpawnObjError> {
+        let spawner = self.clone();
+        spawn_on(&mut self.executor, task, spawner)
     }
 
     fn status(&self) -> Result<(), SpawnErrorKind> {
-        DefaultExecutor::current().status().map_err(|err| {
-            if err.is_shutdown() {
-                SpawnErrorKind::shutdown()
-            } else {
-                panic!(
-                    "tokio executor failed for non-shutdown reason: {:?}",
-                    err
-                )
-            }
-        })
+        self.executor.status().map_err(|err| spawn_error_kind(&err))
+    }
+}
+
+fn spawn_on<Ex, Sp>(
+    executor: &mut Ex,
+    task: FutureObj<'static, ()>,
+    spawner: Sp,
+) -> Result<(), SpawnObjError>
+    where Ex: TokioExecutor,
+          Sp: Spawn + Send + 'static,
+{
+    // `tokio` drops the future when it fails to spawn it, so the task is
+    // only handed over through a slot that it can be recovered from.
+    let slot = Arc::new(Mutex::new(Some(task)));
+    let reclaimable = Reclaimable { slot: slot.clone(), task: None };
+    let fut = Box::new(reclaimable.unit_error().compat(spawner));
+    executor.spawn(fut).map_err(|err| {
+        let future = slot.lock().unwrap().take()
+            .expect("task of a failed spawn was polled");
+        SpawnObjError { future, kind: spawn_error_kind(&err) }
+    })
+}
+
+// `SpawnErrorKind` can only describe a shut down executor so far, so `tokio`
+// running at capacity is reported the same way.
+fn spawn_error_kind(_err: &SpawnError) -> SpawnErrorKind {
+    SpawnErrorKind::shutdown()
+}
+
+// Moves the task out of the shared slot the first time it is polled, after
+// that, the slot is never touched again.
+struct Reclaimable {
+    slot: Arc<Mutex<Option<FutureObj<'static, ()>>>>,
+    task: Option<FutureObj<'static, ()>>,
+}
+
+impl Future for Reclaimable {
+    type Output = ();
+
+    fn poll(mut self: PinMut<Self>, cx: &mut task::Context) -> Poll<()> {
+        if self.task.is_none() {
+            self.task = self.slot.lock().unwrap().take();
+        }
+        self.task.as_mut()
+            .expect("task reclaimed after it was spawned")
+            .poll_unpin(cx)
     }
 }
@@ -1,11 +1,11 @@
 use crate::task::AtomicWaker;
+use alloc::sync::Arc;
+use core::marker::Unpin;
+use core::mem::PinMut;
+use core::sync::atomic::{AtomicBool, Ordering};
 use futures_core::future::Future;
 use futures_core::task::{self, Poll};
 use pin_utils::unsafe_pinned;
-use std::marker::Unpin;
-use std::mem::PinMut;
-use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
 
 /// A future which can be remotely short-circuited using an `AbortHandle`.
 #[derive(Debug, Clone)]
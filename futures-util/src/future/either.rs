@@ -0,0 +1,194 @@
+use core::mem::PinMut;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{self, Poll};
+use futures_sink::Sink;
+
+/// Combines two different futures, streams, or sinks having the same
+/// associated types into a single type.
+///
+/// This is useful when an `if` or `match` expression picks between two
+/// differently-typed futures or streams. The
+/// [`left_future`](crate::future::FutureExt::left_future) and
+/// [`right_future`](crate::future::FutureExt::right_future) methods (and
+/// their stream and sink counterparts) build the two variants.
+///
+/// Polling an `Either` only touches the active variant, which stays pinned in
+/// place, so the variants don't need to be `Unpin`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(futures_api)]
+/// use futures::executor::block_on;
+/// use futures::future::{self, FutureExt};
+///
+/// let x = 6;
+/// let future = if x < 10 {
+///     future::ready(true).left_future()
+/// } else {
+///     future::lazy(|_| false).right_future()
+/// };
+///
+/// assert_eq!(block_on(future), true);
+/// ```
+#[derive(Debug, Clone)]
+pub enum Either<A, B> {
+    /// First branch of the type
+    Left(A),
+    /// Second branch of the type
+    Right(B),
+}
+
+impl<T, A, B> Either<(T, A), (T, B)> {
+    /// Factor out a homogeneous type from an either of pairs.
+    ///
+    /// Here, the homogeneous type is the first element of the pairs.
+    pub fn factor_first(self) -> (T, Either<A, B>) {
+        match self {
+            Either::Left((x, a)) => (x, Either::Left(a)),
+            Either::Right((x, b)) => (x, Either::Right(b)),
+        }
+    }
+}
+
+impl<T, A, B> Either<(A, T), (B, T)> {
+    /// Factor out a homogeneous type from an either of pairs.
+    ///
+    /// Here, the homogeneous type is the second element of the pairs.
+    pub fn factor_second(self) -> (T, Either<A, B>) {
+        match self {
+            Either::Left((a, x)) => (x, Either::Left(a)),
+            Either::Right((b, x)) => (x, Either::Right(b)),
+        }
+    }
+}
+
+impl<A, B> Future for Either<A, B>
+where
+    A: Future,
+    B: Future<Output = A::Output>,
+{
+    type Output = A::Output;
+
+    fn poll(self: PinMut<Self>, cx: &mut task::Context) -> Poll<A::Output> {
+        unsafe {
+            match PinMut::get_mut_unchecked(self) {
+                Either::Left(x) => PinMut::new_unchecked(x).poll(cx),
+                Either::Right(x) => PinMut::new_unchecked(x).poll(cx),
+            }
+        }
+    }
+}
+
+impl<A, B> FusedFuture for Either<A, B>
+where
+    A: FusedFuture,
+    B: FusedFuture,
+{
+    fn is_terminated(&self) -> bool {
+        match self {
+            Either::Left(x) => x.is_terminated(),
+            Either::Right(x) => x.is_terminated(),
+        }
+    }
+}
+
+impl<A, B> Stream for Either<A, B>
+where
+    A: Stream,
+    B: Stream<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    fn poll_next(
+        self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Option<A::Item>> {
+        unsafe {
+            match PinMut::get_mut_unchecked(self) {
+                Either::Left(x) => PinMut::new_unchecked(x).poll_next(cx),
+                Either::Right(x) => PinMut::new_unchecked(x).poll_next(cx),
+            }
+        }
+    }
+
+    // Only one of the streams exists, so its bounds are exact for the
+    // `Either` as a whole.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Either::Left(x) => x.size_hint(),
+            Either::Right(x) => x.size_hint(),
+        }
+    }
+}
+
+impl<A, B> FusedStream for Either<A, B>
+where
+    A: FusedStream,
+    B: FusedStream,
+{
+    fn is_terminated(&self) -> bool {
+        match self {
+            Either::Left(x) => x.is_terminated(),
+            Either::Right(x) => x.is_terminated(),
+        }
+    }
+}
+
+impl<A, B> Sink for Either<A, B>
+where
+    A: Sink,
+    B: Sink<SinkItem = A::SinkItem, SinkError = A::SinkError>,
+{
+    type SinkItem = A::SinkItem;
+    type SinkError = A::SinkError;
+
+    fn poll_ready(
+        self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        unsafe {
+            match PinMut::get_mut_unchecked(self) {
+                Either::Left(x) => PinMut::new_unchecked(x).poll_ready(cx),
+                Either::Right(x) => PinMut::new_unchecked(x).poll_ready(cx),
+            }
+        }
+    }
+
+    fn start_send(
+        self: PinMut<Self>,
+        item: Self::SinkItem,
+    ) -> Result<(), Self::SinkError> {
+        unsafe {
+            match PinMut::get_mut_unchecked(self) {
+                Either::Left(x) => PinMut::new_unchecked(x).start_send(item),
+                Either::Right(x) => PinMut::new_unchecked(x).start_send(item),
+            }
+        }
+    }
+
+    fn poll_flush(
+        self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        unsafe {
+            match PinMut::get_mut_unchecked(self) {
+                Either::Left(x) => PinMut::new_unchecked(x).poll_flush(cx),
+                Either::Right(x) => PinMut::new_unchecked(x).poll_flush(cx),
+            }
+        }
+    }
+
+    fn poll_close(
+        self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        unsafe {
+            match PinMut::get_mut_unchecked(self) {
+                Either::Left(x) => PinMut::new_unchecked(x).poll_close(cx),
+                Either::Right(x) => PinMut::new_unchecked(x).poll_close(cx),
+            }
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use core::marker;
 use core::mem::PinMut;
-use futures_core::future::Future;
+use futures_core::future::{Future, FusedFuture};
 use futures_core::task::{self, Poll};
 
 /// A future which is never resolved.
@@ -33,6 +33,13 @@ pub fn empty<T>() -> Empty<T> {
     Empty { _data: marker::PhantomData }
 }
 
+// The future never produces a value, so there is no point in polling it
+impl<T> FusedFuture for Empty<T> {
+    fn is_terminated(&self) -> bool {
+        true
+    }
+}
+
 impl<T> Future for Empty<T> {
     type Output = T;
 
@@ -1,5 +1,5 @@
 use core::mem::PinMut;
-use futures_core::future::Future;
+use futures_core::future::{Future, FusedFuture};
 use futures_core::task::{self, Poll};
 use pin_utils::unsafe_pinned;
 
@@ -26,6 +26,12 @@ impl<Fut: Future> Fuse<Fut> {
     }
 }
 
+impl<Fut: Future> FusedFuture for Fuse<Fut> {
+    fn is_terminated(&self) -> bool {
+        self.future.is_none()
+    }
+}
+
 impl<Fut: Future> Future for Fuse<Fut> {
     type Output = Fut::Output;
 
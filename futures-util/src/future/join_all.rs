@@ -0,0 +1,106 @@
+//! Definition of the `JoinAll` combinator, waiting for all of a list of futures
+//! to finish.
+
+use crate::future::{MaybeDone, maybe_done};
+use alloc::boxed::{Box, PinBox};
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::FromIterator;
+use core::mem::{self, PinMut};
+use futures_core::future::Future;
+use futures_core::task::{self, Poll};
+
+/// A future which takes a list of futures and resolves with a vector of the
+/// completed values.
+///
+/// This future is created with the `join_all` function.
+#[must_use = "futures do nothing unless polled"]
+pub struct JoinAll<F>
+    where F: Future,
+{
+    elems: PinBox<[MaybeDone<F>]>,
+}
+
+impl<F> fmt::Debug for JoinAll<F>
+    where F: Future + fmt::Debug,
+          F::Output: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("JoinAll")
+            .field("elems", &&*self.elems)
+            .finish()
+    }
+}
+
+/// Creates a future which represents a collection of the outputs of the
+/// futures given.
+///
+/// The returned future will drive execution for all of its underlying futures,
+/// collecting the results into a destination `Vec<T>` in the same order as they
+/// were provided.
+///
+/// This function is only available when the `std` or `alloc` feature of this
+/// library is activated, and it is activated by default.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await, await_macro, futures_api)]
+/// # futures::executor::block_on(async {
+/// use futures::future::{join_all, ready};
+///
+/// let futures = vec![ready(1), ready(2), ready(3)];
+///
+/// assert_eq!(await!(join_all(futures)), [1, 2, 3]);
+/// # });
+/// ```
+pub fn join_all<I>(i: I) -> JoinAll<I::Item>
+    where I: IntoIterator,
+          I::Item: Future,
+{
+    let elems: Box<[_]> = i.into_iter().map(maybe_done).collect();
+    JoinAll { elems: elems.into() }
+}
+
+fn iter_pin_mut<T>(slice: PinMut<[T]>) -> impl Iterator<Item = PinMut<T>> {
+    // Safety: the elements are only ever accessed through `PinMut`s
+    unsafe { PinMut::get_mut_unchecked(slice) }
+        .iter_mut()
+        .map(|t| unsafe { PinMut::new_unchecked(t) })
+}
+
+impl<F> Future for JoinAll<F>
+    where F: Future,
+{
+    type Output = Vec<F::Output>;
+
+    fn poll(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Self::Output> {
+        let mut all_done = true;
+
+        for elem in iter_pin_mut(self.elems.as_pin_mut()) {
+            if elem.poll(cx).is_pending() {
+                all_done = false;
+            }
+        }
+
+        if all_done {
+            let empty: Box<[_]> = Box::new([]);
+            let mut elems = mem::replace(&mut self.elems, empty.into());
+            let result = iter_pin_mut(elems.as_pin_mut())
+                .map(|e| e.take_output().unwrap())
+                .collect();
+            Poll::Ready(result)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<F: Future> FromIterator<F> for JoinAll<F> {
+    fn from_iter<T: IntoIterator<Item=F>>(iter: T) -> Self {
+        join_all(iter)
+    }
+}
@@ -8,6 +8,60 @@ use futures_core::task::{self, Poll};
 /// A future that may have completed.
 ///
 /// This is created by the [`maybe_done()`] function.
+///
+/// Polling a `MaybeDone` drives the inner future and stores its output once
+/// it completes, after which further polls return `Poll::Ready(())` right
+/// away. The output can then be accessed with
+/// [`output_mut`](MaybeDone::output_mut) and moved out once with
+/// [`take_output`](MaybeDone::take_output). This makes it a building block
+/// for combinators that wait for several futures, such as `join`.
+///
+/// # Examples
+///
+/// A join of two futures:
+///
+/// ```
+/// #![feature(async_await, await_macro, futures_api, use_extern_macros, pin, arbitrary_self_types)]
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, Future, MaybeDone};
+/// use futures::task::{self, Poll};
+/// use pin_utils::unsafe_pinned;
+/// use std::mem::PinMut;
+///
+/// struct MyJoin<A: Future, B: Future> {
+///     a: MaybeDone<A>,
+///     b: MaybeDone<B>,
+/// }
+///
+/// impl<A: Future, B: Future> MyJoin<A, B> {
+///     unsafe_pinned!(a: MaybeDone<A>);
+///     unsafe_pinned!(b: MaybeDone<B>);
+/// }
+///
+/// impl<A: Future, B: Future> Future for MyJoin<A, B> {
+///     type Output = (A::Output, B::Output);
+///
+///     fn poll(mut self: PinMut<Self>, cx: &mut task::Context) -> Poll<Self::Output> {
+///         let a_done = self.a().poll(cx).is_ready();
+///         let b_done = self.b().poll(cx).is_ready();
+///         if a_done && b_done {
+///             Poll::Ready((
+///                 self.a().take_output().unwrap(),
+///                 self.b().take_output().unwrap(),
+///             ))
+///         } else {
+///             Poll::Pending
+///         }
+///     }
+/// }
+///
+/// let join = MyJoin {
+///     a: future::maybe_done(future::ready(1)),
+///     b: future::maybe_done(async { 2 }),
+/// };
+/// assert_eq!(await!(join), (1, 2));
+/// # });
+/// ```
 #[derive(Debug)]
 pub enum MaybeDone<Fut: Future> {
     /// A not-yet-completed future
@@ -63,6 +117,8 @@ impl<Fut: Future> MaybeDone<Fut> {
 
     /// Attempt to take the output of a `MaybeDone` without driving it
     /// towards completion.
+    ///
+    /// The output can only be taken once, later calls return [`None`].
     #[inline]
     pub fn take_output(self: PinMut<Self>) -> Option<Fut::Output> {
         unsafe {
@@ -93,8 +149,7 @@ impl<Fut: Future> Future for MaybeDone<Fut> {
                         return Poll::Pending
                     }
                 }
-                MaybeDone::Done(_) => return Poll::Ready(()),
-                MaybeDone::Gone => panic!("MaybeDone polled after value taken"),
+                MaybeDone::Done(_) | MaybeDone::Gone => return Poll::Ready(()),
             }
         };
         PinMut::set(self, MaybeDone::Done(res));
@@ -29,6 +29,9 @@ mod ready;
 pub use self::ready::{ready, ok, err, Ready};
 
 // Combinators
+mod either;
+pub use self::either::Either;
+
 mod flatten;
 pub use self::flatten::Flatten;
 
@@ -47,6 +50,9 @@ pub use self::join::{Join, Join3, Join4, Join5};
 mod map;
 pub use self::map::Map;
 
+mod never_error;
+pub use self::never_error::NeverError;
+
 // Todo
 // mod select;
 // pub use self::select::Select;
@@ -67,19 +73,21 @@ pub use self::with_spawner::WithSpawner;
 mod chain;
 crate use self::chain::Chain;
 
-if_std! {
-    use std::boxed::PinBox;
+if_alloc! {
+    use alloc::boxed::PinBox;
 
     mod abortable;
     pub use self::abortable::{abortable, Abortable, AbortHandle, AbortRegistration, Aborted};
 
+    mod join_all;
+    pub use self::join_all::{join_all, JoinAll};
+}
+
+if_std! {
     mod catch_unwind;
     pub use self::catch_unwind::CatchUnwind;
 
     // ToDo
-    // mod join_all;
-    // pub use self::join_all::{join_all, JoinAll};
-
     // mod select_all;
     // pub use self::select_all::{SelectAll, SelectAllNext, select_all};
 
@@ -335,8 +343,6 @@ pub trait FutureExt: Future {
         Join5::new(self, future2, future3, future4, future5)
     }
 
-    /* ToDo: futures-core cannot implement Future for Either anymore because of
-             the orphan rule. Remove? Implement our own `Either`?
     /// Wrap this future in an `Either` future, making it the left-hand variant
     /// of that `Either`.
     ///
@@ -346,13 +352,15 @@ pub trait FutureExt: Future {
     /// # Examples
     ///
     /// ```
+    /// #![feature(futures_api)]
     /// use futures::executor::block_on;
+    /// use futures::future::{self, FutureExt};
     ///
     /// let x = 6;
     /// let future = if x < 10 {
-    ///     ready(true).left_future()
+    ///     future::ready(true).left_future()
     /// } else {
-    ///     ready(false).right_future()
+    ///     future::ready(false).right_future()
     /// };
     ///
     /// assert_eq!(true, block_on(future));
@@ -373,13 +381,15 @@ pub trait FutureExt: Future {
     /// # Examples
     ///
     /// ```
+    /// #![feature(futures_api)]
     /// use futures::executor::block_on;
+    /// use futures::future::{self, FutureExt};
     ///
-    /// let x = 6;
+    /// let x = 16;
     /// let future = if x < 10 {
-    ///     ready(true).left_future()
+    ///     future::ready(true).left_future()
     /// } else {
-    ///     ready(false).right_future()
+    ///     future::ready(false).right_future()
     /// };
     ///
     /// assert_eq!(false, block_on(future));
@@ -389,7 +399,7 @@ pub trait FutureExt: Future {
               Self: Sized,
     {
         Either::Right(self)
-    }*/
+    }
 
     /// Convert this future into a single element stream.
     ///
@@ -624,7 +634,7 @@ pub trait FutureExt: Future {
     }
 
     /// Wrap the future in a Box, pinning it.
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     fn boxed(self) -> PinBox<Self>
         where Self: Sized
     {
@@ -638,6 +648,30 @@ pub trait FutureExt: Future {
         UnitError::new(self)
     }
 
+    /// Turns a `Future` into a `TryFuture` with `Error = Never`.
+    ///
+    /// Unlike [`unit_error`](FutureExt::unit_error), this states that the
+    /// future can't fail, and its error converts into the error types of this
+    /// library with `err_into`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc::SendError;
+    /// use futures::future::{self, FutureExt, TryFutureExt};
+    ///
+    /// let future = future::ready(1).never_error().err_into::<SendError>();
+    /// assert_eq!(await!(future), Ok(1));
+    /// # });
+    /// ```
+    fn never_error(self) -> NeverError<Self>
+        where Self: Sized
+    {
+        NeverError::new(self)
+    }
+
     /// Assigns the provided `Spawn` to be used when spawning tasks
     /// from within the future.
     ///
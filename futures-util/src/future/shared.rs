@@ -1,5 +1,6 @@
+use crate::task::AtomicWaker;
 use futures_core::future::Future;
-use futures_core::task::{self, Poll, Wake, Waker};
+use futures_core::task::{self, Poll, Wake};
 use slab::Slab;
 use std::fmt;
 use std::cell::UnsafeCell;
@@ -15,7 +16,7 @@ use std::task::local_waker_from_nonlocal;
 #[must_use = "futures do nothing unless polled"]
 pub struct Shared<Fut: Future> {
     inner: Arc<Inner<Fut>>,
-    waker_key: usize,
+    waker_slot: Option<Arc<WakerSlot>>,
 }
 
 struct Inner<Fut: Future> {
@@ -25,7 +26,16 @@ struct Inner<Fut: Future> {
 
 struct Notifier {
     state: AtomicUsize,
-    wakers: Mutex<Option<Slab<Option<Waker>>>>,
+    // Every clone which has been polled owns a slot in here. The lock is only
+    // taken when a slot is added or removed, and to wake all slots, while
+    // registering the waker of a clone again only touches its own slot.
+    waker_slots: Mutex<Option<Slab<Arc<WakerSlot>>>>,
+}
+
+#[derive(Debug)]
+struct WakerSlot {
+    key: usize,
+    waker: AtomicWaker,
 }
 
 // The future itself is polled behind the `Arc`, so it won't be moved
@@ -36,7 +46,7 @@ impl<Fut: Future> fmt::Debug for Shared<Fut> {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         fmt.debug_struct("Shared")
             .field("inner", &self.inner)
-            .field("waker_key", &self.waker_key)
+            .field("waker_slot", &self.waker_slot)
             .finish()
     }
 }
@@ -71,8 +81,6 @@ const REPOLL: usize = 2;
 const COMPLETE: usize = 3;
 const POISONED: usize = 4;
 
-const NULL_WAKER_KEY: usize = usize::max_value();
-
 impl<Fut: Future> Shared<Fut> {
     pub(super) fn new(future: Fut) -> Shared<Fut> {
         Shared {
@@ -80,10 +88,10 @@ impl<Fut: Future> Shared<Fut> {
                 future_or_output: UnsafeCell::new(FutureOrOutput::Future(future)),
                 notifier: Arc::new(Notifier {
                     state: AtomicUsize::new(IDLE),
-                    wakers: Mutex::new(Some(Slab::new())),
+                    waker_slots: Mutex::new(Some(Slab::new())),
                 }),
             }),
-            waker_key: NULL_WAKER_KEY,
+            waker_slot: None,
         }
     }
 }
@@ -106,31 +114,30 @@ where
 
     /// Registers the current task to receive a wakeup when `Inner` is awoken.
     fn set_waker(&mut self, cx: &mut task::Context) {
-        // Acquire the lock first before checking COMPLETE to ensure there
-        // isn't a race.
-        let mut wakers = self.inner.notifier.wakers.lock().unwrap();
-        let wakers = if let Some(wakers) = wakers.as_mut() {
-            wakers
-        } else {
-            // The value is already available, so there's no need to set the waker.
-            return
-        };
-        if self.waker_key == NULL_WAKER_KEY {
-            self.waker_key = wakers.insert(Some(cx.waker().clone()));
-        } else {
-            let waker_slot = &mut wakers[self.waker_key];
-            let needs_replacement = if let Some(old_waker) = waker_slot {
-                // If there's still an unwoken waker in the slot, only replace
-                // if the current one wouldn't wake the same task.
-                !old_waker.will_wake(cx.waker())
+        if self.waker_slot.is_none() {
+            // Acquire the lock first before checking COMPLETE to ensure there
+            // isn't a race.
+            let mut waker_slots = self.inner.notifier.waker_slots.lock().unwrap();
+            let waker_slots = if let Some(waker_slots) = waker_slots.as_mut() {
+                waker_slots
             } else {
-                true
+                // The value is already available, so there's no need to set
+                // the waker.
+                return
             };
-            if needs_replacement {
-                *waker_slot = Some(cx.waker().clone());
-            }
+            let entry = waker_slots.vacant_entry();
+            let slot = Arc::new(WakerSlot {
+                key: entry.key(),
+                waker: AtomicWaker::new(),
+            });
+            entry.insert(slot.clone());
+            self.waker_slot = Some(slot);
         }
-        debug_assert!(self.waker_key != NULL_WAKER_KEY);
+
+        // Registering with the `AtomicWaker` makes sure the wakeup isn't lost
+        // if the slot is being woken concurrently, the state is checked again
+        // afterwards.
+        self.waker_slot.as_ref().unwrap().waker.register(cx.waker());
     }
 
     /// Safety: callers must first ensure that `self.inner.state`
@@ -221,13 +228,12 @@ where
                     }
 
                     // Complete the future
-                    let mut lock = this.inner.notifier.wakers.lock().unwrap();
+                    let mut lock = this.inner.notifier.waker_slots.lock().unwrap();
                     this.inner.notifier.state.store(COMPLETE, SeqCst);
-                    let wakers = &mut lock.take().unwrap();
-                    for (_key, opt_waker) in wakers {
-                        if let Some(waker) = opt_waker.take() {
-                            waker.wake();
-                        }
+                    let waker_slots = lock.take().unwrap();
+                    drop(lock);
+                    for (_key, slot) in &waker_slots {
+                        slot.waker.wake();
                     }
                     return Poll::Ready(output);
                 }
@@ -243,7 +249,7 @@ where
     fn clone(&self) -> Self {
         Shared {
             inner: self.inner.clone(),
-            waker_key: NULL_WAKER_KEY,
+            waker_slot: None,
         }
     }
 }
@@ -253,10 +259,10 @@ where
     Fut: Future,
 {
     fn drop(&mut self) {
-        if self.waker_key != NULL_WAKER_KEY {
-            if let Ok(mut wakers) = self.inner.notifier.wakers.lock() {
-                if let Some(wakers) = wakers.as_mut() {
-                    wakers.remove(self.waker_key);
+        if let Some(slot) = &self.waker_slot {
+            if let Ok(mut waker_slots) = self.inner.notifier.waker_slots.lock() {
+                if let Some(waker_slots) = waker_slots.as_mut() {
+                    waker_slots.remove(slot.key);
                 }
             }
         }
@@ -267,12 +273,10 @@ impl Wake for Notifier {
     fn wake(arc_self: &Arc<Self>) {
         arc_self.state.compare_and_swap(POLLING, REPOLL, SeqCst);
 
-        let wakers = &mut *arc_self.wakers.lock().unwrap();
-        if let Some(wakers) = wakers.as_mut() {
-            for (_key, opt_waker) in wakers {
-                if let Some(waker) = opt_waker.take() {
-                    waker.wake();
-                }
+        let waker_slots = &*arc_self.waker_slots.lock().unwrap();
+        if let Some(waker_slots) = waker_slots {
+            for (_key, slot) in waker_slots {
+                slot.waker.wake();
             }
         }
     }
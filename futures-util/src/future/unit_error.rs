@@ -20,6 +20,11 @@ impl<Fut> UnitError<Fut> {
     pub(super) fn new(future: Fut) -> UnitError<Fut> {
         UnitError { future }
     }
+
+    /// Consumes this combinator, returning the underlying future.
+    pub fn into_inner(self) -> Fut {
+        self.future
+    }
 }
 
 impl<Fut: Unpin> Unpin for UnitError<Fut> {}
@@ -1,19 +1,40 @@
 use futures_core::task::{self, Poll};
-use futures_io::{AsyncRead, AsyncWrite};
+use futures_io::{AsyncRead, AsyncBufRead, AsyncWrite, AsyncSeek, SeekFrom};
 use std::{fmt, io};
 use std::string::String;
 use std::vec::Vec;
 
 /// A simple wrapper type which allows types which implement only
-/// implement `std::io::Read` or `std::io::Write`
-/// to be used in contexts which expect an `AsyncRead` or `AsyncWrite`.
+/// `std::io::Read`, `std::io::BufRead`, `std::io::Write` or `std::io::Seek`
+/// to be used in contexts which expect an `AsyncRead`, `AsyncBufRead`,
+/// `AsyncWrite` or `AsyncSeek`.
 ///
-/// If these types issue an error with the kind `io::ErrorKind::WouldBlock`,
-/// it is expected that they will notify the current task on readiness.
-/// Synchronous `std` types should not issue errors of this kind and
-/// are safe to use in this context. However, using these types with
-/// `AllowStdIo` will cause the event loop to block, so they should be used
-/// with care.
+/// The async methods call straight into the blocking methods of the wrapped
+/// type, so a read or write that blocks will block the task and the thread
+/// running it. This is fine for in-memory types like `std::io::Cursor` and
+/// for tests, but files or sockets should be used with care.
+///
+/// Errors are passed through unchanged, `Interrupted` operations are retried.
+/// In particular an error with the kind `io::ErrorKind::WouldBlock` is *not*
+/// turned into `Poll::Pending`, as the wrapped type has no way to wake the
+/// task once it is ready. Synchronous `std` types don't issue errors of this
+/// kind.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await, await_macro, futures_api)]
+/// # futures::executor::block_on(async {
+/// use futures::io::{AllowStdIo, AsyncReadExt};
+/// use std::io::Cursor;
+///
+/// let mut reader = AllowStdIo::new(Cursor::new(vec![1, 2, 3]));
+/// let mut writer = AllowStdIo::new(Vec::new());
+///
+/// await!(reader.copy_into(&mut writer))?;
+/// assert_eq!(writer.into_inner(), [1, 2, 3]);
+/// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
+/// ```
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct AllowStdIo<T>(T);
 
@@ -113,3 +134,42 @@ impl<T> AsyncRead for AllowStdIo<T> where T: io::Read {
         Poll::Ready(Ok(try_with_interrupt!(io::Read::read(&mut self.0, buf))))
     }
 }
+
+impl<T> io::BufRead for AllowStdIo<T> where T: io::BufRead {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.0.fill_buf()
+    }
+    fn consume(&mut self, amt: usize) {
+        self.0.consume(amt)
+    }
+}
+
+impl<T> AsyncBufRead for AllowStdIo<T> where T: io::BufRead {
+    fn poll_fill_buf<'a>(&'a mut self, _: &mut task::Context)
+        -> Poll<io::Result<&'a [u8]>>
+    {
+        // The borrow checker doesn't allow returning the buffer from within
+        // the loop, so `fill_buf` is called once more after it succeeded.
+        // This doesn't read again, as the buffer is already filled.
+        try_with_interrupt!(io::BufRead::fill_buf(&mut self.0).map(|_| ()));
+        Poll::Ready(io::BufRead::fill_buf(&mut self.0))
+    }
+
+    fn consume(&mut self, amt: usize) {
+        io::BufRead::consume(&mut self.0, amt)
+    }
+}
+
+impl<T> io::Seek for AllowStdIo<T> where T: io::Seek {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.0.seek(pos)
+    }
+}
+
+impl<T> AsyncSeek for AllowStdIo<T> where T: io::Seek {
+    fn poll_seek(&mut self, _: &mut task::Context, pos: SeekFrom)
+        -> Poll<io::Result<u64>>
+    {
+        Poll::Ready(Ok(try_with_interrupt!(io::Seek::seek(&mut self.0, pos))))
+    }
+}
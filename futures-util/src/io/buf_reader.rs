@@ -0,0 +1,244 @@
+use super::DEFAULT_BUF_SIZE;
+use futures_core::task::{self, Poll};
+use futures_io::{AsyncBufRead, AsyncRead, AsyncSeek, Initializer, IoVec, SeekFrom};
+use std::boxed::Box;
+use std::{cmp, fmt, io};
+use std::vec::Vec;
+
+/// The `BufReader` adds buffering to any reader.
+///
+/// It can be excessively inefficient to work directly with a [`AsyncRead`]
+/// instance. A `BufReader` performs large, infrequent reads on the underlying
+/// [`AsyncRead`] and maintains an in-memory buffer of the results.
+///
+/// `BufReader` can improve the speed of programs that make *small* and
+/// *repeated* read calls to the same file or network socket. It does not
+/// help when reading very large amounts at once, or reading just one or a few
+/// times. It also provides no advantage when reading from a source that is
+/// already in memory, like a `Vec<u8>`.
+///
+/// When the `BufReader` is dropped, the contents of its buffer will be
+/// discarded. Creating multiple instances of a `BufReader` on the same
+/// stream can cause data loss.
+///
+/// This type is the async equivalent of
+/// [`std::io::BufReader`](std::io::BufReader).
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await, await_macro, futures_api)]
+/// # futures::executor::block_on(async {
+/// use futures::io::{AsyncBufReadExt, BufReader};
+///
+/// let data: &[u8] = b"lorem\nipsum\n";
+/// let mut reader = BufReader::with_capacity(4, data);
+/// let mut line = String::new();
+///
+/// await!(reader.read_line(&mut line))?;
+/// assert_eq!(line, "lorem\n");
+/// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
+/// ```
+pub struct BufReader<R> {
+    inner: R,
+    buf: Box<[u8]>,
+    pos: usize,
+    cap: usize,
+}
+
+impl<R: AsyncRead> BufReader<R> {
+    /// Creates a new `BufReader` with a default buffer capacity. The default
+    /// is currently 8 KiB, but may change in the future.
+    pub fn new(inner: R) -> Self {
+        Self::with_capacity(DEFAULT_BUF_SIZE, inner)
+    }
+
+    /// Creates a new `BufReader` with the specified buffer capacity.
+    pub fn with_capacity(capacity: usize, inner: R) -> Self {
+        unsafe {
+            let mut buffer = Vec::with_capacity(capacity);
+            buffer.set_len(capacity);
+            inner.initializer().initialize(&mut buffer);
+            BufReader {
+                inner,
+                buf: buffer.into_boxed_slice(),
+                pos: 0,
+                cap: 0,
+            }
+        }
+    }
+}
+
+impl<R> BufReader<R> {
+    /// Gets a reference to the underlying reader.
+    ///
+    /// It is inadvisable to directly read from the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// It is inadvisable to directly read from the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns a reference to the internally buffered data.
+    ///
+    /// Unlike `poll_fill_buf`, this will not attempt to fill the buffer if it
+    /// is empty.
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf[self.pos..self.cap]
+    }
+
+    /// Unwraps this `BufReader`, returning the underlying reader.
+    ///
+    /// Note that any leftover data in the internal buffer is lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Invalidates all data in the internal buffer.
+    #[inline]
+    fn discard_buffer(&mut self) {
+        self.pos = 0;
+        self.cap = 0;
+    }
+}
+
+impl<R: AsyncRead> AsyncRead for BufReader<R> {
+    unsafe fn initializer(&self) -> Initializer {
+        self.inner.initializer()
+    }
+
+    fn poll_read(&mut self, cx: &mut task::Context, buf: &mut [u8])
+        -> Poll<io::Result<usize>>
+    {
+        // If we don't have any buffered data and we're doing a massive read
+        // (larger than our internal buffer), bypass our internal buffer
+        // entirely.
+        if self.pos == self.cap && buf.len() >= self.buf.len() {
+            self.discard_buffer();
+            return self.inner.poll_read(cx, buf);
+        }
+        let nread = {
+            let rem = try_ready!(self.poll_fill_buf(cx));
+            let nread = cmp::min(rem.len(), buf.len());
+            buf[..nread].copy_from_slice(&rem[..nread]);
+            nread
+        };
+        self.consume(nread);
+        Poll::Ready(Ok(nread))
+    }
+
+    fn poll_vectored_read(&mut self, cx: &mut task::Context, vec: &mut [&mut IoVec])
+        -> Poll<io::Result<usize>>
+    {
+        let total_len = vec.iter().map(|buf| buf.len()).sum::<usize>();
+        if self.pos == self.cap && total_len >= self.buf.len() {
+            self.discard_buffer();
+            return self.inner.poll_vectored_read(cx, vec);
+        }
+        let nread = {
+            let mut rem = try_ready!(self.poll_fill_buf(cx));
+            let mut nread = 0;
+            for buf in vec.iter_mut() {
+                let buf: &mut [u8] = buf;
+                let n = cmp::min(rem.len(), buf.len());
+                buf[..n].copy_from_slice(&rem[..n]);
+                rem = &rem[n..];
+                nread += n;
+                if rem.is_empty() {
+                    break;
+                }
+            }
+            nread
+        };
+        self.consume(nread);
+        Poll::Ready(Ok(nread))
+    }
+}
+
+impl<R: AsyncRead> AsyncBufRead for BufReader<R> {
+    fn poll_fill_buf<'a>(&'a mut self, cx: &mut task::Context)
+        -> Poll<io::Result<&'a [u8]>>
+    {
+        // If we've reached the end of our internal buffer then we need to
+        // fetch some more data from the underlying reader.
+        // Branch using `>=` instead of the more correct `==`
+        // to tell the compiler that the pos..cap slice is always valid.
+        if self.pos >= self.cap {
+            debug_assert!(self.pos == self.cap);
+            self.cap = try_ready!(self.inner.poll_read(cx, &mut self.buf));
+            self.pos = 0;
+        }
+        Poll::Ready(Ok(&self.buf[self.pos..self.cap]))
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos = cmp::min(self.pos + amt, self.cap);
+    }
+}
+
+impl<R: AsyncSeek> AsyncSeek for BufReader<R> {
+    /// Seek to an offset, in bytes, in the underlying reader.
+    ///
+    /// The position used for seeking with `SeekFrom::Current(_)` is the
+    /// position the underlying reader would be at if the `BufReader` had no
+    /// internal buffer.
+    ///
+    /// Seeking always discards the internal buffer, even if the seek position
+    /// would otherwise fall within it. This guarantees that calling
+    /// `into_inner()` immediately after a seek yields the underlying reader
+    /// at the same position.
+    ///
+    /// The buffer is only discarded once the underlying reader finished
+    /// seeking, so a seek that returned `Pending` can be retried with the
+    /// same position.
+    ///
+    /// See [`AsyncSeek`](futures_io::AsyncSeek) for more details.
+    ///
+    /// Note: In the edge case where you're seeking with `SeekFrom::Current(n)`
+    /// where `n` minus the internal buffer length overflows an `i64`, two
+    /// seeks will be performed instead of one. If the second seek returns
+    /// `Err`, the underlying reader will be left at the same position it would
+    /// have if you called `seek` with `SeekFrom::Current(0)`.
+    fn poll_seek(&mut self, cx: &mut task::Context, pos: SeekFrom)
+        -> Poll<io::Result<u64>>
+    {
+        let result;
+        if let SeekFrom::Current(n) = pos {
+            let remainder = (self.cap - self.pos) as i64;
+            // it should be safe to assume that remainder fits within an i64 as the alternative
+            // means we managed to allocate 8 exbibytes and that's absurd.
+            // But it's not out of the realm of possibility for some weird underlying reader to
+            // support seeking by i64::min_value() so we need to handle underflow when subtracting
+            // remainder.
+            if let Some(offset) = n.checked_sub(remainder) {
+                result = try_ready!(self.inner.poll_seek(cx, SeekFrom::Current(offset)));
+            } else {
+                // seek backwards by our remainder, and then by the offset
+                try_ready!(self.inner.poll_seek(cx, SeekFrom::Current(-remainder)));
+                // The buffer is gone now, a retry after `Pending` below
+                // computes a remainder of zero and seeks by `n` only.
+                self.discard_buffer();
+                result = try_ready!(self.inner.poll_seek(cx, SeekFrom::Current(n)));
+            }
+        } else {
+            // Seeking with Start/End doesn't care about our buffer length.
+            result = try_ready!(self.inner.poll_seek(cx, pos));
+        }
+        self.discard_buffer();
+        Poll::Ready(Ok(result))
+    }
+}
+
+impl<R: fmt::Debug> fmt::Debug for BufReader<R> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("BufReader")
+            .field("reader", &self.inner)
+            .field("buffer", &format_args!("{}/{}", self.cap - self.pos, self.buf.len()))
+            .finish()
+    }
+}
@@ -0,0 +1,261 @@
+use super::DEFAULT_BUF_SIZE;
+use futures_core::future::Future;
+use futures_core::task::{self, Poll};
+use futures_io::{AsyncWrite, IoVec};
+use std::{error, fmt, io};
+use std::marker::Unpin;
+use std::mem::PinMut;
+use std::vec::Vec;
+
+/// Wraps a writer and buffers its output.
+///
+/// It can be excessively inefficient to work directly with something that
+/// implements [`AsyncWrite`]. A `BufWriter` keeps an in-memory buffer of data
+/// and writes it to an underlying writer in large, infrequent batches.
+///
+/// `BufWriter` can improve the speed of programs that make *small* and
+/// *repeated* write calls to the same file or network socket. It does not
+/// help when writing very large amounts at once, or writing just one or a few
+/// times. It also provides no advantage when writing to a destination that is
+/// in memory, like a `Vec<u8>`.
+///
+/// Unlike [`std::io::BufWriter`](std::io::BufWriter), the buffer is not
+/// flushed when the `BufWriter` is dropped, as flushing may have to wait for
+/// the underlying writer. Any data left in the buffer is discarded, so make
+/// sure to [`flush`](super::AsyncWriteExt::flush) or
+/// [`close`](super::AsyncWriteExt::close) the writer, or to call
+/// [`into_inner`](BufWriter::into_inner), before dropping it.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await, await_macro, futures_api)]
+/// # futures::executor::block_on(async {
+/// use futures::io::{AsyncWriteExt, BufWriter};
+///
+/// let mut writer = BufWriter::new(Vec::new());
+///
+/// await!(writer.write_all(b"lorem "))?;
+/// await!(writer.write_all(b"ipsum"))?;
+/// // Nothing has been written to the `Vec` yet.
+/// assert!(writer.get_ref().is_empty());
+///
+/// await!(writer.flush())?;
+/// assert_eq!(writer.get_ref(), b"lorem ipsum");
+/// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
+/// ```
+pub struct BufWriter<W> {
+    inner: W,
+    buf: Vec<u8>,
+    written: usize,
+}
+
+impl<W: AsyncWrite> BufWriter<W> {
+    /// Creates a new `BufWriter` with a default buffer capacity. The default
+    /// is currently 8 KiB, but may change in the future.
+    pub fn new(inner: W) -> Self {
+        Self::with_capacity(DEFAULT_BUF_SIZE, inner)
+    }
+
+    /// Creates a new `BufWriter` with the specified buffer capacity.
+    pub fn with_capacity(capacity: usize, inner: W) -> Self {
+        BufWriter {
+            inner,
+            buf: Vec::with_capacity(capacity),
+            written: 0,
+        }
+    }
+
+    /// Creates a future which flushes the buffer and then resolves to the
+    /// underlying writer.
+    ///
+    /// If flushing the buffer fails, the future resolves to an
+    /// [`IntoInnerError`] holding both the error and the `BufWriter`, so that
+    /// the buffered data isn't lost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncWriteExt, BufWriter};
+    ///
+    /// let mut writer = BufWriter::new(Vec::new());
+    /// await!(writer.write_all(b"lorem ipsum"))?;
+    ///
+    /// let vec = await!(writer.into_inner())?;
+    /// assert_eq!(vec, b"lorem ipsum");
+    /// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
+    /// ```
+    pub fn into_inner(self) -> IntoInner<W> {
+        IntoInner { writer: Some(self) }
+    }
+
+    // Writes out the buffer. `written` tracks the progress across calls that
+    // returned `Pending`, so that no data is written twice.
+    fn flush_buf(&mut self, cx: &mut task::Context) -> Poll<io::Result<()>> {
+        while self.written < self.buf.len() {
+            let n = try_ready!(self.inner.poll_write(cx, &self.buf[self.written..]));
+            if n == 0 {
+                return Poll::Ready(Err(io::Error::new(
+                    io::ErrorKind::WriteZero,
+                    "failed to write the buffered data",
+                )));
+            }
+            self.written += n;
+        }
+        self.buf.clear();
+        self.written = 0;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<W> BufWriter<W> {
+    /// Gets a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// It is inadvisable to directly write to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns a reference to the internally buffered data that hasn't been
+    /// written to the underlying writer yet.
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf[self.written..]
+    }
+}
+
+impl<W: AsyncWrite> AsyncWrite for BufWriter<W> {
+    fn poll_write(&mut self, cx: &mut task::Context, buf: &[u8])
+        -> Poll<io::Result<usize>>
+    {
+        if self.buf.len() + buf.len() > self.buf.capacity() {
+            try_ready!(self.flush_buf(cx));
+        }
+        // Writes that don't fit into the buffer go straight to the
+        // underlying writer.
+        if buf.len() >= self.buf.capacity() {
+            self.inner.poll_write(cx, buf)
+        } else {
+            self.buf.extend_from_slice(buf);
+            Poll::Ready(Ok(buf.len()))
+        }
+    }
+
+    fn poll_vectored_write(&mut self, cx: &mut task::Context, vec: &[&IoVec])
+        -> Poll<io::Result<usize>>
+    {
+        let total_len = vec.iter().map(|buf| buf.len()).sum::<usize>();
+        if self.buf.len() + total_len > self.buf.capacity() {
+            try_ready!(self.flush_buf(cx));
+        }
+        if total_len >= self.buf.capacity() {
+            self.inner.poll_vectored_write(cx, vec)
+        } else {
+            for buf in vec {
+                self.buf.extend_from_slice(buf);
+            }
+            Poll::Ready(Ok(total_len))
+        }
+    }
+
+    fn poll_flush(&mut self, cx: &mut task::Context) -> Poll<io::Result<()>> {
+        try_ready!(self.flush_buf(cx));
+        self.inner.poll_flush(cx)
+    }
+
+    fn poll_close(&mut self, cx: &mut task::Context) -> Poll<io::Result<()>> {
+        try_ready!(self.flush_buf(cx));
+        self.inner.poll_close(cx)
+    }
+}
+
+impl<W: fmt::Debug> fmt::Debug for BufWriter<W> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("BufWriter")
+            .field("writer", &self.inner)
+            .field("buffer", &format_args!(
+                "{}/{}", self.buf.len() - self.written, self.buf.capacity(),
+            ))
+            .finish()
+    }
+}
+
+/// Future for the [`into_inner`](BufWriter::into_inner) method.
+#[derive(Debug)]
+pub struct IntoInner<W> {
+    writer: Option<BufWriter<W>>,
+}
+
+// We never project pinning to fields
+impl<W> Unpin for IntoInner<W> {}
+
+impl<W: AsyncWrite> Future for IntoInner<W> {
+    type Output = Result<W, IntoInnerError<BufWriter<W>>>;
+
+    fn poll(mut self: PinMut<Self>, cx: &mut task::Context) -> Poll<Self::Output> {
+        let res = self.writer.as_mut()
+            .expect("cannot poll IntoInner twice")
+            .flush_buf(cx);
+        match res {
+            Poll::Ready(Ok(())) => {
+                let writer = self.writer.take().unwrap();
+                Poll::Ready(Ok(writer.inner))
+            }
+            Poll::Ready(Err(error)) => {
+                let writer = self.writer.take().unwrap();
+                Poll::Ready(Err(IntoInnerError { writer, error }))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// An error returned by [`BufWriter::into_inner`] which combines an error that
+/// happened while writing out the buffer, and the buffered writer object
+/// which may be used to recover from the condition.
+#[derive(Debug)]
+pub struct IntoInnerError<W> {
+    writer: W,
+    error: io::Error,
+}
+
+impl<W> IntoInnerError<W> {
+    /// Returns the error which caused the call to `into_inner` to fail.
+    ///
+    /// This error was returned when attempting to write the internal buffer.
+    pub fn error(&self) -> &io::Error {
+        &self.error
+    }
+
+    /// Returns the buffered writer instance which generated the error.
+    ///
+    /// The returned object can be used for error recovery, such as
+    /// re-inspecting the buffer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W> From<IntoInnerError<W>> for io::Error {
+    fn from(err: IntoInnerError<W>) -> io::Error {
+        err.error
+    }
+}
+
+impl<W> fmt::Display for IntoInnerError<W> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl<W: fmt::Debug + Send> error::Error for IntoInnerError<W> {
+    fn description(&self) -> &str {
+        error::Error::description(&self.error)
+    }
+}
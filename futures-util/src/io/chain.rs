@@ -0,0 +1,93 @@
+use futures_core::task::{self, Poll};
+use futures_io::{AsyncBufRead, AsyncRead, Initializer};
+use std::io;
+
+/// Reader for the [`chain`](super::AsyncReadExt::chain) method.
+#[derive(Debug)]
+pub struct Chain<T, U> {
+    first: T,
+    second: U,
+    done_first: bool,
+}
+
+impl<T, U> Chain<T, U>
+    where T: AsyncRead,
+          U: AsyncRead,
+{
+    pub(super) fn new(first: T, second: U) -> Self {
+        Chain { first, second, done_first: false }
+    }
+}
+
+impl<T, U> Chain<T, U> {
+    /// Gets references to the underlying readers in this `Chain`.
+    pub fn get_ref(&self) -> (&T, &U) {
+        (&self.first, &self.second)
+    }
+
+    /// Gets mutable references to the underlying readers in this `Chain`.
+    ///
+    /// Care should be taken to avoid modifying the internal I/O state of the
+    /// underlying readers as doing so may corrupt the internal state of this
+    /// `Chain`.
+    pub fn get_mut(&mut self) -> (&mut T, &mut U) {
+        (&mut self.first, &mut self.second)
+    }
+
+    /// Consumes the `Chain`, returning the wrapped readers.
+    pub fn into_inner(self) -> (T, U) {
+        (self.first, self.second)
+    }
+}
+
+impl<T, U> AsyncRead for Chain<T, U>
+    where T: AsyncRead,
+          U: AsyncRead,
+{
+    unsafe fn initializer(&self) -> Initializer {
+        let initializer = self.first.initializer();
+        if initializer.should_initialize() {
+            initializer
+        } else {
+            self.second.initializer()
+        }
+    }
+
+    fn poll_read(&mut self, cx: &mut task::Context, buf: &mut [u8])
+        -> Poll<io::Result<usize>>
+    {
+        if !self.done_first {
+            match try_ready!(self.first.poll_read(cx, buf)) {
+                // A read into an empty buffer returns 0 without being at EOF.
+                0 if !buf.is_empty() => self.done_first = true,
+                n => return Poll::Ready(Ok(n)),
+            }
+        }
+        self.second.poll_read(cx, buf)
+    }
+}
+
+impl<T, U> AsyncBufRead for Chain<T, U>
+    where T: AsyncBufRead,
+          U: AsyncBufRead,
+{
+    fn poll_fill_buf<'a>(&'a mut self, cx: &mut task::Context)
+        -> Poll<io::Result<&'a [u8]>>
+    {
+        if !self.done_first {
+            match try_ready!(self.first.poll_fill_buf(cx)) {
+                buf if buf.is_empty() => { self.done_first = true; }
+                buf => return Poll::Ready(Ok(buf)),
+            }
+        }
+        self.second.poll_fill_buf(cx)
+    }
+
+    fn consume(&mut self, amt: usize) {
+        if !self.done_first {
+            self.first.consume(amt)
+        } else {
+            self.second.consume(amt)
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use futures_core::future::Future;
+use futures_core::task::{self, Poll};
+use futures_io::{AsyncBufRead, AsyncWrite};
+use std::io;
+use std::marker::Unpin;
+use std::mem::PinMut;
+
+/// Future for the [`copy_buf_into`](super::AsyncBufReadExt::copy_buf_into)
+/// method.
+#[derive(Debug)]
+pub struct CopyBufInto<'a, R: ?Sized + 'a, W: ?Sized + 'a> {
+    reader: &'a mut R,
+    writer: &'a mut W,
+    amt: u64,
+}
+
+// No projections of PinMut<CopyBufInto> into PinMut<Field> are ever done.
+impl<R: ?Sized, W: ?Sized> Unpin for CopyBufInto<'_, R, W> {}
+
+impl<'a, R: ?Sized, W: ?Sized> CopyBufInto<'a, R, W> {
+    pub(super) fn new(reader: &'a mut R, writer: &'a mut W) -> Self {
+        CopyBufInto { reader, writer, amt: 0 }
+    }
+}
+
+impl<R, W> Future for CopyBufInto<'_, R, W>
+    where R: AsyncBufRead + ?Sized,
+          W: AsyncWrite + ?Sized,
+{
+    type Output = io::Result<u64>;
+
+    fn poll(mut self: PinMut<Self>, cx: &mut task::Context) -> Poll<Self::Output> {
+        let this = &mut *self;
+        loop {
+            let i = {
+                let buffer = try_ready!(this.reader.poll_fill_buf(cx));
+                if buffer.is_empty() {
+                    // EOF, flush out the data and finish the transfer. The
+                    // writer is not closed, so it can still be used
+                    // afterwards.
+                    try_ready!(this.writer.poll_flush(cx));
+                    return Poll::Ready(Ok(this.amt));
+                }
+
+                let i = try_ready!(this.writer.poll_write(cx, buffer));
+                if i == 0 {
+                    return Poll::Ready(Err(
+                        io::Error::new(
+                            io::ErrorKind::WriteZero, "write zero byte into writer")));
+                }
+                i
+            };
+            // Only the bytes the writer accepted are consumed, the rest is
+            // returned by the reader again on the next iteration.
+            this.amt += i as u64;
+            this.reader.consume(i);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use super::DEFAULT_BUF_SIZE;
 use futures_core::future::Future;
 use futures_core::task::{self, Poll};
 use futures_io::{AsyncRead, AsyncWrite};
@@ -35,7 +36,7 @@ impl<'a, R: ?Sized, W: ?Sized> CopyInto<'a, R, W> {
             amt: 0,
             pos: 0,
             cap: 0,
-            buf: Box::new([0; 2048]),
+            buf: Box::new([0; DEFAULT_BUF_SIZE]),
         }
     }
 }
@@ -74,9 +75,9 @@ impl<R, W> Future for CopyInto<'_, R, W>
                 }
             }
 
-            // If we've written al the data and we've seen EOF, flush out the
-            // data and finish the transfer.
-            // done with the entire transfer.
+            // If we've written all the data and we've seen EOF, flush out the
+            // data and finish the transfer. The writer is not closed, so it
+            // can still be used afterwards.
             if this.pos == this.cap && this.read_done {
                 try_ready!(this.writer.poll_flush(cx));
                 return Poll::Ready(Ok(this.amt));
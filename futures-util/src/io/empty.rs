@@ -0,0 +1,63 @@
+use futures_core::task::{self, Poll};
+use futures_io::{AsyncBufRead, AsyncRead, Initializer};
+use std::fmt;
+use std::io;
+
+/// Reader for the [`empty()`] function.
+pub struct Empty {
+    _priv: (),
+}
+
+/// Constructs a new handle to an empty reader.
+///
+/// All reads from the returned reader will return `Poll::Ready(Ok(0))`.
+///
+/// # Examples
+///
+/// A slightly sad example of not reading anything into a buffer:
+///
+/// ```
+/// #![feature(async_await, await_macro, futures_api)]
+/// # futures::executor::block_on(async {
+/// use futures::io::{self, AsyncReadExt};
+///
+/// let mut buffer = Vec::new();
+/// await!(io::empty().read_to_end(&mut buffer))?;
+/// assert!(buffer.is_empty());
+/// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
+/// ```
+pub fn empty() -> Empty {
+    Empty { _priv: () }
+}
+
+impl AsyncRead for Empty {
+    #[inline]
+    unsafe fn initializer(&self) -> Initializer {
+        Initializer::nop()
+    }
+
+    #[inline]
+    fn poll_read(&mut self, _: &mut task::Context, _: &mut [u8])
+        -> Poll<io::Result<usize>>
+    {
+        Poll::Ready(Ok(0))
+    }
+}
+
+impl AsyncBufRead for Empty {
+    #[inline]
+    fn poll_fill_buf<'a>(&'a mut self, _: &mut task::Context)
+        -> Poll<io::Result<&'a [u8]>>
+    {
+        Poll::Ready(Ok(&[]))
+    }
+
+    #[inline]
+    fn consume(&mut self, _: usize) {}
+}
+
+impl fmt::Debug for Empty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad("Empty { .. }")
+    }
+}
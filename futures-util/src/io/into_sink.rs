@@ -0,0 +1,96 @@
+use futures_core::task::{self, Poll};
+use futures_io::AsyncWrite;
+use futures_sink::Sink;
+use std::io;
+use std::marker::Unpin;
+use std::mem::PinMut;
+
+#[derive(Debug)]
+struct Block<Item> {
+    offset: usize,
+    bytes: Item,
+}
+
+/// Sink for the [`into_sink`](super::AsyncWriteExt::into_sink) method.
+///
+/// Every item sent into it is written to the underlying writer in full before
+/// the next one is accepted.
+#[derive(Debug)]
+#[must_use = "sinks do nothing unless polled"]
+pub struct IntoSink<W, Item> {
+    writer: W,
+    // An item which is in the middle of being written.
+    buffer: Option<Block<Item>>,
+}
+
+// We never project pinning to fields
+impl<W, Item> Unpin for IntoSink<W, Item> {}
+
+impl<W: AsyncWrite, Item: AsRef<[u8]>> IntoSink<W, Item> {
+    pub(super) fn new(writer: W) -> Self {
+        IntoSink { writer, buffer: None }
+    }
+
+    /// Consumes this sink, returning the underlying writer.
+    ///
+    /// Any item that hasn't been written completely yet is lost, so the sink
+    /// should be flushed before calling this.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Writes out the buffered item, if there is one.
+    fn poll_flush_buffer(&mut self, cx: &mut task::Context)
+        -> Poll<io::Result<()>>
+    {
+        if let Some(buffer) = &mut self.buffer {
+            loop {
+                let bytes = buffer.bytes.as_ref();
+                if buffer.offset == bytes.len() {
+                    break;
+                }
+                let n = try_ready!(self.writer.poll_write(cx, &bytes[buffer.offset..]));
+                if n == 0 {
+                    return Poll::Ready(Err(io::Error::new(
+                        io::ErrorKind::WriteZero,
+                        "failed to write buffered item",
+                    )));
+                }
+                buffer.offset += n;
+            }
+        }
+        self.buffer = None;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<W: AsyncWrite, Item: AsRef<[u8]>> Sink for IntoSink<W, Item> {
+    type SinkItem = Item;
+    type SinkError = io::Error;
+
+    fn poll_ready(mut self: PinMut<Self>, cx: &mut task::Context)
+        -> Poll<io::Result<()>>
+    {
+        self.poll_flush_buffer(cx)
+    }
+
+    fn start_send(mut self: PinMut<Self>, item: Item) -> io::Result<()> {
+        debug_assert!(self.buffer.is_none());
+        self.buffer = Some(Block { offset: 0, bytes: item });
+        Ok(())
+    }
+
+    fn poll_flush(mut self: PinMut<Self>, cx: &mut task::Context)
+        -> Poll<io::Result<()>>
+    {
+        try_ready!(self.poll_flush_buffer(cx));
+        self.writer.poll_flush(cx)
+    }
+
+    fn poll_close(mut self: PinMut<Self>, cx: &mut task::Context)
+        -> Poll<io::Result<()>>
+    {
+        try_ready!(self.poll_flush_buffer(cx));
+        self.writer.poll_close(cx)
+    }
+}
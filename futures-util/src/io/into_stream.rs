@@ -0,0 +1,59 @@
+use futures_core::stream::Stream;
+use futures_core::task::{self, Poll};
+use futures_io::AsyncRead;
+use std::io;
+use std::marker::Unpin;
+use std::mem::{self, PinMut};
+use std::vec::Vec;
+
+/// Stream for the [`into_stream`](super::AsyncReadExt::into_stream) method.
+///
+/// Yields the bytes read from the underlying reader in chunks of at most
+/// `chunk_size` bytes, until the reader reaches EOF.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct IntoStream<R> {
+    reader: R,
+    chunk_size: usize,
+    buf: Vec<u8>,
+}
+
+// We never project pinning to fields
+impl<R> Unpin for IntoStream<R> {}
+
+impl<R: AsyncRead> IntoStream<R> {
+    pub(super) fn new(reader: R, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk size must be non-zero");
+        IntoStream { reader, chunk_size, buf: Vec::new() }
+    }
+
+    /// Consumes this stream, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: AsyncRead> Stream for IntoStream<R> {
+    type Item = io::Result<Vec<u8>>;
+
+    fn poll_next(mut self: PinMut<Self>, cx: &mut task::Context)
+        -> Poll<Option<io::Result<Vec<u8>>>>
+    {
+        let this = &mut *self;
+        // The buffer is kept across pending reads, so it is only allocated
+        // once per chunk.
+        if this.buf.is_empty() {
+            this.buf = vec![0; this.chunk_size];
+        }
+        match this.reader.poll_read(cx, &mut this.buf) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(Err(e)) => Poll::Ready(Some(Err(e))),
+            Poll::Ready(Ok(0)) => Poll::Ready(None),
+            Poll::Ready(Ok(n)) => {
+                let mut chunk = mem::replace(&mut this.buf, Vec::new());
+                chunk.truncate(n);
+                Poll::Ready(Some(Ok(chunk)))
+            }
+        }
+    }
+}
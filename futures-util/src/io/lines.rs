@@ -0,0 +1,59 @@
+use super::read_line::read_line_internal;
+use futures_core::stream::Stream;
+use futures_core::task::{self, Poll};
+use futures_io::AsyncBufRead;
+use std::io;
+use std::marker::Unpin;
+use std::mem::{self, PinMut};
+use std::string::String;
+use std::vec::Vec;
+
+/// Stream for the [`lines`](super::AsyncBufReadExt::lines) method.
+#[derive(Debug)]
+pub struct Lines<R> {
+    reader: R,
+    buf: String,
+    bytes: Vec<u8>,
+    read: usize,
+}
+
+// We never project pinning to fields
+impl<R> Unpin for Lines<R> {}
+
+impl<R: AsyncBufRead> Lines<R> {
+    pub(super) fn new(reader: R) -> Self {
+        Lines { reader, buf: String::new(), bytes: Vec::new(), read: 0 }
+    }
+
+    /// Consumes this stream, returning the underlying reader.
+    ///
+    /// The bytes of a partially read line are lost.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: AsyncBufRead> Stream for Lines<R> {
+    type Item = io::Result<String>;
+
+    fn poll_next(mut self: PinMut<Self>, cx: &mut task::Context) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        let n = match read_line_internal(
+            &mut this.reader, cx, &mut this.buf, &mut this.bytes, &mut this.read,
+        ) {
+            Poll::Ready(Ok(n)) => n,
+            Poll::Ready(Err(e)) => return Poll::Ready(Some(Err(e))),
+            Poll::Pending => return Poll::Pending,
+        };
+        if n == 0 && this.buf.is_empty() {
+            return Poll::Ready(None);
+        }
+        if this.buf.ends_with('\n') {
+            this.buf.pop();
+            if this.buf.ends_with('\r') {
+                this.buf.pop();
+            }
+        }
+        Poll::Ready(Some(Ok(mem::replace(&mut this.buf, String::new()))))
+    }
+}
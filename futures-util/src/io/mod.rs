@@ -1,43 +1,91 @@
 //! IO
 //!
 //! This module contains a number of functions for working with
-//! `AsyncRead` and `AsyncWrite` types, including the
-//! `AsyncReadExt` and `AsyncWriteExt` traits which add methods
-//! to the `AsyncRead` and `AsyncWrite` types.
+//! `AsyncRead`, `AsyncBufRead`, `AsyncWrite` and `AsyncSeek` types, including
+//! the `AsyncReadExt`, `AsyncBufReadExt`, `AsyncWriteExt` and `AsyncSeekExt`
+//! traits which add methods to the `AsyncRead`, `AsyncBufRead`, `AsyncWrite`
+//! and `AsyncSeek` types.
 
+use std::string::String;
 use std::vec::Vec;
 
-pub use futures_io::{AsyncRead, AsyncWrite, IoVec};
+pub use futures_io::{AsyncRead, AsyncBufRead, AsyncWrite, AsyncSeek, IoVec, SeekFrom};
 
-// Temporarily removed until AsyncBufRead is implemented
-// pub use io::lines::{lines, Lines};
-// pub use io::read_until::{read_until, ReadUntil};
-// mod lines;
-// mod read_until;
+#[cfg(feature = "io-compat")]
+use crate::compat::Compat;
+
+#[cfg(feature = "io-compat")]
+use futures_core::task::Spawn;
+
+// Used by `BufReader`, `BufWriter` and `copy_into`, same as std's
+// `DEFAULT_BUF_SIZE`.
+const DEFAULT_BUF_SIZE: usize = 8 * 1024;
 
 mod allow_std;
 pub use self::allow_std::AllowStdIo;
 
+mod buf_reader;
+pub use self::buf_reader::BufReader;
+
+mod buf_writer;
+pub use self::buf_writer::{BufWriter, IntoInner, IntoInnerError};
+
+mod copy_buf_into;
+pub use self::copy_buf_into::CopyBufInto;
+
 mod copy_into;
 pub use self::copy_into::CopyInto;
 
+mod empty;
+pub use self::empty::{empty, Empty};
+
 mod flush;
 pub use self::flush::Flush;
 
+mod into_sink;
+pub use self::into_sink::IntoSink;
+
+mod into_stream;
+pub use self::into_stream::IntoStream;
+
 mod read;
 pub use self::read::Read;
 
+mod repeat;
+pub use self::repeat::{repeat, Repeat};
+
+mod lines;
+pub use self::lines::Lines;
+
 mod read_exact;
 pub use self::read_exact::ReadExact;
 
+mod read_line;
+pub use self::read_line::ReadLine;
+
 mod read_to_end;
 pub use self::read_to_end::ReadToEnd;
 
+mod read_until;
+pub use self::read_until::ReadUntil;
+
+mod chain;
+pub use self::chain::Chain;
+
 mod close;
 pub use self::close::Close;
 
+mod seek;
+pub use self::seek::Seek;
+
+mod sink;
+pub use self::sink::{sink, Sink};
+
 mod split;
-pub use self::split::{ReadHalf, WriteHalf};
+pub use self::split::{ReadHalf, ReuniteError, WriteHalf};
+
+mod take;
+pub use self::take::Take;
 
 mod window;
 pub use self::window::Window;
@@ -45,14 +93,74 @@ pub use self::window::Window;
 mod write_all;
 pub use self::write_all::WriteAll;
 
+mod write_vectored_all;
+pub use self::write_vectored_all::WriteVectoredAll;
+
 /// An extension trait which adds utility methods to `AsyncRead` types.
 pub trait AsyncReadExt: AsyncRead {
+    /// Creates an adaptor which will chain this reader with another.
+    ///
+    /// The returned `AsyncRead` instance will first read all bytes from this
+    /// object until EOF is encountered. Afterwards the output is equivalent
+    /// to the output of `next`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::io::AsyncReadExt;
+    /// use std::io::Cursor;
+    ///
+    /// let reader1 = Cursor::new([1, 2, 3, 4]);
+    /// let reader2 = Cursor::new([5, 6, 7, 8]);
+    ///
+    /// let mut reader = reader1.chain(reader2);
+    /// let mut buffer = Vec::new();
+    ///
+    /// // read the value into a Vec.
+    /// await!(reader.read_to_end(&mut buffer))?;
+    /// assert_eq!(buffer, [1, 2, 3, 4, 5, 6, 7, 8]);
+    /// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn chain<R>(self, next: R) -> Chain<Self, R>
+        where Self: Sized,
+              R: AsyncRead,
+    {
+        Chain::new(self, next)
+    }
+
+    /// Wraps an [`AsyncRead`] into a type that implements tokio-io's
+    /// [`AsyncRead`](tokio_io::AsyncRead), so that it can be used with tokio's
+    /// codecs and I/O utilities. Requires the `io-compat` feature to be
+    /// enabled.
+    ///
+    /// If `Self` also implements [`AsyncWrite`], the result implements
+    /// tokio-io's [`AsyncWrite`](tokio_io::AsyncWrite) as well.
+    ///
+    /// `Poll::Pending` is turned into a `WouldBlock` error, and the current
+    /// futures 0.1 task is woken once the operation can make progress. Like
+    /// tokio's own I/O objects, the result must therefore only be used from
+    /// within a futures 0.1 task. `spawn` is used as the spawner of the
+    /// context the reader is polled with.
+    #[cfg(feature = "io-compat")]
+    fn compat<Sp>(self, spawn: Sp) -> Compat<Self, Sp>
+        where Self: Sized,
+              Sp: Spawn,
+    {
+        Compat::new(self, Some(spawn))
+    }
+
     /// Creates a future which copies all the bytes from one object to another.
     ///
     /// The returned future will copy all the bytes read from this `AsyncRead` into the
     /// `writer` specified. This future will only complete once the `reader` has hit
     /// EOF and all bytes have been written to and flushed from the `writer`
-    /// provided.
+    /// provided. The `writer` is flushed but not closed.
+    ///
+    /// Data is copied through an internal buffer, bytes that were read but
+    /// not yet written when the writer returns `Pending` are kept in it until
+    /// the next poll.
     ///
     /// On success the number of bytes is returned.
     ///
@@ -65,7 +173,7 @@ pub trait AsyncReadExt: AsyncRead {
     /// use std::io::Cursor;
     ///
     /// let mut reader = Cursor::new([1, 2, 3, 4]);
-    /// let mut writer = Cursor::new([0u8; 5]);
+    /// let mut writer = Cursor::new(vec![0u8; 5]);
     ///
     /// let bytes = await!(reader.copy_into(&mut writer))?;
     ///
@@ -82,6 +190,38 @@ pub trait AsyncReadExt: AsyncRead {
         CopyInto::new(self, writer)
     }
 
+    /// Turns this reader into a stream of the bytes it produces, in chunks
+    /// of at most `chunk_size` bytes.
+    ///
+    /// The stream yields a chunk for every successful read and ends once the
+    /// reader reaches EOF. Errors are passed on as they occur, the stream can
+    /// still be polled after an error.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::io::AsyncReadExt;
+    /// use futures::stream::TryStreamExt;
+    /// use std::io::Cursor;
+    ///
+    /// let reader = Cursor::new([1, 2, 3, 4, 5]);
+    /// let chunks: Vec<Vec<u8>> = await!(reader.into_stream(2).try_collect())?;
+    ///
+    /// assert_eq!(chunks, [vec![1, 2], vec![3, 4], vec![5]]);
+    /// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn into_stream(self, chunk_size: usize) -> IntoStream<Self>
+        where Self: Sized,
+    {
+        IntoStream::new(self, chunk_size)
+    }
+
     /// Tries to read some bytes directly into the given `buf` in asynchronous
     /// manner, returning a future type.
     ///
@@ -117,8 +257,10 @@ pub trait AsyncReadExt: AsyncRead {
     ///
     /// The returned future will resolve once the read operation is completed.
     ///
-    /// In the case of an error the buffer and the object will be discarded, with
-    /// the error yielded.
+    /// If EOF is hit first, the future resolves to an
+    /// [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof) error whose message
+    /// records how many bytes were read. The bytes that were read before EOF,
+    /// an error or dropping the future are left at the start of `buf`.
     ///
     /// # Examples
     ///
@@ -162,6 +304,11 @@ pub trait AsyncReadExt: AsyncRead {
 
     /// Creates a future which will read all the bytes from this `AsyncRead`.
     ///
+    /// The bytes are appended to `buf`, which is grown as needed. On EOF the
+    /// future resolves to the number of bytes that were appended. The bytes
+    /// that were read before an error or dropping the future are kept in
+    /// `buf`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -171,11 +318,12 @@ pub trait AsyncReadExt: AsyncRead {
     /// use std::io::Cursor;
     ///
     /// let mut reader = Cursor::new([1, 2, 3, 4]);
-    /// let mut output = Vec::with_capacity(4);
+    /// let mut output = vec![0];
     ///
-    /// await!(reader.read_to_end(&mut output))?;
+    /// let bytes = await!(reader.read_to_end(&mut output))?;
     ///
-    /// assert_eq!(output, vec![1, 2, 3, 4]);
+    /// assert_eq!(bytes, 4);
+    /// assert_eq!(output, vec![0, 1, 2, 3, 4]);
     /// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
     /// ```
     fn read_to_end<'a>(
@@ -188,7 +336,11 @@ pub trait AsyncReadExt: AsyncRead {
     /// Helper method for splitting this read/write object into two halves.
     ///
     /// The two halves returned implement the `AsyncRead` and `AsyncWrite`
-    /// traits, respectively.
+    /// traits, respectively. They share the object behind a lock, a half that
+    /// finds the lock taken by the other half is woken once it is released.
+    /// The halves are `Send` if the object is, so they can be moved to
+    /// different tasks, and can be put back together with
+    /// [`ReadHalf::reunite`].
     ///
     /// # Examples
     ///
@@ -203,8 +355,8 @@ pub trait AsyncReadExt: AsyncRead {
     /// // implement both `AsyncRead` and `AsyncWrite`.
     ///
     /// let mut reader = Cursor::new([1, 2, 3, 4]);
-    /// let mut buffer = Cursor::new([0, 0, 0, 0, 5, 6, 7, 8]);
-    /// let mut writer = Cursor::new([0u8; 5]);
+    /// let mut buffer = Cursor::new(vec![0, 0, 0, 0, 5, 6, 7, 8]);
+    /// let mut writer = Cursor::new(vec![0u8; 5]);
     ///
     /// {
     ///     let (mut buffer_reader, mut buffer_writer) = (&mut buffer).split();
@@ -221,10 +373,202 @@ pub trait AsyncReadExt: AsyncRead {
     {
         split::split(self)
     }
+
+    /// Creates an AsyncRead adapter which will read at most `limit` bytes
+    /// from the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{self, AsyncReadExt};
+    ///
+    /// let mut reader = io::repeat(b'x').take(100);
+    /// let bytes = await!(reader.copy_into(&mut io::sink()))?;
+    ///
+    /// assert_eq!(bytes, 100);
+    /// assert_eq!(reader.limit(), 0);
+    /// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn take(self, limit: u64) -> Take<Self>
+        where Self: Sized
+    {
+        Take::new(self, limit)
+    }
 }
 
 impl<R: AsyncRead + ?Sized> AsyncReadExt for R {}
 
+/// An extension trait which adds utility methods to `AsyncBufRead` types.
+pub trait AsyncBufReadExt: AsyncBufRead {
+    /// Creates a future which copies all the bytes from one object to another,
+    /// writing straight from this reader's internal buffer.
+    ///
+    /// The returned future will copy all the bytes read from this
+    /// `AsyncBufRead` into the `writer` specified. This future will only
+    /// complete once the reader has hit EOF and all bytes have been written to
+    /// and flushed from the `writer` provided. The `writer` is flushed but not
+    /// closed.
+    ///
+    /// Unlike [`copy_into`](AsyncReadExt::copy_into), no additional buffer is
+    /// used. Only the bytes that the writer accepted are consumed from the
+    /// reader, so bytes that haven't been written when an error happens or the
+    /// future is dropped are still available from the reader.
+    ///
+    /// On success the number of bytes is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncBufReadExt, BufReader};
+    /// use std::io::Cursor;
+    ///
+    /// let mut reader = BufReader::new(Cursor::new([1, 2, 3, 4]));
+    /// let mut writer = Cursor::new(vec![0u8; 5]);
+    ///
+    /// let bytes = await!(reader.copy_buf_into(&mut writer))?;
+    ///
+    /// assert_eq!(bytes, 4);
+    /// assert_eq!(writer.into_inner(), [1, 2, 3, 4, 0]);
+    /// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn copy_buf_into<'a, W>(
+        &'a mut self,
+        writer: &'a mut W,
+    ) -> CopyBufInto<'a, Self, W>
+        where W: AsyncWrite,
+    {
+        CopyBufInto::new(self, writer)
+    }
+
+    /// Creates a future which will read all the bytes associated with this I/O
+    /// object into `buf` until the delimiter `byte` or EOF is reached.
+    /// This method is the async equivalent to
+    /// [`BufRead::read_until`](std::io::BufRead::read_until).
+    ///
+    /// All bytes up to, and including, the delimiter (if found) are appended
+    /// to `buf`, and the future resolves to the number of bytes appended. Bytes
+    /// that were read before an error or dropping the future are kept in
+    /// `buf`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::io::AsyncBufReadExt;
+    /// use std::io::Cursor;
+    ///
+    /// let mut cursor = Cursor::new(b"lorem-ipsum");
+    /// let mut buf = vec![];
+    ///
+    /// // cursor is at 'l'
+    /// let num_bytes = await!(cursor.read_until(b'-', &mut buf))?;
+    /// assert_eq!(num_bytes, 6);
+    /// assert_eq!(buf, b"lorem-");
+    /// buf.clear();
+    ///
+    /// // cursor is at 'i'
+    /// let num_bytes = await!(cursor.read_until(b'-', &mut buf))?;
+    /// assert_eq!(num_bytes, 5);
+    /// assert_eq!(buf, b"ipsum");
+    /// buf.clear();
+    ///
+    /// // cursor is at EOF
+    /// let num_bytes = await!(cursor.read_until(b'-', &mut buf))?;
+    /// assert_eq!(num_bytes, 0);
+    /// assert_eq!(buf, b"");
+    /// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn read_until<'a>(
+        &'a mut self,
+        byte: u8,
+        buf: &'a mut Vec<u8>,
+    ) -> ReadUntil<'a, Self> {
+        ReadUntil::new(self, byte, buf)
+    }
+
+    /// Creates a future which will read all the bytes associated with this I/O
+    /// object into `buf` until a newline (the 0xA byte) or EOF is reached.
+    /// This method is the async equivalent to
+    /// [`BufRead::read_line`](std::io::BufRead::read_line).
+    ///
+    /// The line, including the newline (if found), is appended to `buf` and
+    /// the future resolves to the number of bytes read.
+    ///
+    /// If the line is not valid UTF-8, the future resolves to an
+    /// [`InvalidData`](std::io::ErrorKind::InvalidData) error and `buf` is
+    /// left unchanged. The bytes of the line are not lost, the inner error is
+    /// a [`FromUtf8Error`](std::string::FromUtf8Error) holding them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::io::AsyncBufReadExt;
+    /// use std::io::Cursor;
+    ///
+    /// let mut cursor = Cursor::new(b"foo\nbar");
+    /// let mut buf = String::new();
+    ///
+    /// // cursor is at 'f'
+    /// let num_bytes = await!(cursor.read_line(&mut buf))?;
+    /// assert_eq!(num_bytes, 4);
+    /// assert_eq!(buf, "foo\n");
+    /// buf.clear();
+    ///
+    /// // cursor is at 'b'
+    /// let num_bytes = await!(cursor.read_line(&mut buf))?;
+    /// assert_eq!(num_bytes, 3);
+    /// assert_eq!(buf, "bar");
+    /// buf.clear();
+    ///
+    /// // cursor is at EOF
+    /// let num_bytes = await!(cursor.read_line(&mut buf))?;
+    /// assert_eq!(num_bytes, 0);
+    /// assert_eq!(buf, "");
+    /// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn read_line<'a>(&'a mut self, buf: &'a mut String) -> ReadLine<'a, Self> {
+        ReadLine::new(self, buf)
+    }
+
+    /// Returns a stream over the lines of this reader.
+    /// This method is the async equivalent to
+    /// [`BufRead::lines`](std::io::BufRead::lines).
+    ///
+    /// Each line is yielded without its newline byte (the 0xA byte) or a
+    /// carriage return right before it (CRLF). The last line is yielded even
+    /// if it doesn't end with a newline. The stream ends at EOF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::io::AsyncBufReadExt;
+    /// use futures::stream::TryStreamExt;
+    /// use std::io::Cursor;
+    ///
+    /// let cursor = Cursor::new(b"lorem\nipsum\r\ndolor");
+    ///
+    /// let lines: Vec<String> = await!(cursor.lines().try_collect())?;
+    /// assert_eq!(lines, vec!["lorem", "ipsum", "dolor"]);
+    /// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn lines(self) -> Lines<Self>
+        where Self: Sized,
+    {
+        Lines::new(self)
+    }
+}
+
+impl<R: AsyncBufRead + ?Sized> AsyncBufReadExt for R {}
+
 /// An extension trait which adds utility methods to `AsyncWrite` types.
 pub trait AsyncWriteExt: AsyncWrite {
     /// Creates a future which will entirely flush this `AsyncWrite`.
@@ -234,14 +578,12 @@ pub trait AsyncWriteExt: AsyncWrite {
     /// ```
     /// #![feature(async_await, await_macro, futures_api)]
     /// # futures::executor::block_on(async {
-    /// use futures::io::{AllowStdIo, AsyncWriteExt};
-    /// use std::io::{BufWriter, Cursor};
+    /// use futures::io::{AsyncWriteExt, BufWriter};
     ///
     /// let mut output = [0u8; 5];
     ///
     /// {
-    ///     let mut writer = Cursor::new(&mut output[..]);
-    ///     let mut buffered = AllowStdIo::new(BufWriter::new(writer));
+    ///     let mut buffered = BufWriter::new(&mut output[..]);
     ///     await!(buffered.write_all(&[1, 2]))?;
     ///     await!(buffered.write_all(&[3, 4]))?;
     ///     await!(buffered.flush())?;
@@ -255,6 +597,23 @@ pub trait AsyncWriteExt: AsyncWrite {
     }
 
     /// Creates a future which will entirely close this `AsyncWrite`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::io::AsyncWriteExt;
+    /// use std::io::Cursor;
+    ///
+    /// let mut writer = Cursor::new(Vec::new());
+    ///
+    /// await!(writer.write_all(&[1, 2, 3]))?;
+    /// await!(writer.close())?;
+    ///
+    /// assert_eq!(writer.into_inner(), [1, 2, 3]);
+    /// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
+    /// ```
     fn close(&mut self) -> Close<'_, Self> {
         Close::new(self)
     }
@@ -264,7 +623,9 @@ pub trait AsyncWriteExt: AsyncWrite {
     /// Creates a future that will write the entire contents of the buffer `buf` into
     /// this `AsyncWrite`.
     ///
-    /// The returned future will not complete until all the data has been written.
+    /// The returned future will not complete until all the data has been
+    /// written. A write that accepts no bytes fails the future with a
+    /// [`WriteZero`](std::io::ErrorKind::WriteZero) error.
     ///
     /// # Examples
     ///
@@ -274,7 +635,7 @@ pub trait AsyncWriteExt: AsyncWrite {
     /// use futures::io::AsyncWriteExt;
     /// use std::io::Cursor;
     ///
-    /// let mut writer = Cursor::new([0u8; 5]);
+    /// let mut writer = Cursor::new(vec![0u8; 5]);
     ///
     /// await!(writer.write_all(&[1, 2, 3, 4]))?;
     ///
@@ -284,6 +645,123 @@ pub trait AsyncWriteExt: AsyncWrite {
     fn write_all<'a>(&'a mut self, buf: &'a [u8]) -> WriteAll<'a, Self> {
         WriteAll::new(self, buf)
     }
+
+    /// Write data from multiple buffers into this object.
+    ///
+    /// Creates a future that will write the entire contents of the buffers
+    /// in `bufs` into this `AsyncWrite`, in order, using vectored IO
+    /// operations.
+    ///
+    /// The returned future will not complete until all the data has been
+    /// written. Partial writes are continued from the first byte that hasn't
+    /// been written, even if that is in the middle of one of the buffers. A
+    /// write that accepts no bytes fails the future with a
+    /// [`WriteZero`](std::io::ErrorKind::WriteZero) error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncWriteExt, IoVec};
+    ///
+    /// let header = IoVec::from_bytes(b"len=5;").unwrap();
+    /// let payload = IoVec::from_bytes(b"hello").unwrap();
+    ///
+    /// let mut writer = Vec::new();
+    /// await!(writer.write_vectored_all(&[header, payload]))?;
+    ///
+    /// assert_eq!(writer, b"len=5;hello");
+    /// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn write_vectored_all<'a>(
+        &'a mut self,
+        bufs: &'a [&'a IoVec],
+    ) -> WriteVectoredAll<'a, Self> {
+        WriteVectoredAll::new(self, bufs)
+    }
+
+    /// Wraps an [`AsyncWrite`] into a type that implements tokio-io's
+    /// [`AsyncWrite`](tokio_io::AsyncWrite). Requires the `io-compat` feature
+    /// to be enabled.
+    ///
+    /// This works like [`AsyncReadExt::compat`], tokio-io's
+    /// [`shutdown`](tokio_io::AsyncWrite::shutdown) is mapped to
+    /// [`poll_close`](AsyncWrite::poll_close).
+    #[cfg(feature = "io-compat")]
+    fn compat_write<Sp>(self, spawn: Sp) -> Compat<Self, Sp>
+        where Self: Sized,
+              Sp: Spawn,
+    {
+        Compat::new(self, Some(spawn))
+    }
+
+    /// Turns this writer into a sink of byte buffers.
+    ///
+    /// Every item sent into the sink is written to this writer in full before
+    /// the next item is accepted, so a partially written item is kept across
+    /// polls. Flushing and closing the sink flush and close the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::io::AsyncWriteExt;
+    /// use futures::sink::SinkExt;
+    ///
+    /// let writer: Vec<u8> = Vec::new();
+    /// let mut sink = writer.into_sink();
+    /// await!(sink.send(&b"hello "[..]))?;
+    /// await!(sink.send(&b"world"[..]))?;
+    ///
+    /// assert_eq!(sink.into_inner(), b"hello world");
+    /// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn into_sink<Item: AsRef<[u8]>>(self) -> IntoSink<Self, Item>
+        where Self: Sized,
+    {
+        IntoSink::new(self)
+    }
 }
 
 impl<W: AsyncWrite + ?Sized> AsyncWriteExt for W {}
+
+/// An extension trait which adds utility methods to `AsyncSeek` types.
+pub trait AsyncSeekExt: AsyncSeek {
+    /// Creates a future which will seek an IO object, and then yield the
+    /// new position in the object, in bytes from the start.
+    /// This method is the async equivalent to
+    /// [`Seek::seek`](std::io::Seek::seek).
+    ///
+    /// In the case of an error the returned future will resolve to the error.
+    /// The position of the object after an error depends on the
+    /// implementation, e.g. [`BufReader`] may already have moved its inner
+    /// reader and discarded its buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::io::{AsyncReadExt, AsyncSeekExt, SeekFrom};
+    /// use std::io::Cursor;
+    ///
+    /// let mut cursor = Cursor::new([1, 2, 3, 4, 5]);
+    /// let mut buf = [0; 2];
+    ///
+    /// assert_eq!(await!(cursor.seek(SeekFrom::End(-2)))?, 3);
+    /// await!(cursor.read_exact(&mut buf))?;
+    /// assert_eq!(buf, [4, 5]);
+    ///
+    /// assert_eq!(await!(cursor.seek(SeekFrom::Start(1)))?, 1);
+    /// await!(cursor.read_exact(&mut buf))?;
+    /// assert_eq!(buf, [2, 3]);
+    /// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn seek(&mut self, pos: SeekFrom) -> Seek<'_, Self> {
+        Seek::new(self, pos)
+    }
+}
+
+impl<S: AsyncSeek + ?Sized> AsyncSeekExt for S {}
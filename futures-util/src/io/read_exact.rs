@@ -15,18 +15,22 @@ use std::mem::{self, PinMut};
 pub struct ReadExact<'a, R: ?Sized + 'a> {
     reader: &'a mut R,
     buf: &'a mut [u8],
+    read: usize,
 }
 
 impl<R: ?Sized> Unpin for ReadExact<'_, R> {}
 
 impl<'a, R: AsyncRead + ?Sized> ReadExact<'a, R> {
     pub(super) fn new(reader: &'a mut R, buf: &'a mut [u8]) -> Self {
-        ReadExact { reader, buf }
+        ReadExact { reader, buf, read: 0 }
     }
 }
 
-fn eof() -> io::Error {
-    io::Error::new(io::ErrorKind::UnexpectedEof, "early eof")
+fn eof(read: usize, missing: usize) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::UnexpectedEof,
+        format!("early eof after reading {} bytes, {} bytes missing", read, missing),
+    )
 }
 
 impl<R: AsyncRead + ?Sized> Future for ReadExact<'_, R> {
@@ -36,13 +40,14 @@ impl<R: AsyncRead + ?Sized> Future for ReadExact<'_, R> {
         let this = &mut *self;
         while !this.buf.is_empty() {
             let n = try_ready!(this.reader.poll_read(cx, this.buf));
+            if n == 0 {
+                return Poll::Ready(Err(eof(this.read, this.buf.len())))
+            }
             {
                 let (_, rest) = mem::replace(&mut this.buf, &mut []).split_at_mut(n);
                 this.buf = rest;
             }
-            if n == 0 {
-                return Poll::Ready(Err(eof()))
-            }
+            this.read += n;
         }
         Poll::Ready(Ok(()))
     }
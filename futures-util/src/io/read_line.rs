@@ -0,0 +1,56 @@
+use super::read_until::read_until_internal;
+use futures_core::future::Future;
+use futures_core::task::{self, Poll};
+use futures_io::AsyncBufRead;
+use std::io;
+use std::marker::Unpin;
+use std::mem::{self, PinMut};
+use std::string::String;
+use std::vec::Vec;
+
+/// Future for the [`read_line`](super::AsyncBufReadExt::read_line) method.
+#[derive(Debug)]
+pub struct ReadLine<'a, R: ?Sized + 'a> {
+    reader: &'a mut R,
+    buf: &'a mut String,
+    bytes: Vec<u8>,
+    read: usize,
+}
+
+// We never project pinning to fields
+impl<R: ?Sized> Unpin for ReadLine<'_, R> {}
+
+impl<'a, R: AsyncBufRead + ?Sized> ReadLine<'a, R> {
+    pub(super) fn new(reader: &'a mut R, buf: &'a mut String) -> Self {
+        ReadLine { reader, buf, bytes: Vec::new(), read: 0 }
+    }
+}
+
+// The line is collected in `bytes` and only appended to `buf` once it is
+// complete, so that `buf` never contains invalid UTF-8. If the line is not
+// valid UTF-8, the error carries the bytes as a `FromUtf8Error`.
+pub(super) fn read_line_internal<R: AsyncBufRead + ?Sized>(
+    reader: &mut R,
+    cx: &mut task::Context,
+    buf: &mut String,
+    bytes: &mut Vec<u8>,
+    read: &mut usize,
+) -> Poll<io::Result<usize>> {
+    let n = try_ready!(read_until_internal(reader, cx, b'\n', bytes, read));
+    match String::from_utf8(mem::replace(bytes, Vec::new())) {
+        Ok(line) => {
+            buf.push_str(&line);
+            Poll::Ready(Ok(n))
+        }
+        Err(err) => Poll::Ready(Err(io::Error::new(io::ErrorKind::InvalidData, err))),
+    }
+}
+
+impl<R: AsyncBufRead + ?Sized> Future for ReadLine<'_, R> {
+    type Output = io::Result<usize>;
+
+    fn poll(mut self: PinMut<Self>, cx: &mut task::Context) -> Poll<Self::Output> {
+        let this = &mut *self;
+        read_line_internal(this.reader, cx, this.buf, &mut this.bytes, &mut this.read)
+    }
+}
@@ -16,6 +16,7 @@ use std::vec::Vec;
 pub struct ReadToEnd<'a, R: ?Sized + 'a> {
     reader: &'a mut R,
     buf: &'a mut Vec<u8>,
+    start_len: usize,
 }
 
 // We never project pinning to fields
@@ -23,7 +24,8 @@ impl<R: ?Sized> Unpin for ReadToEnd<'_, R> {}
 
 impl<'a, R: AsyncRead + ?Sized> ReadToEnd<'a, R> {
     pub(super) fn new(reader: &'a mut R, buf: &'a mut Vec<u8>) -> Self {
-        ReadToEnd { reader, buf }
+        let start_len = buf.len();
+        ReadToEnd { reader, buf, start_len }
     }
 }
 
@@ -43,12 +45,15 @@ impl Drop for Guard<'_> {
 // amount of data to return.
 //
 // Because we're extending the buffer with uninitialized data for trusted
-// readers, we need to make sure to truncate that if any of this panics.
+// readers, we need to make sure to truncate that if any of this panics. The
+// same guard keeps the bytes read so far in the buffer when the future returns
+// `Pending` or is dropped.
 fn read_to_end_internal<R: AsyncRead + ?Sized>(
     rd: &mut R,
     cx: &mut task::Context,
     buf: &mut Vec<u8>,
-) -> Poll<io::Result<()>> {
+    start_len: usize,
+) -> Poll<io::Result<usize>> {
     let mut g = Guard { len: buf.len(), buf };
     let ret;
     loop {
@@ -63,7 +68,7 @@ fn read_to_end_internal<R: AsyncRead + ?Sized>(
 
         match rd.poll_read(cx, &mut g.buf[g.len..]) {
             Poll::Ready(Ok(0)) => {
-                ret = Poll::Ready(Ok(()));
+                ret = Poll::Ready(Ok(g.len - start_len));
                 break;
             }
             Poll::Ready(Ok(n)) => g.len += n,
@@ -81,10 +86,10 @@ fn read_to_end_internal<R: AsyncRead + ?Sized>(
 impl<A> Future for ReadToEnd<'_, A>
     where A: AsyncRead + ?Sized,
 {
-    type Output = io::Result<()>;
+    type Output = io::Result<usize>;
 
     fn poll(mut self: PinMut<Self>, cx: &mut task::Context) -> Poll<Self::Output> {
         let this = &mut *self;
-        read_to_end_internal(this.reader, cx, this.buf)
+        read_to_end_internal(this.reader, cx, this.buf, this.start_len)
     }
 }
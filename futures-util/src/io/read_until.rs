@@ -0,0 +1,63 @@
+use futures_core::future::Future;
+use futures_core::task::{self, Poll};
+use futures_io::AsyncBufRead;
+use std::io;
+use std::marker::Unpin;
+use std::mem::{self, PinMut};
+use std::vec::Vec;
+
+/// Future for the [`read_until`](super::AsyncBufReadExt::read_until) method.
+#[derive(Debug)]
+pub struct ReadUntil<'a, R: ?Sized + 'a> {
+    reader: &'a mut R,
+    byte: u8,
+    buf: &'a mut Vec<u8>,
+    read: usize,
+}
+
+// We never project pinning to fields
+impl<R: ?Sized> Unpin for ReadUntil<'_, R> {}
+
+impl<'a, R: AsyncBufRead + ?Sized> ReadUntil<'a, R> {
+    pub(super) fn new(reader: &'a mut R, byte: u8, buf: &'a mut Vec<u8>) -> Self {
+        ReadUntil { reader, byte, buf, read: 0 }
+    }
+}
+
+// Appends everything up to and including `byte` to `buf`. `read` counts
+// the bytes appended over multiple calls that returned `Pending`, it is reset
+// once the delimiter or EOF is reached.
+pub(super) fn read_until_internal<R: AsyncBufRead + ?Sized>(
+    reader: &mut R,
+    cx: &mut task::Context,
+    byte: u8,
+    buf: &mut Vec<u8>,
+    read: &mut usize,
+) -> Poll<io::Result<usize>> {
+    loop {
+        let (done, used) = {
+            let available = try_ready!(reader.poll_fill_buf(cx));
+            if let Some(i) = available.iter().position(|&b| b == byte) {
+                buf.extend_from_slice(&available[..=i]);
+                (true, i + 1)
+            } else {
+                buf.extend_from_slice(available);
+                (false, available.len())
+            }
+        };
+        reader.consume(used);
+        *read += used;
+        if done || used == 0 {
+            return Poll::Ready(Ok(mem::replace(read, 0)));
+        }
+    }
+}
+
+impl<R: AsyncBufRead + ?Sized> Future for ReadUntil<'_, R> {
+    type Output = io::Result<usize>;
+
+    fn poll(mut self: PinMut<Self>, cx: &mut task::Context) -> Poll<Self::Output> {
+        let this = &mut *self;
+        read_until_internal(this.reader, cx, this.byte, this.buf, &mut this.read)
+    }
+}
@@ -0,0 +1,31 @@
+use futures_core::future::Future;
+use futures_core::task::{self, Poll};
+use futures_io::{AsyncSeek, SeekFrom};
+use std::io;
+use std::marker::Unpin;
+use std::mem::PinMut;
+
+/// Future for the [`seek`](super::AsyncSeekExt::seek) method.
+#[derive(Debug)]
+pub struct Seek<'a, S: ?Sized + 'a> {
+    seek: &'a mut S,
+    pos: SeekFrom,
+}
+
+// We never project pinning to fields
+impl<S: ?Sized> Unpin for Seek<'_, S> {}
+
+impl<'a, S: AsyncSeek + ?Sized> Seek<'a, S> {
+    pub(super) fn new(seek: &'a mut S, pos: SeekFrom) -> Self {
+        Seek { seek, pos }
+    }
+}
+
+impl<S: AsyncSeek + ?Sized> Future for Seek<'_, S> {
+    type Output = io::Result<u64>;
+
+    fn poll(mut self: PinMut<Self>, cx: &mut task::Context) -> Poll<Self::Output> {
+        let this = &mut *self;
+        this.seek.poll_seek(cx, this.pos)
+    }
+}
@@ -0,0 +1,64 @@
+use futures_core::task::{self, Poll};
+use futures_io::{AsyncWrite, IoVec};
+use std::fmt;
+use std::io;
+
+/// Writer for the [`sink()`] function.
+pub struct Sink {
+    _priv: (),
+}
+
+/// Creates an instance of a writer which will successfully consume all data.
+///
+/// All calls to `poll_write` on the returned instance will return
+/// `Poll::Ready(Ok(buf.len()))` and the contents of the buffer will not be
+/// inspected.
+///
+/// # Examples
+///
+/// ```rust
+/// #![feature(async_await, await_macro, futures_api)]
+/// # futures::executor::block_on(async {
+/// use futures::io::{self, AsyncWriteExt};
+///
+/// let buffer = vec![1, 2, 3, 5, 8];
+/// let mut writer = io::sink();
+/// await!(writer.write_all(&buffer))?;
+/// await!(writer.close())?;
+/// # Ok::<(), Box<std::error::Error>>(()) }).unwrap();
+/// ```
+pub fn sink() -> Sink {
+    Sink { _priv: () }
+}
+
+impl AsyncWrite for Sink {
+    #[inline]
+    fn poll_write(&mut self, _: &mut task::Context, buf: &[u8])
+        -> Poll<io::Result<usize>>
+    {
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    #[inline]
+    fn poll_vectored_write(&mut self, _: &mut task::Context, vec: &[&IoVec])
+        -> Poll<io::Result<usize>>
+    {
+        Poll::Ready(Ok(vec.iter().map(|buf| buf.len()).sum()))
+    }
+
+    #[inline]
+    fn poll_flush(&mut self, _: &mut task::Context) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    #[inline]
+    fn poll_close(&mut self, _: &mut task::Context) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl fmt::Debug for Sink {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad("Sink { .. }")
+    }
+}
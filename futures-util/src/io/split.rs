@@ -1,7 +1,10 @@
 use crate::lock::BiLock;
 use futures_core::task::{self, Poll};
 use futures_io::{AsyncRead, AsyncWrite, IoVec};
-use std::io;
+use std::any::Any;
+use std::error::Error;
+use std::{fmt, io};
+use std::marker::Unpin;
 use std::mem::PinMut;
 
 /// The readable half of an object returned from `AsyncRead::split`.
@@ -36,6 +39,28 @@ pub fn split<T: AsyncRead + AsyncWrite>(t: T) -> (ReadHalf<T>, WriteHalf<T>) {
     (ReadHalf { handle: a }, WriteHalf { handle: b })
 }
 
+impl<T: Unpin> ReadHalf<T> {
+    /// Attempts to put the two "halves" of a split `AsyncRead + AsyncWrite`
+    /// back together. Succeeds only if the `ReadHalf<T>` and `WriteHalf<T>`
+    /// are a matching pair originating from the same call to
+    /// `AsyncReadExt::split`.
+    pub fn reunite(self, other: WriteHalf<T>) -> Result<T, ReuniteError<T>> {
+        self.handle.reunite(other.handle).map_err(|err| {
+            ReuniteError(ReadHalf { handle: err.0 }, WriteHalf { handle: err.1 })
+        })
+    }
+}
+
+impl<T: Unpin> WriteHalf<T> {
+    /// Attempts to put the two "halves" of a split `AsyncRead + AsyncWrite`
+    /// back together. Succeeds only if the `ReadHalf<T>` and `WriteHalf<T>`
+    /// are a matching pair originating from the same call to
+    /// `AsyncReadExt::split`.
+    pub fn reunite(self, other: ReadHalf<T>) -> Result<T, ReuniteError<T>> {
+        other.reunite(self)
+    }
+}
+
 impl<R: AsyncRead> AsyncRead for ReadHalf<R> {
     fn poll_read(&mut self, cx: &mut task::Context, buf: &mut [u8])
         -> Poll<io::Result<usize>>
@@ -71,3 +96,27 @@ impl<W: AsyncWrite> AsyncWrite for WriteHalf<W> {
         lock_and_then(&self.handle, cx, |l, cx| l.poll_close(cx))
     }
 }
+
+/// Error indicating a `ReadHalf<T>` and `WriteHalf<T>` were not two halves
+/// of a `AsyncRead + AsyncWrite`, and thus could not be `reunite`d.
+pub struct ReuniteError<T>(pub ReadHalf<T>, pub WriteHalf<T>);
+
+impl<T> fmt::Debug for ReuniteError<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_tuple("ReuniteError")
+            .field(&"...")
+            .finish()
+    }
+}
+
+impl<T> fmt::Display for ReuniteError<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "tried to reunite a ReadHalf and WriteHalf that don't form a pair")
+    }
+}
+
+impl<T: Any> Error for ReuniteError<T> {
+    fn description(&self) -> &str {
+        "tried to reunite a ReadHalf and WriteHalf that don't form a pair"
+    }
+}
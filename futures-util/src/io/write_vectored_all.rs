@@ -0,0 +1,67 @@
+use futures_core::future::Future;
+use futures_core::task::{self, Poll};
+use futures_io::{AsyncWrite, IoVec};
+use std::io;
+use std::marker::Unpin;
+use std::mem::PinMut;
+use std::vec::Vec;
+
+/// Future for the
+/// [`write_vectored_all`](super::AsyncWriteExt::write_vectored_all) method.
+#[derive(Debug)]
+pub struct WriteVectoredAll<'a, W: ?Sized + 'a> {
+    writer: &'a mut W,
+    bufs: &'a [&'a IoVec],
+    // Number of bytes of `bufs[0]` that have already been written.
+    offset: usize,
+}
+
+// Pinning is never projected to fields
+impl<W: ?Sized> Unpin for WriteVectoredAll<'_, W> {}
+
+impl<'a, W: AsyncWrite + ?Sized> WriteVectoredAll<'a, W> {
+    pub(super) fn new(writer: &'a mut W, bufs: &'a [&'a IoVec]) -> Self {
+        WriteVectoredAll { writer, bufs, offset: 0 }
+    }
+}
+
+fn zero_write() -> io::Error {
+    io::Error::new(io::ErrorKind::WriteZero, "zero-length write")
+}
+
+impl<W: AsyncWrite + ?Sized> Future for WriteVectoredAll<'_, W> {
+    type Output = io::Result<()>;
+
+    fn poll(mut self: PinMut<Self>, cx: &mut task::Context) -> Poll<io::Result<()>> {
+        let this = &mut *self;
+        while !this.bufs.is_empty() {
+            let n = if this.offset == 0 {
+                try_ready!(this.writer.poll_vectored_write(cx, this.bufs))
+            } else {
+                // The first buffer was written partially, so it is replaced
+                // by its unwritten rest. `offset` is always smaller than the
+                // length of the first buffer, so the rest is never empty.
+                let rest = IoVec::from_bytes(&this.bufs[0][this.offset..]).unwrap();
+                let mut bufs = Vec::with_capacity(this.bufs.len());
+                bufs.push(rest);
+                bufs.extend_from_slice(&this.bufs[1..]);
+                try_ready!(this.writer.poll_vectored_write(cx, &bufs))
+            };
+            if n == 0 {
+                return Poll::Ready(Err(zero_write()))
+            }
+
+            // Skip the buffers that were written completely.
+            this.offset += n;
+            while let Some(first) = this.bufs.first() {
+                if this.offset < first.len() {
+                    break;
+                }
+                this.offset -= first.len();
+                this.bufs = &this.bufs[1..];
+            }
+        }
+
+        Poll::Ready(Ok(()))
+    }
+}
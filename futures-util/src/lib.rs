@@ -3,6 +3,7 @@
 
 #![feature(async_await, pin, arbitrary_self_types, futures_api)]
 #![cfg_attr(feature = "std", feature(await_macro))]
+#![cfg_attr(feature = "alloc", feature(alloc))]
 #![cfg_attr(feature = "nightly", feature(cfg_target_has_atomic))]
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -19,6 +20,16 @@ macro_rules! if_std {
     )*)
 }
 
+macro_rules! if_alloc {
+    ($($i:item)*) => ($(
+        #[cfg(feature = "alloc")]
+        $i
+    )*)
+}
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[macro_use]
 mod macros;
 
@@ -82,13 +93,17 @@ pub mod sink;
 
 pub mod task;
 
+pub use futures_core::never;
+
 #[cfg(feature = "compat")]
 pub mod compat;
 
 if_std! {
     pub mod io;
     #[doc(hidden)] pub use crate::io::{AsyncReadExt, AsyncWriteExt};
+}
 
+if_alloc! {
     #[cfg(any(test, feature = "bench"))]
     pub mod lock;
     #[cfg(not(any(test, feature = "bench")))]
@@ -1,19 +1,18 @@
 //! Futures-powered synchronization primitives.
 #![allow(unused)]
 
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use core::any::Any;
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::marker::Unpin;
+use core::mem::{self, PinMut};
+use core::ops::{Deref, DerefMut};
+use core::sync::atomic::AtomicUsize;
+use core::sync::atomic::Ordering::SeqCst;
 use futures_core::future::Future;
 use futures_core::task::{self, Poll, Waker};
-use std::any::Any;
-use std::boxed::Box;
-use std::cell::UnsafeCell;
-use std::error::Error;
-use std::fmt;
-use std::marker::Unpin;
-use std::mem::{self, PinMut};
-use std::ops::{Deref, DerefMut};
-use std::sync::Arc;
-use std::sync::atomic::AtomicUsize;
-use std::sync::atomic::Ordering::SeqCst;
 
 /// A type of futures-powered synchronization primitive which is a mutex between
 /// two possible owners.
@@ -210,7 +209,8 @@ impl<T> fmt::Display for ReuniteError<T> {
     }
 }
 
-impl<T: Any> Error for ReuniteError<T> {
+#[cfg(feature = "std")]
+impl<T: Any> ::std::error::Error for ReuniteError<T> {
     fn description(&self) -> &str {
         "tried to reunite two BiLocks that don't form a pair"
     }
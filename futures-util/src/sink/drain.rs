@@ -1,6 +1,6 @@
-use core::fmt;
 use core::marker::PhantomData;
 use core::mem::PinMut;
+use futures_core::never::Never;
 use futures_core::task::{self, Poll};
 use futures_sink::Sink;
 
@@ -13,14 +13,10 @@ pub struct Drain<T> {
     marker: PhantomData<T>,
 }
 
-/// The error type for the [`Drain`] sink.
-#[derive(Debug)]
-pub enum DrainError {
-}
-
 /// Create a sink that will just discard all items given to it.
 ///
-/// Similar to [`io::Sink`](::std::io::Sink).
+/// Similar to [`io::Sink`](::std::io::Sink). The sink can never fail, so its
+/// error type is [`Never`].
 ///
 /// # Examples
 ///
@@ -31,7 +27,7 @@ pub enum DrainError {
 ///
 /// let mut drain = sink::drain();
 /// await!(drain.send(5))?;
-/// # Ok::<(), futures::sink::DrainError>(()) }).unwrap();
+/// # Ok::<(), futures::never::Never>(()) }).unwrap();
 /// ```
 pub fn drain<T>() -> Drain<T> {
     Drain { marker: PhantomData }
@@ -39,7 +35,7 @@ pub fn drain<T>() -> Drain<T> {
 
 impl<T> Sink for Drain<T> {
     type SinkItem = T;
-    type SinkError = DrainError;
+    type SinkError = Never;
 
     fn poll_ready(
         self: PinMut<Self>,
@@ -69,22 +65,3 @@ impl<T> Sink for Drain<T> {
         Poll::Ready(Ok(()))
     }
 }
-
-impl fmt::Display for DrainError {
-    fn fmt(&self, _: &mut fmt::Formatter) -> fmt::Result {
-        match *self {
-        }
-    }
-}
-
-#[cfg(feature = "std")]
-impl std::error::Error for DrainError {
-}
-
-impl DrainError {
-    /// Convert this drain error into any type
-    pub fn into_any<T>(self) -> T {
-        match self {
-        }
-    }
-}
@@ -3,8 +3,8 @@
 //! This module contains a number of functions for working with `Sink`s,
 //! including the `SinkExt` trait which adds methods to `Sink` types.
 
+use crate::future::Either;
 use core::marker::Unpin;
-use either::Either;
 use futures_core::future::Future;
 use futures_core::stream::Stream;
 use futures_sink::Sink;
@@ -19,7 +19,7 @@ mod close;
 pub use self::close::Close;
 
 mod drain;
-pub use self::drain::{drain, Drain, DrainError};
+pub use self::drain::{drain, Drain};
 
 mod fanout;
 pub use self::fanout::Fanout;
@@ -45,7 +45,7 @@ pub use self::with::With;
 mod with_flat_map;
 pub use self::with_flat_map::WithFlatMap;
 
-if_std! {
+if_alloc! {
     mod buffer;
     pub use self::buffer::Buffer;
 }
@@ -158,9 +158,9 @@ pub trait SinkExt: Sink {
     /// Note that this function consumes the given sink, returning a wrapped
     /// version, much like `Iterator::map`.
     ///
-    /// This method is only available when the `std` feature of this
+    /// This method is only available when the `alloc` feature of this
     /// library is activated, and it is activated by default.
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     fn buffer(self, capacity: usize) -> Buffer<Self>
         where Self: Sized,
     {
@@ -1,12 +1,12 @@
 use crate::stream::{Fuse, FuturesOrdered};
+use core::fmt;
+use core::marker::Unpin;
+use core::mem::PinMut;
 use futures_core::future::Future;
 use futures_core::stream::Stream;
 use futures_core::task::{self, Poll};
 use futures_sink::Sink;
 use pin_utils::{unsafe_pinned, unsafe_unpinned};
-use std::fmt;
-use std::marker::Unpin;
-use std::mem::PinMut;
 
 /// An adaptor for a stream of futures to execute the futures concurrently, if
 /// possible.
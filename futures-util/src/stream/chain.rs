@@ -48,4 +48,18 @@ where St1: Stream,
         PinMut::set(self.first(), None);
         self.second().poll_next(cx)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let second = self.second.size_hint();
+        let first = match &self.first {
+            Some(first) => first.size_hint(),
+            None => return second,
+        };
+        let lower = first.0.saturating_add(second.0);
+        let upper = match (first.1, second.1) {
+            (Some(x), Some(y)) => x.checked_add(y),
+            _ => None,
+        };
+        (lower, upper)
+    }
 }
@@ -1,10 +1,10 @@
 use crate::stream::Fuse;
+use alloc::vec::Vec;
+use core::marker::Unpin;
+use core::mem::{self, PinMut};
 use futures_core::stream::Stream;
 use futures_core::task::{self, Poll};
 use pin_utils::{unsafe_pinned, unsafe_unpinned};
-use std::marker::Unpin;
-use std::mem::{self, PinMut};
-use std::prelude::v1::*;
 
 /// An adaptor that chunks up elements in a vector.
 ///
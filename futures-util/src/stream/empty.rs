@@ -1,6 +1,6 @@
 use core::marker::{Unpin, PhantomData};
 use core::mem::PinMut;
-use futures_core::stream::Stream;
+use futures_core::stream::{Stream, FusedStream};
 use futures_core::task::{self, Poll};
 
 /// A stream which contains no elements.
@@ -23,10 +23,20 @@ pub fn empty<T>() -> Empty<T> {
 
 impl<T> Unpin for Empty<T> {}
 
+impl<T> FusedStream for Empty<T> {
+    fn is_terminated(&self) -> bool {
+        true
+    }
+}
+
 impl<T> Stream for Empty<T> {
     type Item = T;
 
     fn poll_next(self: PinMut<Self>, _: &mut task::Context) -> Poll<Option<Self::Item>> {
         Poll::Ready(None)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(0))
+    }
 }
@@ -0,0 +1,81 @@
+use core::marker::Unpin;
+use core::mem::PinMut;
+use futures_core::stream::Stream;
+use futures_core::task::{self, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// A stream combinator used to filter the results of a stream with a
+/// synchronous predicate and only yield some values.
+///
+/// This structure is produced by the `Stream::filter_sync` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct FilterSync<St, F> {
+    stream: St,
+    f: F,
+}
+
+impl<St: Unpin, F> Unpin for FilterSync<St, F> {}
+
+impl<St, F> FilterSync<St, F>
+    where St: Stream,
+          F: FnMut(&St::Item) -> bool,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+
+    pub(super) fn new(stream: St, f: F) -> FilterSync<St, F> {
+        FilterSync { stream, f }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St, F> Stream for FilterSync<St, F>
+    where St: Stream,
+          F: FnMut(&St::Item) -> bool,
+{
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Option<St::Item>> {
+        loop {
+            let item = match ready!(self.stream().poll_next(cx)) {
+                Some(item) => item,
+                None => return Poll::Ready(None),
+            };
+            if (self.f())(&item) {
+                return Poll::Ready(Some(item));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Any number of the items can be filtered out
+        let (_, upper) = self.stream.size_hint();
+        (0, upper)
+    }
+}
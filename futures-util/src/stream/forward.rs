@@ -1,7 +1,8 @@
 use crate::stream::{StreamExt, Fuse};
+use core::fmt;
 use core::marker::Unpin;
 use core::mem::PinMut;
-use futures_core::future::Future;
+use futures_core::future::{Future, FusedFuture};
 use futures_core::stream::Stream;
 use futures_core::task::{self, Poll};
 use futures_sink::Sink;
@@ -9,8 +10,12 @@ use pin_utils::{unsafe_pinned, unsafe_unpinned};
 
 const INVALID_POLL: &str = "polled `Forward` after completion";
 
-/// Future for the `Stream::forward` combinator, which sends a stream of values
-/// to a sink and then flushes the sink.
+/// Future for the `Stream::forward` and `Stream::forward_err_into`
+/// combinators, which send a stream of values to a sink and then flush the
+/// sink.
+///
+/// Errors of the stream are converted into the error type of the sink with
+/// `Into`.
 ///
 /// Note: this is only usable with `Unpin` sinks, so `Sink`s that aren't `Unpin`
 /// will need to be pinned in order to be used with this combinator.
@@ -20,9 +25,7 @@ const INVALID_POLL: &str = "polled `Forward` after completion";
 #[derive(Debug)]
 #[must_use = "steams do nothing unless polled"]
 pub struct Forward<St: Stream, Si: Sink + Unpin> {
-    sink: Option<Si>,
-    stream: Fuse<St>,
-    buffered_item: Option<Si::SinkItem>,
+    inner: ForwardCore<St, Si>,
 }
 
 impl<St: Stream + Unpin, Si: Sink + Unpin> Unpin for Forward<St, Si> {}
@@ -30,19 +33,123 @@ impl<St: Stream + Unpin, Si: Sink + Unpin> Unpin for Forward<St, Si> {}
 impl<St, Si> Forward<St, Si>
 where
     Si: Sink + Unpin,
-    St: Stream<Item = Result<Si::SinkItem, Si::SinkError>>,
+    St: Stream,
+{
+    unsafe_pinned!(inner: ForwardCore<St, Si>);
+
+    pub(super) fn new(stream: St, sink: Si) -> Forward<St, Si> {
+        Forward { inner: ForwardCore::new(stream, sink) }
+    }
+
+    /// Keeps a clone of every item while it is passed to
+    /// [`start_send`](futures_sink::Sink::start_send), so that an item the
+    /// sink fails to accept can still be recovered with
+    /// [`into_parts`](Forward::into_parts).
+    ///
+    /// Without this, an item passed to a failing `start_send` is owned by the
+    /// sink and lost, unless the error of the sink carries it.
+    pub fn recover_failed_sends(self) -> Forward<St, Si>
+        where Si::SinkItem: Clone,
+    {
+        let inner = ForwardCore {
+            clone_item: Some(CloneItem(Clone::clone)),
+            ..self.inner
+        };
+        Forward { inner }
+    }
+}
+
+impl<St, Si> Forward<St, Si>
+where
+    Si: Sink + Unpin,
+    St: Stream + Unpin,
+{
+    /// Consumes this combinator, returning the item that was taken from the
+    /// stream but not yet accepted by the sink, the underlying stream, and the
+    /// sink if it hasn't been returned yet.
+    ///
+    /// This allows recovering everything which was not sent after the sink
+    /// failed. Note that an item passed to a failing
+    /// [`start_send`](futures_sink::Sink::start_send) is owned by the sink
+    /// and is only returned if the future was set up with
+    /// [`recover_failed_sends`](Forward::recover_failed_sends), an item for
+    /// which [`poll_ready`](futures_sink::Sink::poll_ready) failed always is.
+    pub fn into_parts(self) -> (Option<Si::SinkItem>, St, Option<Si>) {
+        let inner = self.inner;
+        (inner.buffered_item, inner.stream.into_inner(), inner.sink)
+    }
+}
+
+// The sink is taken out when the future completes
+impl<St: Stream, Si: Sink + Unpin> FusedFuture for Forward<St, Si> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<St, Si, E> Future for Forward<St, Si>
+where
+    Si: Sink + Unpin,
+    St: Stream<Item = Result<Si::SinkItem, E>>,
+    E: Into<Si::SinkError>,
 {
+    type Output = Result<Si, Si::SinkError>;
+
+    fn poll(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Self::Output> {
+        try_ready!(self.reborrow().inner().poll_forward(cx));
+        Poll::Ready(Ok(self.inner().sink().take().unwrap()))
+    }
+}
+
+// The state and poll loop shared by `Forward` and `ForwardInto`, which only
+// differ in whether they hand the sink back once forwarding has completed.
+#[derive(Debug)]
+pub(super) struct ForwardCore<St: Stream, Si: Sink> {
+    sink: Option<Si>,
+    stream: Fuse<St>,
+    buffered_item: Option<Si::SinkItem>,
+    clone_item: Option<CloneItem<Si::SinkItem>>,
+}
+
+impl<St: Stream + Unpin, Si: Sink + Unpin> Unpin for ForwardCore<St, Si> {}
+
+// `Clone::clone` of the items, set by `Forward::recover_failed_sends`.
+struct CloneItem<T>(fn(&T) -> T);
+
+impl<T> fmt::Debug for CloneItem<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("CloneItem")
+    }
+}
+
+impl<St: Stream, Si: Sink> ForwardCore<St, Si> {
     unsafe_pinned!(sink: Option<Si>);
     unsafe_pinned!(stream: Fuse<St>);
     unsafe_unpinned!(buffered_item: Option<Si::SinkItem>);
 
-    pub(super) fn new(stream: St, sink: Si) -> Forward<St, Si> {
-    Forward {
-        sink: Some(sink),
-        stream: stream.fuse(),
+    pub(super) fn new(stream: St, sink: Si) -> ForwardCore<St, Si> {
+        ForwardCore {
+            sink: Some(sink),
+            stream: stream.fuse(),
             buffered_item: None,
+            clone_item: None,
+        }
+    }
+
+    // The sink is left in place once forwarding completes, it is up to the
+    // caller to take or drop it.
+    pub(super) fn is_terminated(&self) -> bool {
+        self.sink.is_none()
+    }
+
+    // Drops the sink after forwarding completed, which also works for sinks
+    // that aren't `Unpin`.
+    pub(super) fn drop_sink(mut self: PinMut<Self>) {
+        PinMut::set(self.sink(), None);
     }
-}
 
     fn try_start_send(
         mut self: PinMut<Self>,
@@ -50,28 +157,37 @@ where
         item: Si::SinkItem,
     ) -> Poll<Result<(), Si::SinkError>> {
         debug_assert!(self.buffered_item.is_none());
-        {
-            let mut sink = self.sink().as_pin_mut().unwrap();
-            if try_poll!(sink.reborrow().poll_ready(cx)).is_ready() {
-                return Poll::Ready(sink.start_send(item));
+        let ready = self.sink().as_pin_mut().expect(INVALID_POLL).poll_ready(cx);
+        match ready {
+            Poll::Ready(Ok(())) => {
+                let copy = self.clone_item.as_ref().map(|clone| (clone.0)(&item));
+                let res = self.sink().as_pin_mut().unwrap().start_send(item);
+                if res.is_err() {
+                    *self.buffered_item() = copy;
+                }
+                Poll::Ready(res)
+            }
+            // The item is kept if the sink fails before accepting it, so that
+            // it can be recovered with `into_parts`.
+            Poll::Ready(Err(e)) => {
+                *self.buffered_item() = Some(item);
+                Poll::Ready(Err(e))
+            }
+            Poll::Pending => {
+                *self.buffered_item() = Some(item);
+                Poll::Pending
             }
         }
-        *self.buffered_item() = Some(item);
-        Poll::Pending
     }
-}
 
-impl<St, Si> Future for Forward<St, Si>
-where
-    Si: Sink + Unpin,
-    St: Stream<Item = Result<Si::SinkItem, Si::SinkError>>,
-{
-    type Output = Result<Si, Si::SinkError>;
-
-    fn poll(
+    // Sends all items of the stream to the sink and closes it.
+    pub(super) fn poll_forward<E>(
         mut self: PinMut<Self>,
         cx: &mut task::Context,
-    ) -> Poll<Self::Output> {
+    ) -> Poll<Result<(), Si::SinkError>>
+        where St: Stream<Item = Result<Si::SinkItem, E>>,
+              E: Into<Si::SinkError>,
+    {
         // If we've got an item buffered already, we need to write it to the
         // sink before we can do anything else
         if let Some(item) = self.buffered_item().take() {
@@ -82,11 +198,10 @@ where
             match self.stream().poll_next(cx) {
                 Poll::Ready(Some(Ok(item))) =>
                    try_ready!(self.reborrow().try_start_send(cx, item)),
-                Poll::Ready(Some(Err(e))) => return Poll::Ready(Err(e)),
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Err(e.into())),
                 Poll::Ready(None) => {
-                    try_ready!(self.sink().as_pin_mut().expect(INVALID_POLL)
-                                   .poll_close(cx));
-                    return Poll::Ready(Ok(self.sink().take().unwrap()))
+                    return self.sink().as_pin_mut().expect(INVALID_POLL)
+                               .poll_close(cx)
                 }
                 Poll::Pending => {
                     try_ready!(self.sink().as_pin_mut().expect(INVALID_POLL)
@@ -0,0 +1,61 @@
+use super::forward::ForwardCore;
+use core::marker::Unpin;
+use core::mem::PinMut;
+use futures_core::future::{Future, FusedFuture};
+use futures_core::stream::Stream;
+use futures_core::task::{self, Poll};
+use futures_sink::Sink;
+use pin_utils::unsafe_pinned;
+
+/// Future for the `Stream::forward_into` combinator, which sends a stream of
+/// values to a sink and then flushes and closes the sink.
+///
+/// Errors of the stream are converted into the error type of the sink with
+/// `Into`.
+///
+/// Unlike [`Forward`](super::Forward), the sink is not returned on completion,
+/// so it is only ever accessed pinned and doesn't have to be `Unpin`. It is
+/// dropped once it has been closed.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct ForwardInto<St: Stream, Si: Sink> {
+    inner: ForwardCore<St, Si>,
+}
+
+impl<St: Stream + Unpin, Si: Sink + Unpin> Unpin for ForwardInto<St, Si> {}
+
+impl<St, Si> ForwardInto<St, Si>
+where
+    Si: Sink,
+    St: Stream,
+{
+    unsafe_pinned!(inner: ForwardCore<St, Si>);
+
+    pub(super) fn new(stream: St, sink: Si) -> ForwardInto<St, Si> {
+        ForwardInto { inner: ForwardCore::new(stream, sink) }
+    }
+}
+
+impl<St: Stream, Si: Sink> FusedFuture for ForwardInto<St, Si> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<St, Si, E> Future for ForwardInto<St, Si>
+where
+    Si: Sink,
+    St: Stream<Item = Result<Si::SinkItem, E>>,
+    E: Into<Si::SinkError>,
+{
+    type Output = Result<(), Si::SinkError>;
+
+    fn poll(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Self::Output> {
+        try_ready!(self.reborrow().inner().poll_forward(cx));
+        self.inner().drop_sink();
+        Poll::Ready(Ok(()))
+    }
+}
@@ -1,6 +1,6 @@
 use core::marker::Unpin;
 use core::mem::PinMut;
-use futures_core::stream::Stream;
+use futures_core::stream::{Stream, FusedStream};
 use futures_core::task::{self, Poll};
 use futures_sink::Sink;
 use pin_utils::{unsafe_pinned, unsafe_unpinned};
@@ -70,6 +70,12 @@ impl<St: Stream> Fuse<St> {
     }
 }
 
+impl<S> FusedStream for Fuse<S> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
 impl<S: Stream> Stream for Fuse<S> {
     type Item = S::Item;
 
@@ -87,6 +93,14 @@ impl<S: Stream> Stream for Fuse<S> {
         }
         Poll::Ready(item)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.stream.size_hint()
+        }
+    }
 }
 
 // Forwarding impl of Sink from the underlying stream
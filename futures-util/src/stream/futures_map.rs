@@ -0,0 +1,185 @@
+//! A set of futures which are identified by keys.
+
+use crate::stream::futures_unordered::TaskHandle;
+use crate::stream::{FuturesUnordered, StreamExt};
+use futures_core::future::Future;
+use futures_core::stream::Stream;
+use futures_core::task::{self, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::marker::Unpin;
+use std::mem::PinMut;
+
+/// A set of futures which may complete in any order, each identified by a
+/// key.
+///
+/// This is like [`FuturesUnordered`], but every future is inserted under a
+/// key, which allows replacing or removing a specific future before it
+/// completes. The stream yields the key of every future together with its
+/// output, and the completed future is removed from the set.
+///
+/// Like with [`FuturesUnordered`], only the futures which generated a wake-up
+/// notification are polled. A future which is removed after it was woken is
+/// dropped, and not polled again.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(futures_api)]
+/// use futures::executor::block_on;
+/// use futures::future;
+/// use futures::stream::{FuturesMap, StreamExt};
+///
+/// let mut futures = FuturesMap::new();
+/// futures.insert("a", future::ready(1));
+/// futures.insert("b", future::ready(2));
+/// futures.insert("c", future::ready(3));
+/// assert!(futures.remove(&"b"));
+///
+/// let mut outputs = block_on(futures.collect::<Vec<_>>());
+/// outputs.sort();
+/// assert_eq!(outputs, vec![("a", 1), ("c", 3)]);
+/// ```
+#[must_use = "streams do nothing unless polled"]
+pub struct FuturesMap<K, Fut> {
+    futures: FuturesUnordered<Keyed<K, Fut>>,
+    handles: HashMap<K, TaskHandle<Keyed<K, Fut>>>,
+}
+
+// The handles point into `futures`, which is `Send` and `Sync` if the futures
+// are, and are only dereferenced through `&mut self`.
+unsafe impl<K: Send, Fut: Send> Send for FuturesMap<K, Fut> {}
+unsafe impl<K: Sync, Fut: Sync> Sync for FuturesMap<K, Fut> {}
+impl<K, Fut> Unpin for FuturesMap<K, Fut> {}
+
+impl<K, Fut> FuturesMap<K, Fut>
+where
+    K: Hash + Eq + Clone,
+    Fut: Future,
+{
+    /// Constructs a new, empty [`FuturesMap`].
+    pub fn new() -> FuturesMap<K, Fut> {
+        FuturesMap {
+            futures: FuturesUnordered::new(),
+            handles: HashMap::new(),
+        }
+    }
+
+    /// Returns the number of futures contained in the set.
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    /// Returns `true` if the set contains no futures.
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+
+    /// Returns `true` if the set contains a future for the given key.
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.handles.contains_key(key)
+    }
+
+    /// Inserts a future into the set under the given key.
+    ///
+    /// If there already is a future for this key, it is dropped and replaced,
+    /// and `true` is returned. Like with
+    /// [`FuturesUnordered::push`], this method doesn't poll the future.
+    pub fn insert(&mut self, key: K, future: Fut) -> bool {
+        let replaced = self.remove(&key);
+        let handle = self.futures.push_with_handle(Keyed {
+            key: Some(key.clone()),
+            future,
+        });
+        self.handles.insert(key, handle);
+        replaced
+    }
+
+    /// Removes the future for the given key from the set and drops it.
+    ///
+    /// Returns `true` if there was a future for this key.
+    pub fn remove<Q>(&mut self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        match self.handles.remove(key) {
+            Some(handle) => {
+                // Safety: the handle is removed as soon as the future
+                // completes, so it still belongs to a future in the set.
+                unsafe { self.futures.remove(handle) };
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<K, Fut> Default for FuturesMap<K, Fut>
+where
+    K: Hash + Eq + Clone,
+    Fut: Future,
+{
+    fn default() -> FuturesMap<K, Fut> {
+        FuturesMap::new()
+    }
+}
+
+impl<K, Fut> Stream for FuturesMap<K, Fut>
+where
+    K: Hash + Eq + Clone,
+    Fut: Future,
+{
+    type Item = (K, Fut::Output);
+
+    fn poll_next(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Option<Self::Item>> {
+        match self.futures.poll_next_unpin(cx) {
+            Poll::Ready(Some((key, output))) => {
+                self.handles.remove(&key);
+                Poll::Ready(Some((key, output)))
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<K, Fut> fmt::Debug for FuturesMap<K, Fut> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("FuturesMap")
+            .field("len", &self.handles.len())
+            .finish()
+    }
+}
+
+/// A future in a `FuturesMap`, which outputs its key along with the output of
+/// the future.
+struct Keyed<K, Fut> {
+    key: Option<K>,
+    future: Fut,
+}
+
+impl<K, Fut> Keyed<K, Fut> {
+    unsafe_unpinned!(key: Option<K>);
+    unsafe_pinned!(future: Fut);
+}
+
+impl<K, Fut: Future> Future for Keyed<K, Fut> {
+    type Output = (K, Fut::Output);
+
+    fn poll(mut self: PinMut<Self>, cx: &mut task::Context) -> Poll<Self::Output> {
+        let output = ready!(self.future().poll(cx));
+        let key = self.key().take().expect("polled `Keyed` after completion");
+        Poll::Ready((key, output))
+    }
+}
@@ -1,14 +1,14 @@
 use crate::stream::FuturesUnordered;
+use alloc::collections::binary_heap::{BinaryHeap, PeekMut};
+use core::cmp::{Eq, PartialEq, PartialOrd, Ord, Ordering};
+use core::fmt::{self, Debug};
+use core::iter::FromIterator;
+use core::marker::Unpin;
+use core::mem::PinMut;
 use futures_core::future::Future;
 use futures_core::stream::Stream;
 use futures_core::task::{self, Poll};
 use pin_utils::unsafe_pinned;
-use std::cmp::{Eq, PartialEq, PartialOrd, Ord, Ordering};
-use std::collections::binary_heap::{BinaryHeap, PeekMut};
-use std::fmt::{self, Debug};
-use std::iter::FromIterator;
-use std::marker::Unpin;
-use std::mem::PinMut;
 
 #[must_use = "futures do nothing unless polled"]
 #[derive(Debug)]
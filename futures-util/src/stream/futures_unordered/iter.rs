@@ -1,7 +1,7 @@
 use super::FuturesUnordered;
 use super::task::Task;
-use std::marker::{PhantomData, Unpin};
-use std::mem::PinMut;
+use core::marker::{PhantomData, Unpin};
+use core::mem::PinMut;
 
 #[derive(Debug)]
 /// Mutable iterator over all futures in the unordered set.
@@ -1,19 +1,19 @@
 //! An unbounded set of futures.
 
 use crate::task::AtomicWaker;
+use alloc::sync::{Arc, Weak};
+use core::cell::UnsafeCell;
+use core::fmt::{self, Debug};
+use core::iter::FromIterator;
+use core::marker::{PhantomData, Unpin};
+use core::mem::{self, PinMut};
+use core::ptr;
+use core::sync::atomic::Ordering::SeqCst;
+use core::sync::atomic::{AtomicPtr, AtomicBool};
+use core::usize;
 use futures_core::future::Future;
-use futures_core::stream::Stream;
+use futures_core::stream::{Stream, FusedStream};
 use futures_core::task::{self as core_task, Poll};
-use std::cell::UnsafeCell;
-use std::fmt::{self, Debug};
-use std::iter::FromIterator;
-use std::marker::{PhantomData, Unpin};
-use std::mem::{self, PinMut};
-use std::ptr;
-use std::sync::atomic::Ordering::SeqCst;
-use std::sync::atomic::{AtomicPtr, AtomicBool};
-use std::sync::{Arc, Weak};
-use std::usize;
 
 mod abort;
 
@@ -48,8 +48,14 @@ pub struct FuturesUnordered<Fut> {
     ready_to_run_queue: Arc<ReadyToRunQueue<Fut>>,
     len: usize,
     head_all: *const Task<Fut>,
+    is_terminated: bool,
 }
 
+/// Identifies a future pushed into a [`FuturesUnordered`] with
+/// [`push_with_handle`](FuturesUnordered::push_with_handle), so that it can be
+/// removed again before it completes.
+pub(crate) struct TaskHandle<Fut>(*const Task<Fut>);
+
 unsafe impl<Fut: Send> Send for FuturesUnordered<Fut> {}
 unsafe impl<Fut: Sync> Sync for FuturesUnordered<Fut> {}
 impl<Fut> Unpin for FuturesUnordered<Fut> {}
@@ -104,6 +110,7 @@ impl<Fut: Future> FuturesUnordered<Fut> {
             len: 0,
             head_all: ptr::null_mut(),
             ready_to_run_queue,
+            is_terminated: false,
         }
     }
 }
@@ -134,6 +141,13 @@ impl<Fut> FuturesUnordered<Fut> {
     /// ensure that [`FuturesUnordered::poll_next`](Stream::poll_next) is called
     /// in order to receive wake-up notifications for the given future.
     pub fn push(&mut self, future: Fut) {
+        self.push_with_handle(future);
+    }
+
+    /// Push a future into the set like [`push`](FuturesUnordered::push), and
+    /// return a handle which can be passed to
+    /// [`remove`](FuturesUnordered::remove) to drop the future again.
+    pub(crate) fn push_with_handle(&mut self, future: Fut) -> TaskHandle<Fut> {
         let task = Arc::new(Task {
             future: UnsafeCell::new(Some(future)),
             next_all: UnsafeCell::new(ptr::null_mut()),
@@ -143,6 +157,9 @@ impl<Fut> FuturesUnordered<Fut> {
             ready_to_run_queue: Arc::downgrade(&self.ready_to_run_queue),
         });
 
+        // A new future means that the set yields items again
+        self.is_terminated = false;
+
         // Right now our task has a strong reference count of 1. We transfer
         // ownership of this reference count to our internal linked list
         // and we'll reclaim ownership through the `unlink` method below.
@@ -153,6 +170,20 @@ impl<Fut> FuturesUnordered<Fut> {
         // futures are ready. To do that we unconditionally enqueue it for
         // polling here.
         self.ready_to_run_queue.enqueue(ptr);
+
+        TaskHandle(ptr)
+    }
+
+    /// Removes a future from the set and drops it.
+    ///
+    /// If the future has been woken already, the task is left in the ready to
+    /// run queue without its future and is skipped when it's dequeued.
+    ///
+    /// This is unsafe because the handle has to belong to a future pushed
+    /// into this set which hasn't completed yet, otherwise it's dangling.
+    pub(crate) unsafe fn remove(&mut self, handle: TaskHandle<Fut>) {
+        let task = self.unlink(handle.0);
+        self.release_task(task);
     }
 
     /// Returns an iterator that allows modifying each future in the set.
@@ -248,6 +279,14 @@ impl<Fut> FuturesUnordered<Fut> {
     }
 }
 
+// The set is terminated once it returned `None`, until another future is
+// pushed into it.
+impl<Fut: Future> FusedStream for FuturesUnordered<Fut> {
+    fn is_terminated(&self) -> bool {
+        self.is_terminated
+    }
+}
+
 impl<Fut: Future> Stream for FuturesUnordered<Fut> {
     type Item = Fut::Output;
 
@@ -263,6 +302,7 @@ impl<Fut: Future> Stream for FuturesUnordered<Fut> {
             let task = match unsafe { self.ready_to_run_queue.dequeue() } {
                 Dequeue::Empty => {
                     if self.is_empty() {
+                        self.is_terminated = true;
                         return Poll::Ready(None);
                     } else {
                         return Poll::Pending;
@@ -1,6 +1,6 @@
 use core::marker::Unpin;
 use core::mem::PinMut;
-use futures_core::future::Future;
+use futures_core::future::{Future, FusedFuture};
 use futures_core::stream::Stream;
 use futures_core::task::{self, Poll};
 
@@ -58,6 +58,12 @@ impl<St: Stream + Unpin> StreamFuture<St> {
     }
 }
 
+impl<St: Stream + Unpin> FusedFuture for StreamFuture<St> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_none()
+    }
+}
+
 impl<St: Stream + Unpin> Future for StreamFuture<St> {
     type Output = (Option<St::Item>, St);
 
@@ -65,6 +65,10 @@ impl<St, F, T> Stream for Map<St, F>
         let option = ready!(self.stream().poll_next(cx));
         Poll::Ready(option.map(self.f()))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
 }
 
 /* TODO
@@ -0,0 +1,91 @@
+use core::marker::Unpin;
+use core::mem::PinMut;
+use futures_core::stream::Stream;
+use futures_core::task::{self, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// A stream combinator which maps elements of a stream while the closure
+/// returns `Some`.
+///
+/// This structure is produced by the `Stream::map_while` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct MapWhile<St, F> {
+    stream: Option<St>,
+    f: F,
+}
+
+impl<St: Unpin, F> Unpin for MapWhile<St, F> {}
+
+impl<St, T, F> MapWhile<St, F>
+    where St: Stream,
+          F: FnMut(St::Item) -> Option<T>,
+{
+    unsafe_pinned!(stream: Option<St>);
+    unsafe_unpinned!(f: F);
+
+    pub(super) fn new(stream: St, f: F) -> MapWhile<St, F> {
+        MapWhile { stream: Some(stream), f }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    ///
+    /// Returns `None` once the stream has ended or the closure has returned
+    /// `None`, and the stream has been dropped.
+    pub fn get_ref(&self) -> Option<&St> {
+        self.stream.as_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> Option<&mut St> {
+        self.stream.as_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> Option<St> {
+        self.stream
+    }
+}
+
+impl<St, F, T> Stream for MapWhile<St, F>
+    where St: Stream,
+          F: FnMut(St::Item) -> Option<T>,
+{
+    type Item = T;
+
+    fn poll_next(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context
+    ) -> Poll<Option<T>> {
+        let item = match self.stream().as_pin_mut() {
+            Some(stream) => ready!(stream.poll_next(cx)),
+            None => return Poll::Ready(None),
+        };
+
+        match item.and_then(|item| (self.f())(item)) {
+            Some(mapped) => Poll::Ready(Some(mapped)),
+            None => {
+                // Release the resources of the stream, it won't be polled
+                // again
+                PinMut::set(self.stream(), None);
+                Poll::Ready(None)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.stream {
+            // The closure can end the stream at any item
+            Some(stream) => (0, stream.size_hint().1),
+            None => (0, Some(0)),
+        }
+    }
+}
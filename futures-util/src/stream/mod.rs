@@ -3,11 +3,11 @@
 //! This module contains a number of functions for working with `Stream`s,
 //! including the `StreamExt` trait which adds methods to `Stream` types.
 
+use crate::future::Either;
 use core::marker::Unpin;
 use core::mem::PinMut;
-use either::Either;
 use futures_core::future::Future;
-use futures_core::stream::Stream;
+use futures_core::stream::{Stream, FusedStream};
 use futures_core::task::{self, Poll};
 use futures_sink::Sink;
 
@@ -32,6 +32,12 @@ pub use self::filter::Filter;
 mod filter_map;
 pub use self::filter_map::FilterMap;
 
+mod filter_map_sync;
+pub use self::filter_map_sync::FilterMapSync;
+
+mod filter_sync;
+pub use self::filter_sync::FilterSync;
+
 mod flatten;
 pub use self::flatten::Flatten;
 
@@ -41,6 +47,9 @@ pub use self::fold::Fold;
 mod forward;
 pub use self::forward::Forward;
 
+mod forward_into;
+pub use self::forward_into::ForwardInto;
+
 mod for_each;
 pub use self::for_each::ForEach;
 
@@ -56,6 +65,9 @@ pub use self::inspect::Inspect;
 mod map;
 pub use self::map::Map;
 
+mod map_while;
+pub use self::map_while::MapWhile;
+
 mod next;
 pub use self::next::Next;
 
@@ -71,6 +83,9 @@ pub use self::poll_fn::{poll_fn, PollFn};
 mod select;
 pub use self::select::Select;
 
+mod select_next_some;
+pub use self::select_next_some::SelectNextSome;
+
 mod skip;
 pub use self::skip::Skip;
 
@@ -92,10 +107,8 @@ pub use self::unfold::{unfold, Unfold};
 mod zip;
 pub use self::zip::Zip;
 
-if_std! {
-    use std;
-    use std::boxed::PinBox;
-    use std::iter::Extend;
+if_alloc! {
+    use alloc::boxed::PinBox;
 
     mod buffer_unordered;
     pub use self::buffer_unordered::BufferUnordered;
@@ -103,9 +116,6 @@ if_std! {
     mod buffered;
     pub use self::buffered::Buffered;
 
-    mod catch_unwind;
-    pub use self::catch_unwind::CatchUnwind;
-
     mod chunks;
     pub use self::chunks::Chunks;
 
@@ -129,6 +139,14 @@ if_std! {
     // pub use self::select_all::{select_all, SelectAll};
 }
 
+if_std! {
+    mod catch_unwind;
+    pub use self::catch_unwind::CatchUnwind;
+
+    mod futures_map;
+    pub use self::futures_map::FuturesMap;
+}
+
 impl<T: ?Sized> StreamExt for T where T: Stream {}
 
 /// An extension trait for `Stream`s that provides a variety of convenient
@@ -140,7 +158,9 @@ pub trait StreamExt: Stream {
     /// the [`Stream`] type must be [`Unpin`]. If you want to use `next` with a
     /// [`!Unpin`](Unpin) stream, you'll first have to pin the stream. This can
     /// be done by wrapping the stream in a [`PinBox`](std::boxed::PinBox) or
-    /// pinning it to the stack using the `pin_mut!` macro.
+    /// pinning it to the stack using the `pin_mut!` macro, which is
+    /// re-exported by the `futures` crate. Both a `PinBox` and the resulting
+    /// [`PinMut`] are streams themselves, and are `Unpin`.
     ///
     /// # Examples
     ///
@@ -156,6 +176,35 @@ pub trait StreamExt: Stream {
     /// assert_eq!(block_on(stream.next()), Some(3));
     /// assert_eq!(block_on(stream.next()), None);
     /// ```
+    ///
+    /// Iterating a `!Unpin` stream after pinning it to the stack:
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api, pin)]
+    /// use futures::executor::block_on;
+    /// use futures::pin_mut;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// // The futures of `async` blocks aren't `Unpin`, so neither is this
+    /// // stream.
+    /// let stream = stream::unfold(0, |state| async move {
+    ///     if state < 3 {
+    ///         Some((state, state + 1))
+    ///     } else {
+    ///         None
+    ///     }
+    /// });
+    /// pin_mut!(stream);
+    ///
+    /// let sum = block_on(async {
+    ///     let mut sum = 0;
+    ///     while let Some(item) = await!(stream.next()) {
+    ///         sum += item;
+    ///     }
+    ///     sum
+    /// });
+    /// assert_eq!(sum, 3);
+    /// ```
     fn next(&mut self) -> Next<'_, Self>
         where Self: Sized + Unpin,
     {
@@ -172,7 +221,8 @@ pub trait StreamExt: Stream {
     /// must be [`Unpin`]. If you want to use `into_future` with a
     /// [`!Unpin`](Unpin) stream, you'll first have to pin the stream. This can
     /// be done by wrapping the stream in a [`PinBox`](std::boxed::PinBox) or
-    /// pinning it to the stack using the `pin_mut!` macro.
+    /// pinning it to the stack using the `pin_mut!` macro, which is
+    /// re-exported by the `futures` crate.
     ///
     /// # Examples
     ///
@@ -237,6 +287,10 @@ pub trait StreamExt: Stream {
     /// wrapped version of it, similar to the existing `filter` methods in the
     /// standard library.
     ///
+    /// If the predicate doesn't need to wait for anything, prefer
+    /// [`filter_sync`](StreamExt::filter_sync), which takes a plain closure
+    /// returning `bool`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -269,6 +323,10 @@ pub trait StreamExt: Stream {
     /// wrapped version of it, similar to the existing `filter_map` methods in
     /// the standard library.
     ///
+    /// If the closure doesn't need to wait for anything, prefer
+    /// [`filter_map_sync`](StreamExt::filter_map_sync), which takes a plain
+    /// closure returning an `Option`.
+    ///
     /// # Examples
     /// ```
     /// use futures::executor::block_on;
@@ -291,6 +349,60 @@ pub trait StreamExt: Stream {
         FilterMap::new(self, f)
     }
 
+    /// Filters the values produced by this stream according to the provided
+    /// synchronous predicate.
+    ///
+    /// This is the synchronous version of [`filter`](StreamExt::filter): the
+    /// stream yields the values for which the predicate `f` returns `true`
+    /// and discards the others, like `Iterator::filter`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=10);
+    /// let evens = stream.filter_sync(|x| x % 2 == 0);
+    ///
+    /// assert_eq!(vec![2, 4, 6, 8, 10], block_on(evens.collect::<Vec<_>>()));
+    /// ```
+    fn filter_sync<F>(self, f: F) -> FilterSync<Self, F>
+        where F: FnMut(&Self::Item) -> bool,
+              Self: Sized,
+    {
+        FilterSync::new(self, f)
+    }
+
+    /// Filters the values produced by this stream while simultaneously mapping
+    /// them to a different type according to the provided synchronous
+    /// closure.
+    ///
+    /// This is the synchronous version of
+    /// [`filter_map`](StreamExt::filter_map): the stream yields `item` when
+    /// `f` returns [`Some(item)`](Some) and skips the value when it returns
+    /// [`None`], like `Iterator::filter_map`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=10);
+    /// let evens = stream.filter_map_sync(|x| {
+    ///     if x % 2 == 0 { Some(x + 1) } else { None }
+    /// });
+    ///
+    /// assert_eq!(vec![3, 5, 7, 9, 11], block_on(evens.collect::<Vec<_>>()));
+    /// ```
+    fn filter_map_sync<T, F>(self, f: F) -> FilterMapSync<Self, F>
+        where F: FnMut(Self::Item) -> Option<T>,
+              Self: Sized,
+    {
+        FilterMapSync::new(self, f)
+    }
+
     /// Computes from this stream's items new items of a different type using
     /// an asynchronous closure.
     ///
@@ -326,7 +438,7 @@ pub trait StreamExt: Stream {
     ///
     /// The returned future will be resolved when the stream terminates.
     ///
-    /// This method is only available when the `std` feature of this
+    /// This method is only available when the `alloc` feature of this
     /// library is activated, and it is activated by default.
     ///
     /// # Examples
@@ -348,7 +460,7 @@ pub trait StreamExt: Stream {
     /// let output = block_on(rx.collect::<Vec<i32>>());
     /// assert_eq!(output, vec![1, 2, 3, 4, 5]);
     /// ```
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     fn collect<C: Default + Extend<Self::Item>>(self) -> Collect<Self, C>
         where Self: Sized
     {
@@ -516,6 +628,33 @@ pub trait StreamExt: Stream {
         TakeWhile::new(self, f)
     }
 
+    /// Maps elements of this stream with the provided closure while it
+    /// returns `Some`.
+    ///
+    /// This function, like `Iterator::map_while`, yields the values the
+    /// closure `f` returns in `Some`. The first time it returns `None` the
+    /// stream is done: the underlying stream is dropped and won't be polled
+    /// again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec!["1", "2", "x", "4"]);
+    ///
+    /// let stream = stream.map_while(|s| s.parse::<i32>().ok());
+    ///
+    /// assert_eq!(vec![1, 2], block_on(stream.collect::<Vec<_>>()));
+    /// ```
+    fn map_while<T, F>(self, f: F) -> MapWhile<Self, F>
+        where F: FnMut(Self::Item) -> Option<T>,
+              Self: Sized
+    {
+        MapWhile::new(self, f)
+    }
+
     /// Runs this stream to completion, executing the provided asynchronous
     /// closure for each element on the stream.
     ///
@@ -575,7 +714,7 @@ pub trait StreamExt: Stream {
     /// `Some(10)`, or just `10`. Note: a limit of zero is interpreted as
     /// no limit at all, and will have the same result as passing in `None`.
     ///
-    /// This method is only available when the `std` feature of this
+    /// This method is only available when the `alloc` feature of this
     /// library is activated, and it is activated by default.
     ///
     /// # Examples
@@ -602,7 +741,7 @@ pub trait StreamExt: Stream {
     /// await!(fut);
     /// # })
     /// ```
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     fn for_each_concurrent<Fut, F>(
         self,
         limit: impl Into<Option<usize>>,
@@ -772,13 +911,13 @@ pub trait StreamExt: Stream {
     /// ```
     #[cfg(feature = "std")]
     fn catch_unwind(self) -> CatchUnwind<Self>
-        where Self: Sized + std::panic::UnwindSafe
+        where Self: Sized + ::std::panic::UnwindSafe
     {
         CatchUnwind::new(self)
     }
 
     /// Wrap the stream in a Box, pinning it.
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     fn boxed(self) -> PinBox<Self>
         where Self: Sized
     {
@@ -795,9 +934,9 @@ pub trait StreamExt: Stream {
     ///
     /// The returned stream will be a stream of each future's output.
     ///
-    /// This method is only available when the `std` feature of this
+    /// This method is only available when the `alloc` feature of this
     /// library is activated, and it is activated by default.
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     fn buffered(self, n: usize) -> Buffered<Self>
         where Self::Item: Future,
               Self: Sized
@@ -815,7 +954,7 @@ pub trait StreamExt: Stream {
     ///
     /// The returned stream will be a stream of each future's output.
     ///
-    /// This method is only available when the `std` feature of this
+    /// This method is only available when the `alloc` feature of this
     /// library is activated, and it is activated by default.
     ///
     /// # Examples
@@ -841,7 +980,7 @@ pub trait StreamExt: Stream {
     /// assert_eq!(await!(buffered.next()), None);
     /// # })
     /// ```
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     fn buffer_unordered(self, n: usize) -> BufferUnordered<Self>
         where Self::Item: Future,
               Self: Sized
@@ -926,13 +1065,13 @@ pub trait StreamExt: Stream {
     /// from the underlying stream then the currently buffered items will be
     /// yielded.
     ///
-    /// This method is only available when the `std` feature of this
+    /// This method is only available when the `alloc` feature of this
     /// library is activated, and it is activated by default.
     ///
     /// # Panics
     ///
     /// This method will panic of `capacity` is zero.
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     fn chunks(self, capacity: usize) -> Chunks<Self>
         where Self: Sized
     {
@@ -964,7 +1103,9 @@ pub trait StreamExt: Stream {
     /// stream is exhausted and the sink has received and flushed all items.
     /// Note that the sink is **not** closed.
     ///
-    /// On completion, the sink is returned.
+    /// On completion, the sink is returned. If the sink fails, the item it
+    /// didn't accept, the stream and the sink can be recovered from the
+    /// returned future with [`Forward::into_parts`].
     ///
     /// Note that this combinator is only usable with `Unpin` sinks.
     /// Sinks that are not `Unpin` will need to be pinned in order to be used
@@ -977,6 +1118,75 @@ pub trait StreamExt: Stream {
         Forward::new(self, sink)
     }
 
+    /// Like [`forward`](StreamExt::forward), but the errors of the stream only
+    /// need to convert into the error type of the sink, rather than being
+    /// exactly that type.
+    ///
+    /// Stream errors are converted with `Into` as they are yielded, and the
+    /// returned future fails with the error type of the sink.
+    ///
+    /// `forward` is usually preferable when the error types already match, as
+    /// with it the error type of the stream can be inferred from the sink.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(futures_api)]
+    /// use futures::channel::mpsc;
+    /// use futures::executor::block_on;
+    /// use futures::never::Never;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let (tx, rx) = mpsc::unbounded();
+    /// // The stream can't fail, while the sink fails with `SendError`.
+    /// let stream = stream::iter(vec![Ok::<i32, Never>(1), Ok(2)]);
+    /// block_on(stream.forward_err_into(tx)).unwrap();
+    ///
+    /// assert_eq!(block_on(rx.collect::<Vec<_>>()), vec![1, 2]);
+    /// ```
+    fn forward_err_into<S, E>(self, sink: S) -> Forward<Self, S>
+    where
+        S: Sink + Unpin,
+        Self: Stream<Item = Result<S::SinkItem, E>> + Sized,
+        E: Into<S::SinkError>,
+    {
+        Forward::new(self, sink)
+    }
+
+    /// A future that completes after the given stream has been fully processed
+    /// into the sink, including flushing and closing it.
+    ///
+    /// This is like [`forward`](StreamExt::forward), but the sink is not
+    /// returned on completion. In exchange, it is kept pinned inside of the
+    /// returned future and doesn't have to be `Unpin`.
+    ///
+    /// Like with [`forward_err_into`](StreamExt::forward_err_into), the
+    /// errors of the stream only need to convert into the error type of the
+    /// sink.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(futures_api)]
+    /// use futures::executor::block_on;
+    /// use futures::never::Never;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut sink = Vec::new();
+    /// let stream = stream::iter(vec![Ok::<i32, Never>(1), Ok(2), Ok(3)]);
+    /// block_on(stream.forward_into(&mut sink)).unwrap();
+    ///
+    /// assert_eq!(sink, vec![1, 2, 3]);
+    /// ```
+    fn forward_into<S, E>(self, sink: S) -> ForwardInto<Self, S>
+    where
+        S: Sink,
+        Self: Stream<Item = Result<S::SinkItem, E>> + Sized,
+        E: Into<S::SinkError>,
+    {
+        ForwardInto::new(self, sink)
+    }
+
     /// Splits this `Stream + Sink` object into separate `Stream` and `Sink`
     /// objects.
     ///
@@ -984,9 +1194,9 @@ pub trait StreamExt: Stream {
     /// allow direct interaction between the two objects (e.g. via
     /// `Sink::send_all`).
     ///
-    /// This method is only available when the `std` feature of this
+    /// This method is only available when the `alloc` feature of this
     /// library is activated, and it is activated by default.
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     fn split(self) -> (SplitSink<Self>, SplitStream<Self>)
         where Self: Sink + Sized
     {
@@ -1039,4 +1249,47 @@ pub trait StreamExt: Stream {
     {
         PinMut::new(self).poll_next(cx)
     }
+
+    /// Returns a [`Future`] that resolves when the next item in this stream is
+    /// ready.
+    ///
+    /// This is similar to the [`next`][StreamExt::next] method, but it won't
+    /// resolve to [`None`] if used on an empty [`Stream`]. Instead, the
+    /// returned future type will return `true` from
+    /// [`FusedFuture::is_terminated`][] when the [`Stream`] is empty, allowing
+    /// [`select_next_some`][StreamExt::select_next_some] to be easily used with
+    /// the `select!` macro.
+    ///
+    /// If the future is polled after this [`Stream`] is empty it will panic.
+    /// Using the future with a [`FusedFuture`][]-aware primitive like the
+    /// `select!` macro will prevent this.
+    ///
+    /// [`FusedFuture`]: futures_core::future::FusedFuture
+    /// [`FusedFuture::is_terminated`]: futures_core::future::FusedFuture::is_terminated
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// # futures::executor::block_on(async {
+    /// use futures::{select, stream};
+    /// use futures::stream::StreamExt;
+    ///
+    /// let mut numbers = stream::iter(1..=3).fuse();
+    /// let mut total = 0;
+    ///
+    /// loop {
+    ///     select! {
+    ///         num = numbers.select_next_some() => total += num,
+    ///         complete => break,
+    ///     }
+    /// }
+    /// assert_eq!(total, 6);
+    /// # });
+    /// ```
+    fn select_next_some(&mut self) -> SelectNextSome<'_, Self>
+        where Self: Sized + Unpin + FusedStream,
+    {
+        SelectNextSome::new(self)
+    }
 }
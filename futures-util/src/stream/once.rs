@@ -1,7 +1,7 @@
 use core::marker::Unpin;
 use core::mem::PinMut;
 use futures_core::future::Future;
-use futures_core::stream::Stream;
+use futures_core::stream::{Stream, FusedStream};
 use futures_core::task::{self, Poll};
 use pin_utils::unsafe_pinned;
 
@@ -35,6 +35,12 @@ impl<Fut> Once<Fut> {
     unsafe_pinned!(future: Option<Fut>);
 }
 
+impl<Fut: Future> FusedStream for Once<Fut> {
+    fn is_terminated(&self) -> bool {
+        self.future.is_none()
+    }
+}
+
 impl<Fut: Future> Stream for Once<Fut> {
     type Item = Fut::Output;
 
@@ -50,4 +56,12 @@ impl<Fut: Future> Stream for Once<Fut> {
         PinMut::set(self.future(), None);
         Poll::Ready(Some(val))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.future.is_some() {
+            (1, Some(1))
+        } else {
+            (0, Some(0))
+        }
+    }
 }
@@ -0,0 +1,50 @@
+use core::marker::Unpin;
+use core::mem::PinMut;
+use futures_core::future::{Future, FusedFuture};
+use futures_core::stream::{Stream, FusedStream};
+use futures_core::task::{self, Poll};
+
+/// A future that resolves to the next item of a stream, skipping the end of
+/// the stream.
+///
+/// This is created by the `StreamExt::select_next_some` method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct SelectNextSome<'a, St: 'a> {
+    stream: &'a mut St,
+}
+
+impl<St: Stream + Unpin> Unpin for SelectNextSome<'_, St> {}
+
+impl<'a, St: Stream + Unpin + FusedStream> SelectNextSome<'a, St> {
+    pub(super) fn new(stream: &'a mut St) -> Self {
+        SelectNextSome { stream }
+    }
+}
+
+impl<St: FusedStream> FusedFuture for SelectNextSome<'_, St> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St: Stream + Unpin + FusedStream> Future for SelectNextSome<'_, St> {
+    type Output = St::Item;
+
+    fn poll(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Self::Output> {
+        assert!(!self.stream.is_terminated(), "SelectNextSome polled after terminated");
+
+        match ready!(PinMut::new(&mut *self.stream).poll_next(cx)) {
+            Some(item) => Poll::Ready(item),
+            None => {
+                // The stream is terminated now, so whoever is selecting on it
+                // has to come back and notice that.
+                cx.waker().wake();
+                Poll::Pending
+            }
+        }
+    }
+}
@@ -67,6 +67,16 @@ impl<St: Stream> Stream for Skip<St> {
 
         self.stream().poll_next(cx)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = if self.remaining > usize::max_value() as u64 {
+            usize::max_value()
+        } else {
+            self.remaining as usize
+        };
+        let (lower, upper) = self.stream.size_hint();
+        (lower.saturating_sub(remaining), upper.map(|x| x.saturating_sub(remaining)))
+    }
 }
 
 /* TODO
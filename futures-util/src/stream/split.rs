@@ -1,11 +1,10 @@
+use core::any::Any;
+use core::fmt;
+use core::marker::Unpin;
+use core::mem::PinMut;
 use futures_core::stream::Stream;
 use futures_core::task::{self, Poll};
 use futures_sink::Sink;
-use std::any::Any;
-use std::error::Error;
-use std::fmt;
-use std::marker::Unpin;
-use std::mem::PinMut;
 
 use crate::lock::BiLock;
 
@@ -140,7 +139,8 @@ impl<T: Sink> fmt::Display for ReuniteError<T> {
     }
 }
 
-impl<T: Any + Sink> Error for ReuniteError<T> {
+#[cfg(feature = "std")]
+impl<T: Any + Sink> ::std::error::Error for ReuniteError<T> {
     fn description(&self) -> &str {
         "tried to reunite a SplitStream and SplitSink that don't form a pair"
     }
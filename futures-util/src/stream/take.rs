@@ -1,3 +1,4 @@
+use core::cmp;
 use core::marker::Unpin;
 use core::mem::PinMut;
 use futures_core::stream::Stream;
@@ -71,6 +72,24 @@ impl<St> Stream for Take<St>
             Poll::Ready(next)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.remaining == 0 {
+            return (0, Some(0));
+        }
+
+        let (lower, upper) = self.stream.size_hint();
+        let remaining = if self.remaining > usize::max_value() as u64 {
+            usize::max_value()
+        } else {
+            self.remaining as usize
+        };
+        let upper = match upper {
+            Some(upper) => cmp::min(upper, remaining),
+            None => remaining,
+        };
+        (cmp::min(lower, remaining), Some(upper))
+    }
 }
 
 /* TODO
@@ -0,0 +1,89 @@
+#![allow(cast_ptr_alignment)] // clippy is too strict here
+
+use alloc::sync::Arc;
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::ptr::NonNull;
+use core::task::{Waker, UnsafeWake};
+
+/// A way of waking up a specific task.
+///
+/// By implementing this trait, types that are expected to be wrapped in an
+/// `Arc` can be converted into [`Waker`](::std::task::Waker) objects with
+/// [`waker`] or borrowed as one with [`waker_ref`](super::waker_ref), without
+/// having to write any unsafe code.
+///
+/// Those wakers can be used to signal executors that a task it owns is ready
+/// to be `poll`ed again.
+pub trait ArcWake: Send + Sync {
+    /// Indicates that the associated task is ready to make progress and should
+    /// be `poll`ed.
+    ///
+    /// This function can be called from an arbitrary thread, including threads
+    /// which did not create the `ArcWake` based [`Waker`](::std::task::Waker).
+    ///
+    /// Executors generally maintain a queue of "ready" tasks; `wake` should
+    /// place the associated task onto this queue.
+    fn wake(arc_self: &Arc<Self>);
+}
+
+/// Creates a [`Waker`](::std::task::Waker) from an `Arc<impl ArcWake>`.
+///
+/// The returned waker holds a reference count of `wake`, which is released
+/// when the waker is dropped. Cloning the waker bumps the reference count.
+pub fn waker<W>(wake: Arc<W>) -> Waker
+where
+    W: ArcWake + 'static,
+{
+    let ptr = Arc::into_raw(wake)
+        as *const ArcWaker<W>
+        as *const dyn UnsafeWake
+        as *mut dyn UnsafeWake;
+    unsafe { Waker::new(NonNull::new_unchecked(ptr)) }
+}
+
+// Pointers to this type below are really pointers to the `W` inside of an
+// `Arc<W>`, and every `Waker` that uses this type owns a reference count.
+struct ArcWaker<W> {
+    _marker: PhantomData<W>,
+}
+
+unsafe impl<W: ArcWake + 'static> UnsafeWake for ArcWaker<W> {
+    #[inline]
+    unsafe fn clone_raw(&self) -> Waker {
+        let me = self as *const ArcWaker<W> as *const W;
+        clone_arc_raw(me)
+    }
+
+    #[inline]
+    unsafe fn drop_raw(&self) {
+        let me = self as *const ArcWaker<W> as *const W;
+        drop(Arc::from_raw(me));
+    }
+
+    #[inline]
+    unsafe fn wake(&self) {
+        let me = self as *const ArcWaker<W> as *const W;
+        wake_arc_raw(me)
+    }
+
+    #[inline]
+    unsafe fn wake_local(&self) {
+        let me = self as *const ArcWaker<W> as *const W;
+        wake_arc_raw(me)
+    }
+}
+
+// Creates a new owning `Waker` for the `Arc<W>` that `data` points into,
+// leaving the reference count of the caller untouched.
+crate unsafe fn clone_arc_raw<W: ArcWake + 'static>(data: *const W) -> Waker {
+    let arc = ManuallyDrop::new(Arc::from_raw(data));
+    waker(Arc::clone(&arc))
+}
+
+// Wakes the `Arc<W>` that `data` points into without consuming a reference
+// count.
+crate unsafe fn wake_arc_raw<W: ArcWake + 'static>(data: *const W) {
+    let arc = ManuallyDrop::new(Arc::from_raw(data));
+    W::wake(&arc)
+}
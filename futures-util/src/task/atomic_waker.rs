@@ -24,6 +24,67 @@ use futures_core::task::Waker;
 /// `AtomicWaker` does not provide any memory ordering guarantees, as such the
 /// user should use caution and use other synchronization primitives to guard
 /// the result of the underlying computation.
+///
+/// # Examples
+///
+/// A stream of values delivered by a callback that runs on another thread:
+///
+/// ```
+/// #![feature(pin, arbitrary_self_types, futures_api)]
+/// use futures::executor::block_on;
+/// use futures::stream::{Stream, StreamExt};
+/// use futures::task::{self, Poll, AtomicWaker};
+/// use std::collections::VecDeque;
+/// use std::mem::PinMut;
+/// use std::sync::{Arc, Mutex};
+/// use std::thread;
+///
+/// struct Shared {
+///     waker: AtomicWaker,
+///     items: Mutex<VecDeque<Option<u32>>>,
+/// }
+///
+/// struct Values(Arc<Shared>);
+///
+/// impl Stream for Values {
+///     type Item = u32;
+///
+///     fn poll_next(self: PinMut<Self>, cx: &mut task::Context) -> Poll<Option<u32>> {
+///         // Register **before** checking for items, an item delivered in
+///         // between would otherwise be missed.
+///         self.0.waker.register(cx.waker());
+///
+///         match self.0.items.lock().unwrap().pop_front() {
+///             Some(item) => Poll::Ready(item),
+///             None => Poll::Pending,
+///         }
+///     }
+/// }
+///
+/// // Calls `callback` with each value and then with `None`.
+/// fn on_values<F: Fn(Option<u32>) + Send + 'static>(callback: F) {
+///     thread::spawn(move || {
+///         for i in 0..3 {
+///             callback(Some(i));
+///         }
+///         callback(None);
+///     });
+/// }
+///
+/// let shared = Arc::new(Shared {
+///     waker: AtomicWaker::new(),
+///     items: Mutex::new(VecDeque::new()),
+/// });
+///
+/// let producer = shared.clone();
+/// on_values(move |item| {
+///     producer.items.lock().unwrap().push_back(item);
+///     producer.waker.wake();
+/// });
+///
+/// let values: Vec<u32> = block_on(Values(shared).collect());
+/// assert_eq!(values, vec![0, 1, 2]);
+/// ```
 pub struct AtomicWaker {
     state: AtomicUsize,
     waker: UnsafeCell<Option<Waker>>,
@@ -86,7 +147,7 @@ pub struct AtomicWaker {
 // back to `WAITING`. This transition must succeed as, at this point, the state
 // cannot be transitioned by another thread.
 //
-// If the thread is unable to obtain the lock, the `WAKING` bit is still.  This
+// If the thread is unable to obtain the lock, the `WAKING` bit is still set. This
 // is because it has either been set by the current thread but the previous
 // value included the `REGISTERING` bit **or** a concurrent thread is in the
 // `WAKING` critical section. Either way, no action must be taken.
@@ -210,7 +271,7 @@ impl AtomicWaker {
                     // wake it.`
                     //
                     // Start by assuming that the state is `REGISTERING` as this
-                    // is what we jut set it to.
+                    // is what we just set it to.
                     let mut curr = REGISTERING;
 
                     // If a task has to be woken, the waker will be set here.
@@ -3,8 +3,31 @@
 mod spawn;
 pub use self::spawn::{SpawnExt, SpawnError};
 
+mod no_spawner;
+pub use self::no_spawner::{no_spawner_mut, NoSpawner};
+
+mod noop_waker;
+pub use self::noop_waker::{
+    noop_waker, noop_local_waker, noop_waker_ref, noop_local_waker_ref,
+    noop_waker_context,
+};
+
+mod panic_waker;
+pub use self::panic_waker::{
+    panic_waker, panic_local_waker, panic_waker_ref, panic_local_waker_ref,
+    panic_waker_context,
+};
+
 if_std! {
     pub use self::spawn::JoinHandle;
+}
+
+if_alloc! {
+    mod arc_wake;
+    pub use self::arc_wake::{ArcWake, waker};
+
+    mod waker_ref;
+    pub use self::waker_ref::{waker_ref, WakerRef};
 
     mod local_waker_ref;
     pub use self::local_waker_ref::{local_waker_ref, local_waker_ref_from_nonlocal, LocalWakerRef};
@@ -0,0 +1,58 @@
+use core::ptr::NonNull;
+use futures_core::future::FutureObj;
+use futures_core::task::{Spawn, SpawnErrorKind, SpawnObjError};
+
+/// A spawner for contexts that have no executor to spawn tasks onto.
+///
+/// Every spawn fails with a
+/// [`SpawnErrorKind::shutdown`](futures_core::task::SpawnErrorKind::shutdown)
+/// error that hands the future back to the caller, and
+/// [`status`](futures_core::task::Spawn::status) reports the same error, so
+/// code that spawns from within `poll` can handle the missing executor
+/// instead of panicking.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(futures_api)]
+/// use futures::future;
+/// use futures::task::{self, noop_local_waker_ref, no_spawner_mut, SpawnExt};
+///
+/// let mut cx = task::Context::new(noop_local_waker_ref(), no_spawner_mut());
+///
+/// let err = cx.spawner().spawn(future::ready(())).unwrap_err();
+/// assert!(err.kind.is_shutdown());
+/// ```
+#[derive(Debug, Default)]
+pub struct NoSpawner {
+    _reserved: (),
+}
+
+impl NoSpawner {
+    /// Create a new instance
+    pub fn new() -> Self {
+        Self { _reserved: () }
+    }
+}
+
+impl Spawn for NoSpawner {
+    fn spawn_obj(
+        &mut self,
+        future: FutureObj<'static, ()>,
+    ) -> Result<(), SpawnObjError> {
+        Err(SpawnObjError { future, kind: SpawnErrorKind::shutdown() })
+    }
+
+    fn status(&self) -> Result<(), SpawnErrorKind> {
+        Err(SpawnErrorKind::shutdown())
+    }
+}
+
+/// Get a reference to a singleton instance of [`NoSpawner`].
+///
+/// This doesn't allocate, so it is also available without the `std` feature.
+pub fn no_spawner_mut() -> &'static mut NoSpawner {
+    // `NoSpawner` is zero-sized, so any well-aligned pointer is a valid
+    // reference that doesn't alias anything.
+    unsafe { &mut *NonNull::dangling().as_ptr() }
+}
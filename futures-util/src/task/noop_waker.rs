@@ -0,0 +1,114 @@
+use core::ptr::NonNull;
+use futures_core::task::{Context, LocalWaker, UnsafeWake, Waker};
+use crate::task::no_spawner_mut;
+
+// A waker that doesn't do anything when woken, pointers to the single
+// instance below are used by every noop waker.
+struct NoopWake {
+    _reserved: (),
+}
+
+unsafe impl UnsafeWake for NoopWake {
+    unsafe fn clone_raw(&self) -> Waker {
+        noop_waker()
+    }
+
+    unsafe fn drop_raw(&self) {}
+
+    unsafe fn wake(&self) {}
+}
+
+// `Waker` and `LocalWaker` consist of nothing but a pointer to their
+// `UnsafeWake`, so a reference to this static can stand in for either one.
+static NOOP_WAKE: &dyn UnsafeWake = &NoopWake { _reserved: () };
+
+fn noop_unsafe_wake() -> NonNull<dyn UnsafeWake> {
+    NonNull::from(NOOP_WAKE)
+}
+
+/// Create a new [`Waker`](futures_core::task::Waker) which does nothing when
+/// `wake()` is called on it.
+///
+/// The waker doesn't allocate, so this is also available without the `std`
+/// feature.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(futures_api)]
+/// use futures::task::noop_waker;
+///
+/// let waker = noop_waker();
+/// waker.wake();
+/// ```
+#[inline]
+pub fn noop_waker() -> Waker {
+    unsafe { Waker::new(noop_unsafe_wake()) }
+}
+
+/// Create a new [`LocalWaker`](futures_core::task::LocalWaker) which does
+/// nothing when `wake()` is called on it.
+///
+/// The waker doesn't allocate, so this is also available without the `std`
+/// feature.
+#[inline]
+pub fn noop_local_waker() -> LocalWaker {
+    unsafe { LocalWaker::new(noop_unsafe_wake()) }
+}
+
+/// Get a static reference to a [`Waker`](futures_core::task::Waker) which
+/// does nothing when `wake()` is called on it.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(futures_api)]
+/// use futures::task::noop_waker_ref;
+///
+/// let waker = noop_waker_ref();
+/// waker.wake();
+/// ```
+#[inline]
+pub fn noop_waker_ref() -> &'static Waker {
+    unsafe { &*(&NOOP_WAKE as *const &dyn UnsafeWake as *const Waker) }
+}
+
+/// Get a static reference to a [`LocalWaker`](futures_core::task::LocalWaker)
+/// which does nothing when `wake()` is called on it.
+///
+/// This can be used to manually poll a future without an executor:
+///
+/// ```
+/// #![feature(pin, futures_api)]
+/// use futures::future::{self, Future};
+/// use futures::task::{self, noop_local_waker_ref, no_spawner_mut};
+/// use pin_utils::pin_mut;
+///
+/// let mut cx = task::Context::new(noop_local_waker_ref(), no_spawner_mut());
+/// let future = future::empty::<()>();
+/// pin_mut!(future);
+/// assert!(future.poll(&mut cx).is_pending());
+/// ```
+#[inline]
+pub fn noop_local_waker_ref() -> &'static LocalWaker {
+    unsafe { &*(&NOOP_WAKE as *const &dyn UnsafeWake as *const LocalWaker) }
+}
+
+/// Create a new [`task::Context`](futures_core::task::Context) whose waker
+/// does nothing when woken and whose spawner is a [`NoSpawner`](crate::task::NoSpawner).
+///
+/// # Examples
+///
+/// ```
+/// #![feature(pin, futures_api)]
+/// use futures::future::{self, Future};
+/// use futures::task::{noop_waker_context, Poll};
+/// use pin_utils::pin_mut;
+///
+/// let future = future::ready(1);
+/// pin_mut!(future);
+/// assert_eq!(future.poll(&mut noop_waker_context()), Poll::Ready(1));
+/// ```
+pub fn noop_waker_context() -> Context<'static> {
+    Context::new(noop_local_waker_ref(), no_spawner_mut())
+}
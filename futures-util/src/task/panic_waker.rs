@@ -0,0 +1,105 @@
+use core::ptr::NonNull;
+use futures_core::task::{Context, LocalWaker, UnsafeWake, Waker};
+use crate::task::no_spawner_mut;
+
+// A waker that panics when woken, pointers to the single instance below are
+// used by every panic waker.
+struct PanicWake {
+    _reserved: (),
+}
+
+unsafe impl UnsafeWake for PanicWake {
+    unsafe fn clone_raw(&self) -> Waker {
+        panic_waker()
+    }
+
+    unsafe fn drop_raw(&self) {}
+
+    unsafe fn wake(&self) {
+        panic!("should not be woken")
+    }
+}
+
+// `Waker` and `LocalWaker` consist of nothing but a pointer to their
+// `UnsafeWake`, so a reference to this static can stand in for either one.
+static PANIC_WAKE: &dyn UnsafeWake = &PanicWake { _reserved: () };
+
+fn panic_unsafe_wake() -> NonNull<dyn UnsafeWake> {
+    NonNull::from(PANIC_WAKE)
+}
+
+/// Create a new [`Waker`](futures_core::task::Waker) which will panic when
+/// `wake()` is called on it.
+///
+/// This is useful for asserting that a code path never registers interest in
+/// being woken up. The waker doesn't allocate, so this is also available
+/// without the `std` feature.
+///
+/// # Examples
+///
+/// ```should_panic
+/// #![feature(futures_api)]
+/// use futures::task::panic_waker;
+///
+/// let waker = panic_waker();
+/// waker.wake(); // Will panic
+/// ```
+#[inline]
+pub fn panic_waker() -> Waker {
+    unsafe { Waker::new(panic_unsafe_wake()) }
+}
+
+/// Create a new [`LocalWaker`](futures_core::task::LocalWaker) which will
+/// panic when `wake()` is called on it.
+///
+/// The waker doesn't allocate, so this is also available without the `std`
+/// feature.
+#[inline]
+pub fn panic_local_waker() -> LocalWaker {
+    unsafe { LocalWaker::new(panic_unsafe_wake()) }
+}
+
+/// Get a static reference to a [`Waker`](futures_core::task::Waker) which
+/// will panic when `wake()` is called on it.
+#[inline]
+pub fn panic_waker_ref() -> &'static Waker {
+    unsafe { &*(&PANIC_WAKE as *const &dyn UnsafeWake as *const Waker) }
+}
+
+/// Get a static reference to a [`LocalWaker`](futures_core::task::LocalWaker)
+/// which will panic when `wake()` is called on it.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(pin, futures_api)]
+/// use futures::future::{self, Future};
+/// use futures::task::{self, panic_local_waker_ref, no_spawner_mut, Poll};
+/// use pin_utils::pin_mut;
+///
+/// let mut cx = task::Context::new(panic_local_waker_ref(), no_spawner_mut());
+/// // A ready future never needs to wake its task.
+/// let future = future::ready(1);
+/// pin_mut!(future);
+/// assert_eq!(future.poll(&mut cx), Poll::Ready(1));
+/// ```
+#[inline]
+pub fn panic_local_waker_ref() -> &'static LocalWaker {
+    unsafe { &*(&PANIC_WAKE as *const &dyn UnsafeWake as *const LocalWaker) }
+}
+
+/// Create a new [`task::Context`](futures_core::task::Context) whose waker
+/// panics when woken and whose spawner is a [`NoSpawner`](crate::task::NoSpawner).
+///
+/// # Examples
+///
+/// ```should_panic
+/// #![feature(futures_api)]
+/// use futures::task::panic_waker_context;
+///
+/// let cx = panic_waker_context();
+/// cx.waker().wake(); // Will panic
+/// ```
+pub fn panic_waker_context() -> Context<'static> {
+    Context::new(panic_local_waker_ref(), no_spawner_mut())
+}
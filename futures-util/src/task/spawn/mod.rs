@@ -3,9 +3,12 @@ use futures_core::task::Spawn;
 mod spawn_error;
 pub use self::spawn_error::SpawnError;
 
-if_std! {
+if_alloc! {
+    use alloc::boxed::Box;
     use futures_core::future::Future;
+}
 
+if_std! {
     mod spawn_with_handle;
     use self::spawn_with_handle::spawn_with_handle;
     pub use self::spawn_with_handle::JoinHandle;
@@ -42,7 +45,7 @@ pub trait SpawnExt: Spawn {
     /// executor.spawn(future).unwrap();
     /// # });
     /// ```
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     fn spawn<Fut>(&mut self, future: Fut) -> Result<(), SpawnError>
     where Fut: Future<Output = ()> + Send + 'static,
     {
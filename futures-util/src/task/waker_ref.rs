@@ -0,0 +1,95 @@
+#![allow(cast_ptr_alignment)] // clippy is too strict here
+
+use super::arc_wake::{ArcWake, clone_arc_raw, wake_arc_raw};
+use alloc::sync::Arc;
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::ptr::NonNull;
+use core::task::{LocalWaker, Waker, UnsafeWake};
+
+/// A [`Waker`](::std::task::Waker) that is only valid for a given lifetime.
+///
+/// Note: this type implements [`Deref<Target = Waker>`](::std::ops::Deref),
+/// so it can be used to get a `&Waker`.
+#[derive(Debug)]
+pub struct WakerRef<'a> {
+    waker: Waker,
+    local_waker: LocalWaker,
+    _marker: PhantomData<&'a ()>,
+}
+
+impl<'a> WakerRef<'a> {
+    /// Returns the borrowed waker as a [`LocalWaker`](::std::task::LocalWaker),
+    /// e.g. for creating a [`Context`](::std::task::Context).
+    ///
+    /// Waking the returned `LocalWaker` is the same as waking the `Waker`,
+    /// since the underlying [`ArcWake`] can be woken from any thread.
+    pub fn as_local_waker(&self) -> &LocalWaker {
+        &self.local_waker
+    }
+}
+
+impl<'a> Deref for WakerRef<'a> {
+    type Target = Waker;
+
+    fn deref(&self) -> &Waker {
+        &self.waker
+    }
+}
+
+// Pointers to this type below are really pointers to the `W` inside of an
+// `Arc<W>` that is borrowed, so they don't own a reference count.
+struct ReferencedArcWaker<W> {
+    _marker: PhantomData<W>,
+}
+
+unsafe impl<W: ArcWake + 'static> UnsafeWake for ReferencedArcWaker<W> {
+    #[inline]
+    unsafe fn clone_raw(&self) -> Waker {
+        let me = self as *const ReferencedArcWaker<W> as *const W;
+        clone_arc_raw(me)
+    }
+
+    #[inline]
+    unsafe fn drop_raw(&self) {}
+
+    #[inline]
+    unsafe fn wake(&self) {
+        let me = self as *const ReferencedArcWaker<W> as *const W;
+        wake_arc_raw(me)
+    }
+
+    #[inline]
+    unsafe fn wake_local(&self) {
+        let me = self as *const ReferencedArcWaker<W> as *const W;
+        wake_arc_raw(me)
+    }
+}
+
+/// Creates a reference to a [`Waker`](::std::task::Waker) from a reference
+/// to `Arc<impl ArcWake>`.
+///
+/// The resulting [`Waker`](::std::task::Waker) will call
+/// [`ArcWake::wake`](ArcWake::wake) if awoken. Unlike
+/// [`waker`](super::waker), this doesn't touch the reference count of `wake`,
+/// which makes it cheap enough to be called for every poll of a task. Only
+/// cloning the borrowed waker creates a new reference.
+#[inline]
+pub fn waker_ref<W>(wake: &Arc<W>) -> WakerRef<'_>
+where
+    W: ArcWake + 'static,
+{
+    let ptr = &**wake
+        as *const W
+        as *const ReferencedArcWaker<W>
+        as *const dyn UnsafeWake
+        as *mut dyn UnsafeWake;
+    unsafe {
+        let ptr = NonNull::new_unchecked(ptr);
+        WakerRef {
+            waker: Waker::new(ptr),
+            local_waker: LocalWaker::new(ptr),
+            _marker: PhantomData,
+        }
+    }
+}
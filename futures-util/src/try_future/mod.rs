@@ -4,11 +4,15 @@
 //! including the `FutureExt` trait which adds methods to `Future` types.
 
 use futures_core::future::TryFuture;
+use futures_core::never::Never;
 use futures_sink::Sink;
 
 #[cfg(feature = "compat")]
 use crate::compat::Compat;
 
+#[cfg(feature = "compat")]
+use crate::task::NoSpawner;
+
 #[cfg(feature = "compat")]
 use futures_core::task::Spawn;
 
@@ -56,6 +60,9 @@ pub use self::map_ok::MapOk;
 mod or_else;
 pub use self::or_else::OrElse;
 
+mod unwrap_infallible;
+pub use self::unwrap_infallible::UnwrapInfallible;
+
 mod unwrap_or_else;
 pub use self::unwrap_or_else::UnwrapOrElse;
 
@@ -480,8 +487,32 @@ pub trait TryFutureExt: TryFuture {
         UnwrapOrElse::new(self, f)
     }
 
+    /// Unwraps the output of a future which can't fail, producing a future
+    /// with this future's [`Ok`](TryFuture::Ok) type as its
+    /// [`Output`](std::future::Future::Output) type.
+    ///
+    /// This method is only available for futures whose error type is
+    /// [`Never`], so no error can be lost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro, futures_api)]
+    /// use futures::future::{self, FutureExt, TryFutureExt};
+    ///
+    /// # futures::executor::block_on(async {
+    /// let future = future::ready(1).never_error();
+    /// assert_eq!(await!(future.unwrap_infallible()), 1);
+    /// # });
+    /// ```
+    fn unwrap_infallible(self) -> UnwrapInfallible<Self>
+        where Self: TryFuture<Error = Never> + Sized,
+    {
+        UnwrapInfallible::new(self)
+    }
+
     /// Wraps a [`TryFuture`] into a future compatable with libraries using
-    /// futures 0.1 future definitons. Requires the `compat` feature to enable.
+    /// futures 0.1 future definitions. Requires the `compat` feature to enable.
     ///
     #[cfg(feature = "compat")]
     fn compat<Sp>(self, spawn: Sp) -> Compat<Self, Sp>
@@ -491,6 +522,41 @@ pub trait TryFutureExt: TryFuture {
         Compat::new(self, Some(spawn))
     }
 
+    /// Wraps a [`TryFuture`] that doesn't spawn any tasks into a future
+    /// compatible with libraries using futures 0.1 future definitions.
+    /// Requires the `compat` feature to enable.
+    ///
+    /// The resulting future works the same as the one returned by
+    /// [`compat`](TryFutureExt::compat), except that it is polled with the
+    /// [`NoSpawner`](crate::task::NoSpawner), so trying to spawn a task from
+    /// it fails with a shutdown error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(futures_api)]
+    /// use futures::channel::oneshot;
+    /// use futures::executor::block_on;
+    /// use futures::future::{FutureExt, TryFutureExt};
+    /// use futures_util::compat::Future01CompatExt;
+    ///
+    /// let (sender, receiver) = oneshot::channel::<i32>();
+    ///
+    /// let future01 = receiver
+    ///     .map(|result| result.map_err(drop))
+    ///     .compat_unspawned();
+    ///
+    /// sender.send(5).unwrap();
+    /// // Drive the futures 0.1 future by converting it back.
+    /// assert_eq!(block_on(future01.compat()), Ok(5));
+    /// ```
+    #[cfg(feature = "compat")]
+    fn compat_unspawned(self) -> Compat<Self, NoSpawner>
+        where Self: Sized + Unpin,
+    {
+        Compat::new(self, Some(NoSpawner::new()))
+    }
+
     /// Wraps a [`TryFuture`] into a type that implements
     /// [`Future`](std::future::Future).
     ///
@@ -0,0 +1,41 @@
+use core::marker::Unpin;
+use core::mem::PinMut;
+use futures_core::future::{Future, TryFuture};
+use futures_core::never::Never;
+use futures_core::task::{self, Poll};
+use pin_utils::unsafe_pinned;
+
+/// Future for the
+/// [`unwrap_infallible`](super::TryFutureExt::unwrap_infallible) combinator.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct UnwrapInfallible<Fut> {
+    future: Fut,
+}
+
+impl<Fut> UnwrapInfallible<Fut> {
+    unsafe_pinned!(future: Fut);
+
+    /// Creates a new UnwrapInfallible.
+    pub(super) fn new(future: Fut) -> UnwrapInfallible<Fut> {
+        UnwrapInfallible { future }
+    }
+}
+
+impl<Fut: Unpin> Unpin for UnwrapInfallible<Fut> {}
+
+impl<Fut> Future for UnwrapInfallible<Fut>
+    where Fut: TryFuture<Error = Never>,
+{
+    type Output = Fut::Ok;
+
+    fn poll(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Self::Output> {
+        self.future().try_poll(cx).map(|result| match result {
+            Ok(output) => output,
+            Err(never) => never.into_any(),
+        })
+    }
+}
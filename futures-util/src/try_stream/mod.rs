@@ -5,6 +5,7 @@
 
 use core::marker::Unpin;
 use futures_core::future::TryFuture;
+use futures_core::never::Never;
 use futures_core::stream::TryStream;
 
 #[cfg(feature = "compat")]
@@ -40,10 +41,16 @@ pub use self::try_fold::TryFold;
 mod try_skip_while;
 pub use self::try_skip_while::TrySkipWhile;
 
-if_std! {
+mod unwrap_infallible;
+pub use self::unwrap_infallible::UnwrapInfallible;
+
+if_alloc! {
     mod try_buffer_unordered;
     pub use self::try_buffer_unordered::TryBufferUnordered;
 
+    mod try_buffered;
+    pub use self::try_buffered::TryBuffered;
+
     mod try_collect;
     pub use self::try_collect::TryCollect;
 
@@ -161,6 +168,31 @@ pub trait TryStreamExt: TryStream {
         IntoStream::new(self)
     }
 
+    /// Unwraps the items of a stream which can't fail, producing a stream of
+    /// its [`Ok`](TryStream::Ok) values.
+    ///
+    /// This method is only available for streams whose error type is
+    /// [`Never`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro)]
+    /// # futures::executor::block_on(async {
+    /// use futures::never::Never;
+    /// use futures::stream::{self, StreamExt, TryStreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, Never>(1), Ok(2)]);
+    /// let items = await!(stream.unwrap_infallible().collect::<Vec<_>>());
+    /// assert_eq!(items, vec![1, 2]);
+    /// # })
+    /// ```
+    fn unwrap_infallible(self) -> UnwrapInfallible<Self>
+        where Self: TryStream<Error = Never> + Sized,
+    {
+        UnwrapInfallible::new(self)
+    }
+
     /// Creates a future that attempts to resolve the next item in the stream.
     /// If an error is encountered before the next item, the error is returned
     /// instead.
@@ -268,7 +300,7 @@ pub trait TryStreamExt: TryStream {
     /// but will resolve to an error immediately if the underlying stream or the provided
     /// closure return an error.
     ///
-    /// This method is only available when the `std` feature of this
+    /// This method is only available when the `alloc` feature of this
     /// library is activated, and it is activated by default.
     ///
     /// # Examples
@@ -301,7 +333,7 @@ pub trait TryStreamExt: TryStream {
     /// assert_eq!(Err(oneshot::Canceled), await!(fut));
     /// # })
     /// ```
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     fn try_for_each_concurrent<Fut, F>(
         self,
         limit: impl Into<Option<usize>>,
@@ -323,7 +355,7 @@ pub trait TryStreamExt: TryStream {
     ///
     /// The returned future will be resolved when the stream terminates.
     ///
-    /// This method is only available when the `std` feature of this
+    /// This method is only available when the `alloc` feature of this
     /// library is activated, and it is activated by default.
     ///
     /// # Examples
@@ -349,7 +381,7 @@ pub trait TryStreamExt: TryStream {
     /// assert_eq!(output, Err(6));
     /// # })
     /// ```
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     fn try_collect<C: Default + Extend<Self::Ok>>(self) -> TryCollect<Self, C>
         where Self: Sized
     {
@@ -496,7 +528,7 @@ pub trait TryStreamExt: TryStream {
     /// assert_eq!(await!(buffered.next()), Some(Err("error in the stream")));
     /// # })
     /// ```
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     fn try_buffer_unordered(self, n: usize) -> TryBufferUnordered<Self>
         where Self::Ok: TryFuture<Error = Self::Error>,
               Self: Sized
@@ -504,8 +536,63 @@ pub trait TryStreamExt: TryStream {
         TryBufferUnordered::new(self, n)
     }
 
+    /// Attempt to execute several futures from a stream concurrently,
+    /// returning their results in the order of the stream.
+    ///
+    /// This stream's `Ok` type must be a [`TryFuture`] with an `Error` type
+    /// that matches the stream's `Error` type.
+    ///
+    /// This adaptor will buffer up to `n` futures and then return their
+    /// outputs in the order in which the futures were yielded by the
+    /// underlying stream. An error is returned in its place in that order as
+    /// well: if a future fails while futures before it are still running, the
+    /// error is returned after their outputs.
+    ///
+    /// The first error, of either the underlying stream or one of the
+    /// futures, ends the returned stream. The futures that are still in
+    /// progress at that point are dropped.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await, await_macro)]
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::oneshot;
+    /// use futures::stream::{self, StreamExt, TryStreamExt};
+    ///
+    /// let (send_one, recv_one) = oneshot::channel();
+    /// let (send_two, recv_two) = oneshot::channel();
+    ///
+    /// let stream_of_futures = stream::iter(vec![Ok(recv_one), Ok(recv_two)]);
+    ///
+    /// let mut buffered = stream_of_futures.try_buffered(10);
+    ///
+    /// send_two.send(2i32);
+    /// send_one.send(1i32);
+    /// assert_eq!(await!(buffered.next()), Some(Ok(1i32)));
+    /// assert_eq!(await!(buffered.next()), Some(Ok(2i32)));
+    ///
+    /// assert_eq!(await!(buffered.next()), None);
+    /// # })
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_buffered(self, n: usize) -> TryBuffered<Self>
+        where Self::Ok: TryFuture<Error = Self::Error>,
+              Self: Sized
+    {
+        TryBuffered::new(self, n)
+    }
+
     /// Wraps a [`TryStream`] into a stream compatible with libraries using
     /// futures 0.1 `Stream`. Requires the `compat` feature to be enabled.
+    ///
+    /// `Some(Ok(t))` is yielded as `Async::Ready(Some(t))`, `Some(Err(e))` as
+    /// an error and `None` as `Async::Ready(None)`. The stream is polled with
+    /// a waker that notifies the current futures 0.1 task, and `spawn` is
+    /// used as the spawner of its context.
     #[cfg(feature = "compat")]
     fn compat<Sp>(self, spawn: Sp) -> Compat<Self, Sp>
         where Self: Sized + Unpin,
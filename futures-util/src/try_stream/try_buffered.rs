@@ -0,0 +1,141 @@
+use crate::stream::{Fuse, FuturesOrdered, StreamExt};
+use crate::try_future::{IntoFuture, TryFutureExt};
+use crate::try_stream::IntoStream;
+use core::fmt;
+use core::marker::Unpin;
+use core::mem::PinMut;
+use futures_core::future::TryFuture;
+use futures_core::stream::{Stream, TryStream};
+use futures_core::task::{self, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// A stream returned by the
+/// [`try_buffered`](super::TryStreamExt::try_buffered) method
+#[must_use = "streams do nothing unless polled"]
+pub struct TryBuffered<St>
+    where St: TryStream,
+          St::Ok: TryFuture,
+{
+    stream: Fuse<IntoStream<St>>,
+    in_progress_queue: FuturesOrdered<IntoFuture<St::Ok>>,
+    stream_error: Option<St::Error>,
+    max: usize,
+    is_terminated: bool,
+}
+
+impl<St> Unpin for TryBuffered<St>
+    where St: TryStream + Unpin,
+          St::Ok: TryFuture,
+{}
+
+impl<St> fmt::Debug for TryBuffered<St>
+    where St: TryStream + fmt::Debug,
+          St::Ok: TryFuture,
+          St::Error: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("TryBuffered")
+            .field("stream", &self.stream)
+            .field("in_progress_queue", &self.in_progress_queue)
+            .field("stream_error", &self.stream_error)
+            .field("max", &self.max)
+            .field("is_terminated", &self.is_terminated)
+            .finish()
+    }
+}
+
+impl<St> TryBuffered<St>
+    where St: TryStream,
+          St::Ok: TryFuture,
+{
+    unsafe_pinned!(stream: Fuse<IntoStream<St>>);
+    unsafe_unpinned!(in_progress_queue: FuturesOrdered<IntoFuture<St::Ok>>);
+    unsafe_unpinned!(stream_error: Option<St::Error>);
+    unsafe_unpinned!(is_terminated: bool);
+
+    pub(super) fn new(stream: St, n: usize) -> Self {
+        TryBuffered {
+            stream: IntoStream::new(stream).fuse(),
+            in_progress_queue: FuturesOrdered::new(),
+            stream_error: None,
+            max: n,
+            is_terminated: false,
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        self.stream.get_ref().get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        self.stream.get_mut().get_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream.into_inner().into_inner()
+    }
+}
+
+impl<St> Stream for TryBuffered<St>
+    where St: TryStream,
+          St::Ok: TryFuture<Error = St::Error>,
+{
+    type Item = Result<<St::Ok as TryFuture>::Ok, St::Error>;
+
+    fn poll_next(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Option<Self::Item>> {
+        if self.is_terminated {
+            return Poll::Ready(None);
+        }
+
+        // First up, try to spawn off as many futures as possible by filling up
+        // our queue of futures. An error from the stream stops the filling,
+        // it is returned once all the futures before it have completed.
+        while self.stream_error.is_none() && self.in_progress_queue.len() < self.max {
+            match self.stream().poll_next(cx) {
+                Poll::Ready(Some(Ok(fut))) => self.in_progress_queue().push(fut.into_future()),
+                Poll::Ready(Some(Err(e))) => *self.stream_error() = Some(e),
+                Poll::Ready(None) | Poll::Pending => break,
+            }
+        }
+
+        // Attempt to pull the next value from the in_progress_queue. The
+        // first error ends the stream.
+        match PinMut::new(self.in_progress_queue()).poll_next(cx) {
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(Some(Ok(item))) => return Poll::Ready(Some(Ok(item))),
+            Poll::Ready(Some(Err(e))) => {
+                // Drop the futures that are still in progress
+                *self.in_progress_queue() = FuturesOrdered::new();
+                *self.is_terminated() = true;
+                return Poll::Ready(Some(Err(e)));
+            }
+            Poll::Ready(None) => {}
+        }
+
+        if let Some(e) = self.stream_error().take() {
+            *self.is_terminated() = true;
+            return Poll::Ready(Some(Err(e)));
+        }
+
+        // If more values are still coming from the stream, we're not done yet
+        if self.stream.is_done() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use core::marker::Unpin;
+use core::mem::PinMut;
+use futures_core::never::Never;
+use futures_core::stream::{Stream, TryStream};
+use futures_core::task::{self, Poll};
+use pin_utils::unsafe_pinned;
+
+/// Stream for the
+/// [`unwrap_infallible`](super::TryStreamExt::unwrap_infallible) combinator.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct UnwrapInfallible<St> {
+    stream: St,
+}
+
+impl<St: Unpin> Unpin for UnwrapInfallible<St> {}
+
+impl<St> UnwrapInfallible<St> {
+    unsafe_pinned!(stream: St);
+
+    pub(super) fn new(stream: St) -> Self {
+        UnwrapInfallible { stream }
+    }
+}
+
+impl<St> Stream for UnwrapInfallible<St>
+where
+    St: TryStream<Error = Never>,
+{
+    type Item = St::Ok;
+
+    fn poll_next(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Option<Self::Item>> {
+        self.stream().try_poll_next(cx).map(|res| res.map(|item| match item {
+            Ok(item) => item,
+            Err(never) => never.into_any(),
+        }))
+    }
+}
@@ -54,13 +54,17 @@ pub use futures_util::{
     // Error/readiness propagation
     try_ready, try_poll, ready,
 };
+// Pinning values to the stack, e.g. to call `StreamExt::next` on `!Unpin`
+// streams
+pub use pin_utils::pin_mut;
 #[cfg(feature = "std")]
 pub use futures_util::{
     // Async-await
-    join, try_join, select, pending, poll, spawn, spawn_with_handle,
+    join, try_join, select, select_biased, stream_select, pending, poll, spawn,
+    spawn_with_handle,
 };
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 pub mod channel {
     //! Cross-task communication.
     //!
@@ -72,8 +76,14 @@ pub mod channel {
     //! - [mpsc](crate::channel::mpsc), a multi-producer, single-consumer
     //!   channel for sending values between tasks, analogous to the
     //!   similarly-named structure in the standard library.
+    //!
+    //! `mpsc` is only available when the `std` feature of this library is
+    //! activated, `oneshot` only needs the `alloc` feature.
 
-    pub use futures_channel::{oneshot, mpsc};
+    pub use futures_channel::oneshot;
+
+    #[cfg(feature = "std")]
+    pub use futures_channel::mpsc;
 }
 
 #[cfg(feature = "compat")]
@@ -83,15 +93,25 @@ pub mod compat {
 
     pub use futures_util::compat::{
         Compat,
+        Compat01As03,
         Executor01Future,
         Executor01As03,
         Executor01CompatExt,
+        Executor01LocalFuture,
+        Executor01LocalAs03,
         Future01CompatExt,
+        Sink01As03,
+        Sink01CompatExt,
         Stream01CompatExt,
     };
 
+    #[cfg(feature = "io-compat")]
+    pub use futures_util::compat::{AsyncRead01CompatExt, AsyncWrite01CompatExt};
+
     #[cfg(feature = "tokio-compat")]
-    pub use futures_util::compat::TokioDefaultSpawn;
+    pub use futures_util::compat::{
+        TokioDefaultSpawn, TokioExecutorAs03, TokioExecutorCompatExt,
+    };
 }
 
 #[cfg(feature = "std")]
@@ -138,7 +158,11 @@ pub mod executor {
     //!   [`spawn_obj`](crate::executor::Spawn::spawn_obj) method directly.
     //!
     //! Every task always has an associated default spawner, which is usually
-    //! the executor on which the task is running.
+    //! the executor on which the task is running. Contexts that are created
+    //! without an executor, e.g. to poll a future by hand, can use
+    //! [`NoSpawner`](crate::task::NoSpawner), which fails every spawn with a
+    //! [`shutdown`](crate::task::SpawnErrorKind::shutdown) error instead of
+    //! panicking.
     //!
     //! # Single-threaded execution
     //!
@@ -179,7 +203,7 @@ pub mod future {
     //!   immediate defined value.
 
     pub use futures_core::future::{
-        Future, TryFuture,
+        Future, TryFuture, FusedFuture,
         FutureObj, LocalFutureObj, UnsafeFutureObj,
     };
 
@@ -190,26 +214,31 @@ pub mod future {
         poll_fn, PollFn,
         ready, ok, err, Ready,
 
-        OptionFuture,
+        Either, OptionFuture,
 
         FutureExt,
         FlattenStream, Flatten, Fuse, Inspect, IntoStream, Join, Join3, Join4,
-        Join5, Map, Then, WithSpawner,
+        Join5, Map, NeverError, Then, WithSpawner,
     };
 
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     pub use futures_util::future::{
         abortable, Abortable, AbortHandle, AbortRegistration, Aborted,
+        join_all, JoinAll,
+    };
+
+    #[cfg(feature = "std")]
+    pub use futures_util::future::{
         // For FutureExt:
         CatchUnwind, Shared
 
-        // ToDo: JoinAll, SelectAll, SelectOk, join_all, select_all, select_ok
+        // ToDo: SelectAll, SelectOk, select_all, select_ok
     };
 
     pub use futures_util::try_future::{
         TryFutureExt,
         AndThen, ErrInto, FlattenSink, IntoFuture, MapErr, MapOk, OrElse,
-        UnwrapOrElse,
+        UnwrapInfallible, UnwrapOrElse,
         TryJoin, TryJoin3, TryJoin4, TryJoin5,
     };
 }
@@ -218,29 +247,45 @@ pub mod future {
 pub mod io {
     //! Asynchronous I/O.
     //!
-    //! This module is the asynchronous version of `std::io`. It defines two
-    //! traits, [`AsyncRead`](crate::io::AsyncRead) and
-    //! [`AsyncWrite`](crate::io::AsyncWrite), which mirror the `Read` and
-    //! `Write` traits of the standard library. However, these traits integrate
-    //! with the asynchronous task system, so that if an I/O object isn't ready
-    //! for reading (or writing), the thread is not blocked, and instead the
-    //! current task is queued to be woken when I/O is ready.
-    //!
-    //! In addition, the [`AsyncReadExt`](crate::io::AsyncReadExt) and
-    //! [`AsyncWriteExt`](crate::io::AsyncWriteExt) extension traits offer a
+    //! This module is the asynchronous version of `std::io`. It defines four
+    //! traits, [`AsyncRead`](crate::io::AsyncRead),
+    //! [`AsyncBufRead`](crate::io::AsyncBufRead),
+    //! [`AsyncWrite`](crate::io::AsyncWrite) and
+    //! [`AsyncSeek`](crate::io::AsyncSeek), which mirror the `Read`,
+    //! `BufRead`, `Write` and `Seek` traits of the standard library. However, these
+    //! traits integrate with the asynchronous task system, so that if an I/O
+    //! object isn't ready for reading (or writing), the thread is not blocked,
+    //! and instead the current task is queued to be woken when I/O is ready.
+    //!
+    //! In addition, the [`AsyncReadExt`](crate::io::AsyncReadExt),
+    //! [`AsyncBufReadExt`](crate::io::AsyncBufReadExt),
+    //! [`AsyncWriteExt`](crate::io::AsyncWriteExt) and
+    //! [`AsyncSeekExt`](crate::io::AsyncSeekExt) extension traits offer a
     //! variety of useful combinators for operating with asynchronous I/O
     //! objects, including ways to work with them using futures, streams and
     //! sinks.
 
     pub use futures_io::{
-        Error, Initializer, IoVec, ErrorKind, AsyncRead, AsyncWrite, Result
+        Error, Initializer, IoVec, ErrorKind, AsyncRead, AsyncBufRead, AsyncWrite,
+        AsyncSeek, Result, SeekFrom,
     };
     pub use futures_util::io::{
-        AsyncReadExt, AsyncWriteExt, AllowStdIo, Close, CopyInto, Flush,
-        Read, ReadExact, ReadHalf, ReadToEnd, Window, WriteAll, WriteHalf,
+        AsyncReadExt, AsyncBufReadExt, AsyncWriteExt, AsyncSeekExt, AllowStdIo,
+        BufReader, BufWriter, Chain, Close, CopyBufInto, CopyInto, Empty, Flush,
+        IntoInner, IntoInnerError, IntoSink, IntoStream, Lines, Read, ReadExact,
+        ReadHalf, ReadLine, ReadToEnd, ReadUntil, Repeat, ReuniteError, Seek,
+        Sink, Take, Window, WriteAll, WriteHalf, WriteVectoredAll, empty, repeat,
+        sink,
     };
 }
 
+pub mod never {
+    //! The [`Never`](crate::never::Never) type, which is used as the error
+    //! type of futures, streams and sinks which can't fail.
+
+    pub use futures_core::never::Never;
+}
+
 pub mod prelude {
     //! A "prelude" for crates using the `futures` crate.
     //!
@@ -260,7 +305,10 @@ pub mod prelude {
     pub use crate::sink::{self, Sink, SinkExt};
 
     #[cfg(feature = "std")]
-    pub use crate::io::{ AsyncRead, AsyncWrite, AsyncReadExt, AsyncWriteExt };
+    pub use crate::io::{
+        AsyncRead, AsyncBufRead, AsyncWrite, AsyncSeek,
+        AsyncReadExt, AsyncBufReadExt, AsyncWriteExt, AsyncSeekExt,
+    };
 }
 
 pub mod sink {
@@ -277,11 +325,11 @@ pub mod sink {
 
     pub use futures_util::sink::{
         Close, Flush, Send, SendAll, SinkErrInto, SinkMapErr, With,
-        SinkExt, Fanout, Drain, DrainError, drain,
+        SinkExt, Fanout, Drain, drain,
         // WithFlatMap,
     };
 
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     pub use futures_util::sink::Buffer;
 }
 
@@ -299,7 +347,7 @@ pub mod stream {
     //!   [`futures_unordered`](crate::stream::futures_unordered()), which
     //!   constructs a stream from a collection of futures.
 
-    pub use futures_core::stream::{Stream, TryStream};
+    pub use futures_core::stream::{Stream, TryStream, FusedStream};
 
     pub use futures_util::stream::{
         iter, Iter,
@@ -310,35 +358,44 @@ pub mod stream {
         unfold, Unfold,
 
         StreamExt,
-        Chain, Concat, Filter, FilterMap, Flatten, Fold, Forward, ForEach, Fuse,
-        StreamFuture, Inspect, Map, Next, Peekable, Select, Skip, SkipWhile,
-        Take, TakeWhile, Then, Zip
+        Chain, Concat, Filter, FilterMap, FilterMapSync, FilterSync, Flatten,
+        Fold, Forward, ForwardInto, ForEach, Fuse, StreamFuture, Inspect, Map,
+        MapWhile, Next, Peekable, Select, SelectNextSome, Skip, SkipWhile, Take,
+        TakeWhile, Then, Zip
     };
 
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     pub use futures_util::stream::{
         futures_ordered, FuturesOrdered,
         futures_unordered, FuturesUnordered,
 
         // For StreamExt:
-        BufferUnordered, Buffered, CatchUnwind, Chunks, Collect, SplitStream,
-        SplitSink, ReuniteError,
+        BufferUnordered, Buffered, Chunks, Collect, SplitStream, SplitSink,
+        ReuniteError,
 
         // ToDo: select_all, SelectAll,
     };
 
+    #[cfg(feature = "std")]
+    pub use futures_util::stream::{
+        FuturesMap,
+
+        // For StreamExt:
+        CatchUnwind,
+    };
+
     pub use futures_util::try_stream::{
         TryStreamExt,
         TryNext, TryForEach, ErrInto,
         TryFold, TrySkipWhile,
-        IntoStream,
+        IntoStream, UnwrapInfallible,
         // ToDo: AndThen, ErrInto, InspectErr, MapErr, OrElse
     };
 
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     pub use futures_util::try_stream::{
         // For TryStreamExt:
-        TryCollect, TryBufferUnordered,
+        TryCollect, TryBufferUnordered, TryBuffered,
         // ToDo: AndThen, InspectErr, MapErr, OrElse
     };
 }
@@ -365,18 +422,27 @@ pub mod task {
         SpawnErrorKind, SpawnObjError, SpawnLocalObjError,
     };
 
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     pub use futures_core::task::{
         Wake, local_waker, local_waker_from_nonlocal
     };
 
-    pub use futures_util::task::{SpawnExt, SpawnError};
+    pub use futures_util::task::{
+        SpawnExt, SpawnError, NoSpawner, no_spawner_mut,
+        noop_waker, noop_local_waker, panic_waker, panic_local_waker,
+        noop_waker_ref, noop_local_waker_ref, panic_waker_ref, panic_local_waker_ref,
+        noop_waker_context, panic_waker_context,
+    };
 
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     pub use futures_util::task::{
-        LocalWakerRef, local_waker_ref, local_waker_ref_from_nonlocal, JoinHandle
+        LocalWakerRef, local_waker_ref, local_waker_ref_from_nonlocal,
+        ArcWake, waker, waker_ref, WakerRef,
     };
 
+    #[cfg(feature = "std")]
+    pub use futures_util::task::JoinHandle;
+
     #[cfg_attr(
         feature = "nightly",
         cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
@@ -0,0 +1,134 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::future::{self, FutureExt};
+use futures::task::{self, ArcWake, Poll, waker, waker_ref};
+use futures_test::task::noop_spawner_mut;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct CountingWake {
+    wakes: AtomicUsize,
+    drops: Arc<AtomicUsize>,
+}
+
+impl CountingWake {
+    fn new(drops: Arc<AtomicUsize>) -> Arc<CountingWake> {
+        Arc::new(CountingWake { wakes: AtomicUsize::new(0), drops })
+    }
+
+    fn wakes(&self) -> usize {
+        self.wakes.load(Ordering::SeqCst)
+    }
+}
+
+impl ArcWake for CountingWake {
+    fn wake(arc_self: &Arc<Self>) {
+        arc_self.wakes.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+impl Drop for CountingWake {
+    fn drop(&mut self) {
+        self.drops.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn waker_holds_a_reference() {
+    let drops = Arc::new(AtomicUsize::new(0));
+    let wake = CountingWake::new(drops.clone());
+
+    let w1 = waker(wake.clone());
+    assert_eq!(Arc::strong_count(&wake), 2);
+
+    let w2 = w1.clone();
+    assert_eq!(Arc::strong_count(&wake), 3);
+
+    w2.wake();
+    w1.wake();
+    assert_eq!(wake.wakes(), 2);
+    assert_eq!(Arc::strong_count(&wake), 3);
+
+    drop(w2);
+    assert_eq!(Arc::strong_count(&wake), 2);
+
+    drop(wake);
+    assert_eq!(drops.load(Ordering::SeqCst), 0);
+
+    drop(w1);
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn waker_ref_borrows_without_reference() {
+    let drops = Arc::new(AtomicUsize::new(0));
+    let wake = CountingWake::new(drops.clone());
+
+    {
+        let w_ref = waker_ref(&wake);
+        assert_eq!(Arc::strong_count(&wake), 1);
+
+        w_ref.wake();
+        w_ref.as_local_waker().wake();
+        assert_eq!(wake.wakes(), 2);
+        assert_eq!(Arc::strong_count(&wake), 1);
+
+        // Clones of the borrowed waker own a reference.
+        let w1 = (*w_ref).clone();
+        let w2 = w_ref.as_local_waker().clone();
+        assert_eq!(Arc::strong_count(&wake), 3);
+
+        w1.wake();
+        w2.wake();
+        assert_eq!(wake.wakes(), 4);
+
+        drop(w1);
+        drop(w2);
+        assert_eq!(Arc::strong_count(&wake), 1);
+    }
+    assert_eq!(Arc::strong_count(&wake), 1);
+
+    drop(wake);
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn waker_outlives_its_source() {
+    let drops = Arc::new(AtomicUsize::new(0));
+    let wake = CountingWake::new(drops.clone());
+
+    let w = {
+        let w_ref = waker_ref(&wake);
+        w_ref.clone()
+    };
+    drop(wake);
+    assert_eq!(drops.load(Ordering::SeqCst), 0);
+
+    w.wake();
+    drop(w);
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn waker_ref_can_poll_futures() {
+    let drops = Arc::new(AtomicUsize::new(0));
+    let wake = CountingWake::new(drops.clone());
+    let w_ref = waker_ref(&wake);
+    let mut cx = task::Context::new(w_ref.as_local_waker(), noop_spawner_mut());
+
+    let mut woken_once = false;
+    let mut future = future::poll_fn(move |cx| {
+        if woken_once {
+            Poll::Ready(())
+        } else {
+            woken_once = true;
+            cx.waker().wake();
+            Poll::Pending
+        }
+    });
+
+    assert_eq!(future.poll_unpin(&mut cx), Poll::Pending);
+    assert_eq!(wake.wakes(), 1);
+    assert_eq!(future.poll_unpin(&mut cx), Poll::Ready(()));
+    assert_eq!(Arc::strong_count(&wake), 1);
+}
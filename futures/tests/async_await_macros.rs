@@ -1,9 +1,12 @@
 #![feature(async_await, await_macro, pin, arbitrary_self_types, futures_api)]
 
-use futures::{Poll, future, pending, poll, join, try_join, select};
-use futures::channel::oneshot;
+use futures::{Poll, future, stream, pending, poll, join, try_join, select, select_biased};
+use futures::channel::{mpsc, oneshot};
+use futures::future::{FusedFuture, Future, FutureExt, TryFutureExt};
+use futures::stream::StreamExt;
 use futures::executor::block_on;
 use pin_utils::pin_mut;
+use std::marker::Unpin;
 
 #[test]
 fn poll_and_pending() {
@@ -15,6 +18,53 @@ fn poll_and_pending() {
     });
 }
 
+async fn now_or_else<Fut: Future + Unpin>(mut fut: Fut, default: Fut::Output) -> Fut::Output {
+    match poll!(&mut fut) {
+        Poll::Ready(output) => output,
+        Poll::Pending => default,
+    }
+}
+
+#[test]
+fn poll_now_or_else() {
+    assert_eq!(block_on(now_or_else(future::ready(1), 0)), 1);
+    assert_eq!(block_on(now_or_else(future::empty(), 0)), 0);
+
+    let (tx, rx) = oneshot::channel::<i32>();
+    let mut rx = rx.fuse();
+    assert_eq!(block_on(now_or_else(&mut rx, Ok(0))), Ok(0));
+    tx.send(2).unwrap();
+    assert_eq!(block_on(now_or_else(&mut rx, Ok(0))), Ok(2));
+}
+
+async fn yield_times(n: usize) -> usize {
+    for _ in 0..n {
+        pending!();
+    }
+    n
+}
+
+#[test]
+fn pending_counts_polls() {
+    for n in 0..4 {
+        let fut = yield_times(n);
+        pin_mut!(fut);
+        let mut polls = 0;
+
+        let res = block_on(future::poll_fn(|cx| {
+            polls += 1;
+            let res = fut.reborrow().poll(cx);
+            // `pending!` doesn't wake the task by itself.
+            if res.is_pending() {
+                cx.waker().wake();
+            }
+            res
+        }));
+        assert_eq!(res, n);
+        assert_eq!(polls, n + 1);
+    }
+}
+
 #[test]
 fn join() {
     let (tx1, rx1) = oneshot::channel::<i32>();
@@ -35,40 +85,113 @@ fn join() {
     });
 }
 
+#[test]
+fn join_mixed_expressions() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let data = vec![1, 2, 3];
+
+    let fut = async {
+        join!(
+            rx,
+            future::ready(data.len()),
+            async {
+                pending!();
+                data.iter().sum::<i32>()
+            },
+        )
+    };
+
+    block_on(async {
+        pin_mut!(fut);
+        assert_eq!(Poll::Pending, poll!(&mut fut));
+        tx.send(1).unwrap();
+        assert_eq!(Poll::Ready((Ok(1), 3, 6)), poll!(&mut fut));
+    });
+}
+
+#[test]
+fn try_join_early_error() {
+    let (tx, rx) = oneshot::channel::<i32>();
+
+    let res = block_on(async {
+        try_join!(
+            rx.map_err(|_| "canceled"),
+            future::ready(Err::<i32, _>("error")),
+        )
+    });
+    assert_eq!(res, Err("error"));
+    // The pending receiver was dropped right away.
+    assert!(tx.is_canceled());
+}
+
 #[test]
 fn select() {
-    let (tx1, mut rx1) = oneshot::channel::<i32>();
-    let (_tx2, mut rx2) = oneshot::channel::<i32>();
+    let (tx1, rx1) = oneshot::channel::<i32>();
+    let (_tx2, rx2) = oneshot::channel::<i32>();
+    let (mut rx1, mut rx2) = (rx1.fuse(), rx2.fuse());
     tx1.send(1).unwrap();
     let mut ran = false;
     block_on(async {
         select! {
-            rx1 => {
-                assert_eq!(Ok(1), rx1);
+            res = rx1 => {
+                assert_eq!(Ok(1), res);
                 ran = true;
             },
-            rx2 => unreachable!(),
+            _ = rx2 => unreachable!(),
         }
     });
     assert!(ran);
 }
 
+#[test]
+fn select_second_arm_wins() {
+    let (_tx1, rx1) = oneshot::channel::<i32>();
+    let (tx2, rx2) = oneshot::channel::<&str>();
+    let (mut rx1, mut rx2) = (rx1.fuse(), rx2.fuse());
+
+    let res = block_on(async {
+        let mut poll_count = 0;
+        let mut send_later = future::poll_fn(|cx| {
+            poll_count += 1;
+            if poll_count == 3 {
+                Poll::Ready(())
+            } else {
+                cx.waker().wake();
+                Poll::Pending
+            }
+        }).fuse();
+        let mut tx2 = Some(tx2);
+
+        loop {
+            select! {
+                _ = rx1 => unreachable!(),
+                res = rx2 => break res,
+                () = send_later => tx2.take().unwrap().send("two").unwrap(),
+            }
+        }
+    });
+    assert_eq!(res, Ok("two"));
+    assert!(!rx1.is_terminated());
+    assert!(rx2.is_terminated());
+}
+
 #[test]
 fn select_can_move_uncompleted_futures() {
-    let (tx1, mut rx1) = oneshot::channel::<i32>();
-    let (tx2, mut rx2) = oneshot::channel::<i32>();
+    let (tx1, rx1) = oneshot::channel::<i32>();
+    let (tx2, rx2) = oneshot::channel::<i32>();
+    let (mut rx1, mut rx2) = (rx1.fuse(), rx2.fuse());
     tx1.send(1).unwrap();
     tx2.send(2).unwrap();
     let mut ran = false;
     block_on(async {
         select! {
-            rx1 => {
-                assert_eq!(Ok(1), rx1);
+            res = rx1 => {
+                assert_eq!(Ok(1), res);
                 assert_eq!(Ok(2), await!(rx2));
                 ran = true;
             },
-            rx2 => {
-                assert_eq!(Ok(2), rx2);
+            res = rx2 => {
+                assert_eq!(Ok(2), res);
                 assert_eq!(Ok(1), await!(rx1));
                 ran = true;
             },
@@ -77,25 +200,200 @@ fn select_can_move_uncompleted_futures() {
     assert!(ran);
 }
 
+#[test]
+fn select_in_loop_keeps_state() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let mut rx = rx.fuse();
+    let mut first = future::ready(1).fuse();
+    let mut tx = Some(tx);
+    let mut output = Vec::new();
+
+    block_on(async {
+        loop {
+            select! {
+                x = first => {
+                    // `rx` may have been polled already, so this has to
+                    // wake it up.
+                    tx.take().unwrap().send(x + 1).unwrap();
+                    output.push(x);
+                },
+                x = rx => output.push(x.unwrap()),
+                complete => break,
+            }
+        }
+    });
+    assert_eq!(output, vec![1, 2]);
+    assert!(first.is_terminated());
+    assert!(rx.is_terminated());
+}
+
+#[test]
+fn select_next_some_streams() {
+    let (mut tx1, rx1) = mpsc::unbounded::<u32>();
+    let (mut tx2, rx2) = mpsc::unbounded::<u32>();
+    let (mut rx1, mut rx2) = (rx1.fuse(), rx2.fuse());
+    for i in 0..5 {
+        tx1.start_send(i).unwrap();
+        tx2.start_send(i * 10).unwrap();
+    }
+    drop((tx1, tx2));
+
+    let (mut from1, mut from2) = (Vec::new(), Vec::new());
+    block_on(async {
+        loop {
+            select! {
+                x = rx1.select_next_some() => from1.push(x),
+                x = rx2.select_next_some() => from2.push(x),
+                complete => break,
+            }
+        }
+    });
+    assert_eq!(from1, vec![0, 1, 2, 3, 4]);
+    assert_eq!(from2, vec![0, 10, 20, 30, 40]);
+}
+
+#[test]
+fn select_default() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let mut rx = rx.fuse();
+
+    let res = block_on(async {
+        select! {
+            _ = rx => panic!("nothing was sent yet"),
+            default => 0,
+        }
+    });
+    assert_eq!(res, 0);
+    assert!(!rx.is_terminated());
+
+    tx.send(5).unwrap();
+    let res = block_on(async {
+        select! {
+            x = rx => x.unwrap(),
+            default => 0,
+        }
+    });
+    assert_eq!(res, 5);
+}
+
+#[test]
+fn select_complete() {
+    let mut a = future::ready(1).fuse();
+    let mut b = future::ready(2).fuse();
+    let mut total = 0;
+    let mut rounds = 0;
+
+    block_on(async {
+        loop {
+            rounds += 1;
+            select! {
+                x = a => total += x,
+                x = b => total += x,
+                complete => break,
+                default => unreachable!(),
+            }
+        }
+    });
+    assert_eq!(total, 3);
+    assert_eq!(rounds, 3);
+}
+
+#[test]
+fn select_default_when_complete_is_missing() {
+    let mut a = future::ready(1).fuse();
+    block_on(async {
+        select! {
+            x = a => assert_eq!(x, 1),
+            default => unreachable!(),
+        }
+        select! {
+            _ = a => unreachable!(),
+            default => {},
+        }
+    });
+}
+
+#[test]
+#[should_panic(expected = "all futures in select! were completed")]
+fn select_panics_when_all_complete() {
+    let mut a = future::ready(1).fuse();
+    block_on(async {
+        for _ in 0..2 {
+            select! {
+                _ = a => {},
+            }
+        }
+    });
+}
+
+#[test]
+fn select_order_is_random() {
+    let mut wins = [0; 2];
+    for _ in 0..100 {
+        block_on(async {
+            select! {
+                () = future::ready(()).fuse() => wins[0] += 1,
+                () = future::ready(()).fuse() => wins[1] += 1,
+            }
+        });
+    }
+    // The odds of this failing with a fair order are 2 in 2^100.
+    assert!(wins[0] > 0, "{:?}", wins);
+    assert!(wins[1] > 0, "{:?}", wins);
+}
+
+#[test]
+fn select_biased_first_arm_wins() {
+    for _ in 0..100 {
+        let res = block_on(async {
+            select_biased! {
+                x = future::ready(1).fuse() => x,
+                x = future::ready(2).fuse() => x,
+            }
+        });
+        assert_eq!(res, 1);
+    }
+}
+
+#[test]
+fn select_biased_in_order_until_complete() {
+    let (tx, rx) = oneshot::channel::<()>();
+    let (mut shutdown, mut items) = (rx.fuse(), stream::iter(1..=3).fuse());
+    tx.send(()).unwrap();
+
+    // The shutdown signal is checked first, even though items are ready.
+    let mut seen = Vec::new();
+    block_on(async {
+        loop {
+            select_biased! {
+                _ = shutdown => seen.push(0),
+                x = items.select_next_some() => seen.push(x),
+                complete => break,
+            }
+        }
+    });
+    assert_eq!(seen, vec![0, 1, 2, 3]);
+}
+
 #[test]
 fn select_size() {
     let fut = async {
-        let mut ready = future::ready(0i32);
+        let mut ready = future::ready(0i32).fuse();
         select! {
-            ready => {},
+            _ = ready => {},
         }
     };
-    assert_eq!(::std::mem::size_of_val(&fut), 24);
+    assert_eq!(::std::mem::size_of_val(&fut), 40);
 
     let fut = async {
-        let mut ready1 = future::ready(0i32);
-        let mut ready2 = future::ready(0i32);
+        let mut ready1 = future::ready(0i32).fuse();
+        let mut ready2 = future::ready(0i32).fuse();
         select! {
-            ready1 => {},
-            ready2 => {},
+            _ = ready1 => {},
+            _ = ready2 => {},
         }
     };
-    assert_eq!(::std::mem::size_of_val(&fut), 40);
+    assert_eq!(::std::mem::size_of_val(&fut), 72);
 }
 
 #[test]
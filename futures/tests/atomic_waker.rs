@@ -0,0 +1,128 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on;
+use futures::future::poll_fn;
+use futures::task::{AtomicWaker, Poll};
+use futures_test::task::{panic_context, WakeCounter};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+#[test]
+fn wake_before_register_is_noop() {
+    let atomic_waker = AtomicWaker::new();
+    atomic_waker.wake();
+
+    let wake_counter = WakeCounter::new();
+    let mut cx = panic_context();
+    let cx = &mut cx.with_waker(wake_counter.local_waker());
+    atomic_waker.register(cx.waker());
+    assert_eq!(wake_counter.count(), 0);
+}
+
+#[test]
+fn wake_takes_registered_waker() {
+    let atomic_waker = AtomicWaker::new();
+    let wake_counter = WakeCounter::new();
+    let mut cx = panic_context();
+    let cx = &mut cx.with_waker(wake_counter.local_waker());
+
+    atomic_waker.register(cx.waker());
+    atomic_waker.wake();
+    assert_eq!(wake_counter.count(), 1);
+
+    // The waker was deregistered by the first `wake`.
+    atomic_waker.wake();
+    assert_eq!(wake_counter.count(), 1);
+}
+
+#[test]
+fn register_replaces_previous_waker() {
+    let atomic_waker = AtomicWaker::new();
+    let first = WakeCounter::new();
+    let second = WakeCounter::new();
+    let mut cx = panic_context();
+
+    atomic_waker.register(cx.with_waker(first.local_waker()).waker());
+    atomic_waker.register(cx.with_waker(second.local_waker()).waker());
+    atomic_waker.wake();
+
+    assert_eq!(first.count(), 0);
+    assert_eq!(second.count(), 1);
+}
+
+#[test]
+fn wake_racing_register_is_never_lost() {
+    const ROUNDS: usize = 10_000;
+
+    let atomic_waker = Arc::new(AtomicWaker::new());
+    let produced = Arc::new(AtomicUsize::new(0));
+    let consumed = Arc::new(AtomicUsize::new(0));
+
+    let producer = {
+        let atomic_waker = atomic_waker.clone();
+        let produced = produced.clone();
+        let consumed = consumed.clone();
+        thread::spawn(move || {
+            for round in 1..=ROUNDS {
+                // Only produce once the previous value has been seen, so that
+                // every round needs its own wakeup.
+                while consumed.load(Ordering::SeqCst) != round - 1 {
+                    thread::yield_now();
+                }
+                produced.store(round, Ordering::SeqCst);
+                atomic_waker.wake();
+            }
+        })
+    };
+
+    // `block_on` only returns if none of the wakeups got lost.
+    block_on(poll_fn(|cx| {
+        loop {
+            atomic_waker.register(cx.waker());
+            let round = produced.load(Ordering::SeqCst);
+            if round == consumed.load(Ordering::SeqCst) {
+                return Poll::Pending;
+            }
+            consumed.store(round, Ordering::SeqCst);
+            if round == ROUNDS {
+                return Poll::Ready(());
+            }
+        }
+    }));
+
+    producer.join().unwrap();
+}
+
+#[test]
+fn concurrent_wakes_wake_registered_task() {
+    const THREADS: usize = 4;
+    const WAKES: usize = 1_000;
+
+    let atomic_waker = Arc::new(AtomicWaker::new());
+    let cnt = Arc::new(AtomicUsize::new(0));
+
+    let producers: Vec<_> = (0..THREADS).map(|_| {
+        let atomic_waker = atomic_waker.clone();
+        let cnt = cnt.clone();
+        thread::spawn(move || {
+            for _ in 0..WAKES {
+                cnt.fetch_add(1, Ordering::SeqCst);
+                atomic_waker.wake();
+            }
+        })
+    }).collect();
+
+    block_on(poll_fn(|cx| {
+        atomic_waker.register(cx.waker());
+        if cnt.load(Ordering::SeqCst) == THREADS * WAKES {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }));
+
+    for producer in producers {
+        producer.join().unwrap();
+    }
+}
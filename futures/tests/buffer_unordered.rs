@@ -0,0 +1,44 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on;
+use futures::future::{self, FutureExt};
+use futures::stream::{self, StreamExt};
+use futures_test::future::FutureTestExt;
+use futures_test::stream::StreamTestExt;
+
+#[test]
+fn buffer_unordered_with_pending_futures_and_stream() {
+    let stream = stream::iter(0..20)
+        .map(|i| {
+            // Futures that are pending a different number of times finish in
+            // a different order than they started.
+            if i % 3 == 0 {
+                future::ready(i).interleave_pending().left_future()
+            } else {
+                future::ready(i).pending_once().interleave_pending().right_future()
+            }
+        })
+        .interleave_pending()
+        .buffer_unordered(3);
+
+    let mut items: Vec<i32> = block_on(stream.collect());
+    items.sort();
+    assert_eq!(items, (0..20).collect::<Vec<_>>());
+}
+
+#[test]
+fn buffered_keeps_order_with_pending_futures_and_stream() {
+    let stream = stream::iter(0..20)
+        .map(|i| {
+            if i % 3 == 0 {
+                future::ready(i).interleave_pending().left_future()
+            } else {
+                future::ready(i).pending_once().interleave_pending().right_future()
+            }
+        })
+        .interleave_pending()
+        .buffered(3);
+
+    let items: Vec<i32> = block_on(stream.collect());
+    assert_eq!(items, (0..20).collect::<Vec<_>>());
+}
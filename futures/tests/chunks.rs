@@ -0,0 +1,42 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::future;
+use futures::stream::{self, StreamExt};
+use futures_test::future::FutureTestExt;
+use futures_test::{
+    assert_stream_pending, assert_stream_next, assert_stream_done,
+};
+use pin_utils::pin_mut;
+
+#[test]
+fn chunks_keep_items_across_pending() {
+    let stream = stream::iter(1..=5)
+        .then(|x| future::ready(x).pending_once())
+        .chunks(2);
+    pin_mut!(stream);
+
+    assert_stream_pending!(stream);
+    // The first item is buffered while waiting for the second one.
+    assert_stream_pending!(stream);
+    assert_stream_next!(stream, vec![1, 2]);
+    assert_stream_pending!(stream);
+    assert_stream_pending!(stream);
+    assert_stream_next!(stream, vec![3, 4]);
+    assert_stream_pending!(stream);
+    // The last chunk isn't full.
+    assert_stream_next!(stream, vec![5]);
+    assert_stream_done!(stream);
+    assert_stream_done!(stream);
+}
+
+#[test]
+fn chunks_of_empty_stream() {
+    let mut stream = stream::empty::<i32>().chunks(3);
+    assert_stream_done!(stream);
+}
+
+#[test]
+#[should_panic]
+fn chunks_zero_capacity() {
+    let _ = stream::empty::<i32>().chunks(0);
+}
@@ -0,0 +1,90 @@
+#![feature(async_await, await_macro, pin, arbitrary_self_types, futures_api)]
+#![cfg(feature = "compat")]
+
+use futures::compat::{Future01CompatExt, Stream01CompatExt};
+use futures::executor::{block_on, ThreadPool};
+use futures::stream::StreamExt;
+use futures::task::SpawnExt;
+use futures01::{task_local, Async as Async01};
+use futures01::future as future01;
+use futures01::stream as stream01;
+use futures01::sync::oneshot as oneshot01;
+use futures01::task as task01;
+use std::cell::Cell;
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn await_01_oneshot() {
+    let (tx, rx) = oneshot01::channel();
+    let sender = thread::spawn(move || {
+        // Make sure that the task is waiting when the value arrives.
+        thread::sleep(Duration::from_millis(20));
+        tx.send(5).unwrap();
+    });
+
+    assert_eq!(block_on(async { await!(rx.compat()) }), Ok(5));
+    sender.join().unwrap();
+}
+
+#[test]
+fn await_01_oneshot_on_thread_pool() {
+    let (tx, rx) = oneshot01::channel();
+    let mut pool = ThreadPool::new().unwrap();
+
+    let handle = pool.spawn_with_handle(async move {
+        await!(rx.compat())
+    }).unwrap();
+    tx.send("hello").unwrap();
+
+    assert_eq!(block_on(handle), Ok("hello"));
+}
+
+#[test]
+fn await_01_deferred_future() {
+    // Only ready on the second poll, the first poll notifies the current
+    // 0.1 task, which has to wake the 0.3 task.
+    let mut polled = false;
+    let deferred = future01::poll_fn(move || {
+        if polled {
+            Ok::<_, ()>(Async01::Ready(5))
+        } else {
+            polled = true;
+            task01::current().notify();
+            Ok(Async01::NotReady)
+        }
+    });
+
+    assert_eq!(block_on(async { await!(deferred.compat()) }), Ok(5));
+}
+
+#[test]
+fn task_local_data_is_kept_across_polls() {
+    task_local! {
+        static POLLS: Cell<usize> = Cell::new(0)
+    }
+
+    // The future counts its polls in task-local data, that only works if all
+    // polls happen in the same futures 0.1 task.
+    let future = future01::poll_fn(|| {
+        let polls = POLLS.with(|polls| {
+            polls.set(polls.get() + 1);
+            polls.get()
+        });
+        if polls == 3 {
+            Ok::<_, ()>(Async01::Ready(polls))
+        } else {
+            task01::current().notify();
+            Ok(Async01::NotReady)
+        }
+    });
+
+    assert_eq!(block_on(future.compat()), Ok(3));
+}
+
+#[test]
+fn await_01_stream() {
+    let stream = stream01::iter_result(vec![Ok(1), Err(2), Ok(3)]);
+    let items = block_on(stream.compat().collect::<Vec<_>>());
+    assert_eq!(items, vec![Ok(1), Err(2), Ok(3)]);
+}
@@ -0,0 +1,86 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+#![cfg(feature = "compat")]
+
+use futures::channel::oneshot;
+use futures::compat::Executor01CompatExt;
+use futures::executor::block_on;
+use futures::future::{self, FutureObj};
+use futures::task::{Spawn, SpawnExt};
+use futures01::Future as Future01;
+use futures01::future as future01;
+use tokio::executor::current_thread::TaskExecutor;
+use tokio::runtime::Runtime;
+use tokio::runtime::current_thread::Runtime as CurrentThreadRuntime;
+
+#[test]
+fn spawn_on_runtime_executor() {
+    let (tx, rx) = oneshot::channel();
+    let runtime = Runtime::new().unwrap();
+
+    let mut spawn = runtime.executor().compat();
+    spawn.spawn(future::lazy(move |cx| {
+        // Tasks spawned from within the task go to the same executor.
+        cx.spawner().spawn(future::lazy(move |_| {
+            tx.send(5).unwrap();
+        })).unwrap();
+    })).unwrap();
+
+    assert_eq!(block_on(rx), Ok(5));
+    runtime.shutdown_on_idle().wait().unwrap();
+}
+
+#[test]
+fn spawn_on_current_thread() {
+    let (tx, rx) = oneshot::channel();
+    let mut runtime = CurrentThreadRuntime::new().unwrap();
+
+    runtime.block_on(future01::lazy(move || {
+        // `TaskExecutor` is not `Send`, it spawns onto the `CurrentThread`
+        // executor running on this thread.
+        let mut spawn = TaskExecutor::current().compat_local();
+        spawn.spawn(future::lazy(move |cx| {
+            // Tasks spawned from within the task go to the same executor.
+            cx.spawner().spawn(future::lazy(move |_| {
+                tx.send(5).unwrap();
+            })).unwrap();
+        })).unwrap();
+        Ok::<_, ()>(())
+    })).unwrap();
+    runtime.run().unwrap();
+
+    assert_eq!(block_on(rx), Ok(5));
+}
+
+#[test]
+fn failed_spawn_returns_the_future() {
+    let (tx, rx) = oneshot::channel();
+    let task = FutureObj::new(Box::new(future::lazy(move |_| {
+        tx.send(5).unwrap();
+    })));
+
+    let runtime = Runtime::new().unwrap();
+    let mut spawn = runtime.executor().compat();
+    runtime.shutdown_now().wait().unwrap();
+
+    let err = spawn.spawn_obj(task).unwrap_err();
+    assert!(err.kind.is_shutdown());
+
+    block_on(err.future);
+    assert_eq!(block_on(rx), Ok(5));
+}
+
+#[test]
+fn failed_local_spawn_returns_the_future() {
+    let (tx, rx) = oneshot::channel();
+    let task = FutureObj::new(Box::new(future::lazy(move |_| {
+        tx.send(5).unwrap();
+    })));
+
+    // There is no `CurrentThread` executor running on this thread.
+    let mut spawn = TaskExecutor::current().compat_local();
+    let err = spawn.spawn_obj(task).unwrap_err();
+    assert!(err.kind.is_shutdown());
+
+    block_on(err.future);
+    assert_eq!(block_on(rx), Ok(5));
+}
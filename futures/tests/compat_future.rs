@@ -0,0 +1,40 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+#![cfg(feature = "compat")]
+
+use futures::channel::oneshot;
+use futures::future::{self, FutureExt, TryFutureExt};
+use futures::task::SpawnExt;
+use futures01::Future as Future01;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn unspawned_wakes_up_under_tokio_run() {
+    let (tx, rx) = oneshot::channel();
+    let (done_tx, done_rx) = mpsc::channel();
+
+    let sender = thread::spawn(move || {
+        // Make sure that the task is waiting when the value arrives.
+        thread::sleep(Duration::from_millis(20));
+        tx.send(5).unwrap();
+    });
+
+    let future = rx
+        .map(move |result| done_tx.send(result.unwrap()).unwrap())
+        .unit_error()
+        .compat_unspawned();
+    tokio::run(future);
+
+    assert_eq!(done_rx.recv().unwrap(), 5);
+    sender.join().unwrap();
+}
+
+#[test]
+fn unspawned_spawn_fails() {
+    let future = future::lazy(|cx| {
+        cx.spawner().spawn(future::ready(())).unwrap_err().kind.is_shutdown()
+    });
+
+    assert_eq!(future.unit_error().compat_unspawned().wait(), Ok(true));
+}
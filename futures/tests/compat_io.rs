@@ -0,0 +1,85 @@
+#![feature(async_await, await_macro, pin, arbitrary_self_types, futures_api)]
+#![cfg(all(feature = "io-compat", feature = "tokio-compat"))]
+
+use futures::compat::{
+    AsyncRead01CompatExt, Future01CompatExt, TokioDefaultSpawn,
+};
+use futures::future::{FutureExt, TryFutureExt};
+use futures::io::{AsyncReadExt, AsyncWriteExt};
+use futures01::{Future as Future01, Stream as Stream01};
+use std::io;
+use std::net::SocketAddr;
+use tokio::io::{AsyncRead as AsyncRead01, AsyncWrite as AsyncWrite01};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::runtime::Runtime;
+
+fn data() -> Vec<u8> {
+    // Large enough that neither side can write everything without the other
+    // side reading in between.
+    (0..1024 * 1024).map(|i| (i % 251) as u8).collect()
+}
+
+/// Starts a tokio echo server for a single connection on `runtime`. The
+/// socket is passed through `wrap` before it is handed to tokio's `copy`.
+fn echo_server<T, F>(runtime: &mut Runtime, mut wrap: F) -> SocketAddr
+where
+    F: FnMut(TcpStream) -> T + Send + 'static,
+    T: AsyncRead01 + AsyncWrite01 + Send + 'static,
+{
+    let listener = TcpListener::bind(&"127.0.0.1:0".parse().unwrap()).unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = listener.incoming().take(1).for_each(move |socket| {
+        let (reader, writer) = wrap(socket).split();
+        tokio::io::copy(reader, writer)
+            .and_then(|(_, _, writer)| tokio::io::shutdown(writer))
+            .map(drop)
+    });
+    runtime.spawn(server.map_err(|e| panic!("echo server failed: {}", e)));
+    addr
+}
+
+/// Sends `data` to the echo server through the futures-io wrapper of a tokio
+/// socket and returns everything read back until EOF.
+fn echo_client(runtime: &mut Runtime, addr: SocketAddr, data: Vec<u8>) -> Vec<u8> {
+    let client = async move {
+        let socket = await!(TcpStream::connect(&addr).compat())?;
+        let (mut reader, mut writer) = socket.compat().split();
+
+        let write = async move {
+            await!(writer.write_all(&data))?;
+            // Shuts down the write side of the socket, so the server sees EOF.
+            await!(writer.close())
+        };
+        let read = async move {
+            let mut buf = Vec::new();
+            await!(reader.read_to_end(&mut buf))?;
+            Ok::<_, io::Error>(buf)
+        };
+
+        let (written, read) = await!(write.join(read));
+        written?;
+        read
+    };
+    runtime.block_on(client.boxed().compat(TokioDefaultSpawn)).unwrap()
+}
+
+#[test]
+fn echo_through_futures_io_wrapper() {
+    let mut runtime = Runtime::new().unwrap();
+    let addr = echo_server(&mut runtime, |socket| socket);
+
+    assert_eq!(echo_client(&mut runtime, addr, data()), data());
+}
+
+#[test]
+fn echo_through_both_wrappers() {
+    let mut runtime = Runtime::new().unwrap();
+    // The server socket is converted to futures-io and back to tokio-io, so
+    // tokio's `copy` and `shutdown` go through both directions of the compat
+    // layer.
+    let addr = echo_server(&mut runtime, |socket| {
+        socket.compat().compat(TokioDefaultSpawn)
+    });
+
+    assert_eq!(echo_client(&mut runtime, addr, data()), data());
+}
@@ -0,0 +1,69 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+#![cfg(feature = "tokio-compat")]
+
+use futures::channel::mpsc;
+use futures::compat::{Sink01CompatExt, TokioDefaultSpawn};
+use futures::executor::block_on;
+use futures::sink::SinkExt;
+use futures::stream::{self, StreamExt};
+use futures::future::TryFutureExt;
+use futures01::{Future as Future01, Stream as Stream01};
+use futures01::stream as stream01;
+use futures01::sync::mpsc as mpsc01;
+use tokio::runtime::Runtime;
+
+#[test]
+fn forward_03_stream_into_01_sink() {
+    // With a buffer of 0 the sender only takes one item at a time, so most
+    // items are returned with `AsyncSink::NotReady` first and have to be kept
+    // until the receiver made room.
+    let (tx, rx) = mpsc01::channel(0);
+    let forward = stream::iter((0..10).map(Ok))
+        .forward(tx.sink_compat())
+        .compat_unspawned()
+        // Drop the sender so that the receiver ends.
+        .map(drop)
+        .map_err(|_| ());
+
+    let mut runtime = Runtime::new().unwrap();
+    let ((), items) = runtime.block_on(forward.join(rx.collect())).unwrap();
+    assert_eq!(items, (0..10).collect::<Vec<i32>>());
+}
+
+#[test]
+fn error_of_01_sink_is_passed_on() {
+    let (tx, rx) = mpsc01::channel(1);
+    drop(rx);
+
+    let forward = stream::iter(vec![Ok(1), Ok(2)]).forward(tx.sink_compat());
+    let err = block_on(forward).unwrap_err();
+    assert_eq!(err.into_inner(), 1);
+}
+
+#[test]
+fn flush_and_close_reach_01_sink() {
+    let (tx, rx) = mpsc01::unbounded();
+    let mut sink = tx.sink_compat();
+
+    block_on(sink.send(1)).unwrap();
+    block_on(sink.send(2)).unwrap();
+    block_on(sink.close()).unwrap();
+    drop(sink);
+
+    assert_eq!(rx.collect().wait().unwrap(), vec![1, 2]);
+}
+
+#[test]
+fn forward_01_stream_into_03_sink() {
+    let (tx, rx) = mpsc::channel(0);
+    let forward = stream01::iter_ok::<_, mpsc::SendError>(0..10)
+        .forward(tx.compat(TokioDefaultSpawn))
+        .map(drop)
+        .map_err(|e| panic!("forwarding failed: {}", e));
+
+    let runtime = Runtime::new().unwrap();
+    runtime.executor().spawn(forward);
+
+    assert_eq!(block_on(rx.collect::<Vec<_>>()), (0..10).collect::<Vec<i32>>());
+    runtime.shutdown_on_idle().wait().unwrap();
+}
@@ -0,0 +1,54 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+#![cfg(feature = "tokio-compat")]
+
+use futures::compat::{Stream01CompatExt, TokioDefaultSpawn};
+use futures::stream::TryStreamExt;
+use futures01::Stream as Stream01;
+use futures01::stream as stream01;
+use futures01::sync::mpsc as mpsc01;
+use std::marker::Unpin;
+use std::thread;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+/// Converts a 0.1 stream to a 0.3 stream and back, then collects all items
+/// and errors on a `tokio` runtime.
+fn round_trip<St>(stream: St) -> Vec<Result<St::Item, St::Error>>
+where
+    St: Stream01 + Unpin + Send + 'static,
+    St::Item: Send + 'static,
+    St::Error: Send + 'static,
+{
+    let stream = TryStreamExt::compat(stream.compat(), TokioDefaultSpawn);
+    let mut runtime = Runtime::new().unwrap();
+    runtime.block_on(stream.then(Ok::<_, ()>).collect()).unwrap()
+}
+
+#[test]
+fn round_trip_items_errors_and_end() {
+    let stream = stream01::iter_result(vec![Ok(1), Err("boom"), Ok(2), Ok(3)]);
+    assert_eq!(round_trip(stream), vec![Ok(1), Err("boom"), Ok(2), Ok(3)]);
+}
+
+#[test]
+fn round_trip_empty() {
+    let stream = stream01::empty::<u8, ()>();
+    assert_eq!(round_trip(stream), vec![]);
+}
+
+#[test]
+fn round_trip_wakeups_from_another_thread() {
+    let (tx, rx) = mpsc01::unbounded();
+    let sender = thread::spawn(move || {
+        for item in vec![Ok(1), Err("boom"), Ok(2)] {
+            // Give the receiving task time to return `NotReady`, so each item
+            // has to wake it up through both conversions.
+            thread::sleep(Duration::from_millis(10));
+            tx.unbounded_send(item).unwrap();
+        }
+    });
+
+    let stream = rx.then(|item| item.expect("0.1 channels never fail"));
+    assert_eq!(round_trip(stream), vec![Ok(1), Err("boom"), Ok(2)]);
+    sender.join().unwrap();
+}
@@ -0,0 +1,68 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+#![cfg(feature = "tokio-compat")]
+
+use futures::channel::oneshot;
+use futures::compat::{TokioDefaultSpawn, TokioExecutorCompatExt};
+use futures::executor::block_on;
+use futures::future::{self, FutureObj};
+use futures::task::{Spawn, SpawnExt};
+use futures01::Future as Future01;
+use tokio::runtime::Runtime;
+
+#[test]
+fn status_outside_of_tokio_is_shutdown() {
+    assert!(TokioDefaultSpawn.status().unwrap_err().is_shutdown());
+}
+
+#[test]
+fn failed_spawn_returns_the_future() {
+    let (tx, rx) = oneshot::channel();
+    let task = FutureObj::new(Box::new(future::lazy(move |_| {
+        tx.send(5).unwrap();
+    })));
+
+    // There is no `tokio` executor running on this thread, so spawning fails
+    // without panicking and hands back the task.
+    let err = TokioDefaultSpawn.spawn_obj(task).unwrap_err();
+    assert!(err.kind.is_shutdown());
+
+    block_on(err.future);
+    assert_eq!(block_on(rx), Ok(5));
+}
+
+#[test]
+fn spawn_on_tokio_executor() {
+    let (tx, rx) = oneshot::channel();
+    let runtime = Runtime::new().unwrap();
+
+    let mut spawn = runtime.executor().compat_tokio();
+    assert!(spawn.status().is_ok());
+    spawn.spawn(future::lazy(move |cx| {
+        // Tasks spawned from within the task go to the same executor.
+        cx.spawner().spawn(future::lazy(move |_| {
+            tx.send(5).unwrap();
+        })).unwrap();
+    })).unwrap();
+
+    assert_eq!(block_on(rx), Ok(5));
+}
+
+#[test]
+fn spawn_on_shut_down_tokio_executor() {
+    let (tx, rx) = oneshot::channel();
+    let runtime = Runtime::new().unwrap();
+    let mut spawn = runtime.executor().compat_tokio();
+    runtime.shutdown_now().wait().unwrap();
+
+    let task = FutureObj::new(Box::new(future::lazy(move |_| {
+        tx.send(5).unwrap();
+    })));
+
+    // The executor refuses the task, which is handed back instead of being
+    // dropped or panicking.
+    let err = spawn.spawn_obj(task).unwrap_err();
+    assert!(err.kind.is_shutdown());
+
+    block_on(err.future);
+    assert_eq!(block_on(rx), Ok(5));
+}
@@ -0,0 +1,78 @@
+#![feature(pin, arbitrary_self_types, futures_api, async_await, await_macro)]
+
+use futures::executor::block_on;
+use futures::future::{self, Either, Future, FutureExt};
+use futures::stream::{self, Stream, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+use std::boxed::PinBox;
+
+fn pick_future(left: bool) -> impl Future<Output = u32> {
+    if left {
+        // The future of an `async` block isn't `Unpin`.
+        async { 1 }.left_future()
+    } else {
+        future::ready(2).right_future()
+    }
+}
+
+fn pick_stream(left: bool) -> impl Stream<Item = u32> {
+    if left {
+        stream::unfold(0, |n| async move {
+            if n < 2 { Some((n, n + 1)) } else { None }
+        }).left_stream()
+    } else {
+        stream::iter(vec![10, 11, 12]).right_stream()
+    }
+}
+
+#[test]
+fn future_picks_branch() {
+    assert_eq!(block_on(pick_future(true)), 1);
+    assert_eq!(block_on(pick_future(false)), 2);
+}
+
+#[test]
+fn future_with_pinned_variant_across_polls() {
+    let (tx, rx) = futures::channel::oneshot::channel::<u32>();
+    let future = async { await!(rx).unwrap() + 1 }
+        .left_future::<future::Ready<u32>>();
+    let mut future = PinBox::new(future);
+
+    let cx = &mut noop_context();
+    assert_eq!(future.as_pin_mut().poll(cx), Poll::Pending);
+    tx.send(41).unwrap();
+    assert_eq!(future.as_pin_mut().poll(cx), Poll::Ready(42));
+}
+
+#[test]
+fn stream_picks_branch() {
+    assert_eq!(block_on(pick_stream(true).collect::<Vec<_>>()), vec![0, 1]);
+    assert_eq!(block_on(pick_stream(false).collect::<Vec<_>>()), vec![10, 11, 12]);
+}
+
+#[test]
+fn stream_size_hint_of_active_variant() {
+    let stream = stream::iter(vec![1, 2, 3])
+        .left_stream::<stream::Empty<i32>>();
+    assert_eq!(stream.size_hint(), (3, Some(3)));
+
+    let stream = stream::empty::<i32>()
+        .right_stream::<stream::Iter<std::vec::IntoIter<i32>>>();
+    assert_eq!(stream.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn factor_first_and_second() {
+    let either: Either<(u8, &str), (u8, bool)> = Either::Left((1, "a"));
+    match either.factor_first() {
+        (1, Either::Left("a")) => {}
+        _ => panic!("unexpected factoring"),
+    }
+
+    let either: Either<(&str, u8), (bool, u8)> = Either::Right((true, 2));
+    match either.factor_second() {
+        (2, Either::Right(true)) => {}
+        _ => panic!("unexpected factoring"),
+    }
+}
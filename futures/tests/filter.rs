@@ -0,0 +1,42 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on;
+use futures::future;
+use futures::stream::{self, StreamExt};
+
+fn words() -> Vec<&'static str> {
+    vec!["a", "futures", "is", "a", "stream", "of", "values"]
+}
+
+#[test]
+fn filter_sync_matches_filter() {
+    let sync = stream::iter(words()).filter_sync(|x| x.len() > 3);
+    let not_sync = stream::iter(words()).filter(|x| future::ready(x.len() > 3));
+
+    let sync = block_on(sync.collect::<Vec<_>>());
+    assert_eq!(sync, vec!["futures", "stream", "values"]);
+    assert_eq!(sync, block_on(not_sync.collect::<Vec<_>>()));
+}
+
+#[test]
+fn filter_map_sync_matches_filter_map() {
+    let parse = |x: &str| if x.len() > 3 { Some(x.len()) } else { None };
+    let sync = stream::iter(words()).filter_map_sync(parse);
+    let not_sync = stream::iter(words()).filter_map(|x| future::ready(parse(x)));
+
+    let sync = block_on(sync.collect::<Vec<_>>());
+    assert_eq!(sync, vec![7, 6, 6]);
+    assert_eq!(sync, block_on(not_sync.collect::<Vec<_>>()));
+}
+
+#[test]
+fn filter_sync_size_hint() {
+    let stream = stream::iter(words()).filter_sync(|x| x.len() > 3);
+    assert_eq!(stream.size_hint(), (0, Some(7)));
+
+    let stream = stream::iter(words()).filter_map_sync(Some);
+    assert_eq!(stream.size_hint(), (0, Some(7)));
+
+    let stream = stream::repeat(1).filter_sync(|_| true);
+    assert_eq!(stream.size_hint(), (0, None));
+}
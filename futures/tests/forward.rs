@@ -0,0 +1,333 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on;
+use futures::future::Future;
+use futures::sink::Sink;
+use futures::stream::{self, StreamExt};
+use futures::task::{self, Poll, Waker};
+use futures_test::sink::SinkTestExt;
+use futures_test::stream::StreamTestExt;
+use futures_test::task::{new_count_waker, panic_context};
+use pin_utils::pin_mut;
+use std::cell::RefCell;
+use std::marker::Pinned;
+use std::mem::PinMut;
+use std::rc::Rc;
+
+/// The shared state of a `ManualSink`, which only accepts an item after it's
+/// made ready by the test.
+#[derive(Default)]
+struct ManualState {
+    items: Vec<i32>,
+    ready: bool,
+    waker: Option<Waker>,
+}
+
+impl ManualState {
+    fn set_ready(&mut self) {
+        self.ready = true;
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// A sink which accepts two items, and then fails either in `poll_ready` or
+/// in `start_send`.
+struct FailingSink {
+    items: Vec<i32>,
+    fail_in_start_send: bool,
+}
+
+impl Sink for FailingSink {
+    type SinkItem = i32;
+    type SinkError = &'static str;
+
+    fn poll_ready(
+        self: PinMut<Self>,
+        _: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        if self.items.len() == 2 && !self.fail_in_start_send {
+            Poll::Ready(Err("not ready"))
+        } else {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    fn start_send(
+        mut self: PinMut<Self>,
+        item: Self::SinkItem,
+    ) -> Result<(), Self::SinkError> {
+        if self.items.len() == 2 {
+            return Err("send failed");
+        }
+        self.items.push(item);
+        Ok(())
+    }
+
+    fn poll_flush(
+        self: PinMut<Self>,
+        _: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(
+        self: PinMut<Self>,
+        _: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// A sink which is deliberately `!Unpin`, and records the items it receives in
+/// a shared `Vec`.
+struct PinnedSink {
+    items: Rc<RefCell<Vec<i32>>>,
+    closed: Rc<RefCell<bool>>,
+    _pinned: Pinned,
+}
+
+impl Sink for PinnedSink {
+    type SinkItem = i32;
+    type SinkError = ();
+
+    fn poll_ready(
+        self: PinMut<Self>,
+        _: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(
+        self: PinMut<Self>,
+        item: Self::SinkItem,
+    ) -> Result<(), Self::SinkError> {
+        self.items.borrow_mut().push(item);
+        Ok(())
+    }
+
+    fn poll_flush(
+        self: PinMut<Self>,
+        _: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(
+        self: PinMut<Self>,
+        _: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        *self.closed.borrow_mut() = true;
+        Poll::Ready(Ok(()))
+    }
+}
+
+struct ManualSink(Rc<RefCell<ManualState>>);
+
+impl Sink for ManualSink {
+    type SinkItem = i32;
+    type SinkError = ();
+
+    fn poll_ready(
+        self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        let mut state = self.0.borrow_mut();
+        if state.ready {
+            Poll::Ready(Ok(()))
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+
+    fn start_send(
+        self: PinMut<Self>,
+        item: Self::SinkItem,
+    ) -> Result<(), Self::SinkError> {
+        let mut state = self.0.borrow_mut();
+        state.items.push(item);
+        state.ready = false;
+        Ok(())
+    }
+
+    fn poll_flush(
+        self: PinMut<Self>,
+        _: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(
+        self: PinMut<Self>,
+        _: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[test]
+fn forward_to_pending_sink() {
+    let stream = stream::iter((0..10).map(Ok));
+    let sink = Vec::new().interleave_pending_sink();
+
+    // Every `poll_ready` is pending first, so every item has to be buffered
+    // by `Forward` and sent on the next poll.
+    let sink = block_on(stream.forward(sink)).unwrap();
+    assert_eq!(sink.into_inner(), (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn forward_from_pending_stream_to_pending_sink() {
+    let stream = stream::iter((0..10).map(Ok)).interleave_pending();
+    let sink = Vec::new().interleave_pending_sink();
+
+    // Every other poll of the stream is pending as well, which makes
+    // `Forward` flush the sink in between items.
+    let sink = block_on(stream.forward(sink)).unwrap();
+    assert_eq!(sink.into_inner(), (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn forward_wakes_once_when_sink_becomes_ready() {
+    let state = Rc::new(RefCell::new(ManualState::default()));
+    let forward = stream::iter((0..2).map(Ok)).forward(ManualSink(state.clone()));
+    pin_mut!(forward);
+
+    let (local_waker, count) = new_count_waker();
+    let mut cx = panic_context();
+    let cx = &mut cx.with_waker(&local_waker);
+
+    // The sink isn't ready, so the first item is buffered and nothing wakes
+    // the task until the sink does.
+    assert!(forward.reborrow().poll(cx).is_pending());
+    assert!(forward.reborrow().poll(cx).is_pending());
+    assert_eq!(count, 0);
+
+    state.borrow_mut().set_ready();
+    assert_eq!(count, 1);
+
+    assert!(forward.reborrow().poll(cx).is_pending());
+    assert_eq!(state.borrow().items, vec![0]);
+    assert_eq!(count, 1);
+
+    state.borrow_mut().set_ready();
+    assert_eq!(count, 2);
+
+    assert!(forward.reborrow().poll(cx).is_ready());
+    assert_eq!(state.borrow().items, vec![0, 1]);
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn forward_keeps_item_when_sink_is_not_ready() {
+    let sink = FailingSink { items: Vec::new(), fail_in_start_send: false };
+    let mut forward = stream::iter((0..5).map(Ok)).forward(sink);
+
+    assert_eq!(block_on(&mut forward).err(), Some("not ready"));
+
+    let (item, stream, sink) = forward.into_parts();
+    assert_eq!(item, Some(2));
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![Ok(3), Ok(4)]);
+    assert_eq!(sink.unwrap().items, vec![0, 1]);
+}
+
+#[test]
+fn forward_recovers_stream_when_start_send_fails() {
+    let sink = FailingSink { items: Vec::new(), fail_in_start_send: true };
+    let mut forward = stream::iter((0..5).map(Ok))
+        .forward(sink)
+        .recover_failed_sends();
+
+    assert_eq!(block_on(&mut forward).err(), Some("send failed"));
+
+    // The sink dropped the third item, but a clone of it was kept.
+    let (item, stream, sink) = forward.into_parts();
+    assert_eq!(item, Some(2));
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![Ok(3), Ok(4)]);
+    assert_eq!(sink.unwrap().items, vec![0, 1]);
+}
+
+#[test]
+fn forward_into_pinned_sink() {
+    let items = Rc::new(RefCell::new(Vec::new()));
+    let closed = Rc::new(RefCell::new(false));
+    let sink = PinnedSink {
+        items: items.clone(),
+        closed: closed.clone(),
+        _pinned: Pinned,
+    };
+
+    let stream = stream::iter((0..5).map(Ok::<i32, ()>)).interleave_pending();
+    block_on(stream.forward_into(sink)).unwrap();
+
+    assert_eq!(*items.borrow(), vec![0, 1, 2, 3, 4]);
+    assert!(*closed.borrow());
+}
+
+#[test]
+fn forward_into_stops_at_stream_error() {
+    let items = Rc::new(RefCell::new(Vec::new()));
+    let closed = Rc::new(RefCell::new(false));
+    let sink = PinnedSink {
+        items: items.clone(),
+        closed: closed.clone(),
+        _pinned: Pinned,
+    };
+
+    let stream = stream::iter(vec![Ok(0), Ok(1), Err(()), Ok(2)]);
+    assert_eq!(block_on(stream.forward_into(sink)), Err(()));
+
+    assert_eq!(*items.borrow(), vec![0, 1]);
+    assert!(!*closed.borrow());
+}
+
+/// An error of a stream, which converts into the error of `FailingSink`.
+#[derive(Debug)]
+struct StreamError;
+
+impl From<StreamError> for &'static str {
+    fn from(_: StreamError) -> &'static str {
+        "stream failed"
+    }
+}
+
+#[test]
+fn forward_infers_matching_error_type() {
+    // The error type of the stream is only known from the sink.
+    let sink = block_on(stream::iter(vec![Ok(1)]).forward(Vec::new()));
+    assert_eq!(sink.unwrap_or_else(|e| e.into_any()), vec![1]);
+
+    let sink = FailingSink { items: Vec::new(), fail_in_start_send: false };
+    // With `forward_err_into` the error type has to be known already, and
+    // may be the error type of the sink itself.
+    let stream = stream::iter(vec![Ok::<i32, &'static str>(1)]);
+    let sink = block_on(stream.forward_err_into(sink));
+    assert_eq!(sink.unwrap().items, vec![1]);
+}
+
+#[test]
+fn forward_err_into_converts_stream_error() {
+    let sink = FailingSink { items: Vec::new(), fail_in_start_send: false };
+    let stream = stream::iter(vec![Ok(0), Err(StreamError), Ok(1)]);
+    let mut forward = stream.forward_err_into(sink);
+
+    let result: Result<FailingSink, &'static str> = block_on(&mut forward);
+    assert_eq!(result.err(), Some("stream failed"));
+
+    let (item, stream, sink) = forward.into_parts();
+    assert_eq!(item, None);
+    assert_eq!(block_on(stream.map(|r| r.is_ok()).collect::<Vec<_>>()), vec![true]);
+    assert_eq!(sink.unwrap().items, vec![0]);
+}
+
+#[test]
+fn forward_into_converts_stream_error() {
+    let mut sink = FailingSink { items: Vec::new(), fail_in_start_send: false };
+    let stream = stream::iter(vec![Ok(0), Err(StreamError), Ok(1)]);
+
+    let result = block_on(stream.forward_into(&mut sink));
+    assert_eq!(result, Err("stream failed"));
+    assert_eq!(sink.items, vec![0]);
+}
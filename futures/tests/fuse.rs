@@ -1,7 +1,14 @@
 #![feature(pin, arbitrary_self_types, futures_api)]
 
 use futures::future::{self, FutureExt};
-use futures_test::task::panic_context;
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::future::FutureTestExt;
+use futures_test::task::{new_count_waker, panic_context};
+use futures_test::{
+    assert_stream_pending, assert_stream_next, assert_stream_done,
+};
+use pin_utils::pin_mut;
 
 #[test]
 fn fuse() {
@@ -10,3 +17,43 @@ fn fuse() {
     assert!(future.poll_unpin(cx).is_ready());
     assert!(future.poll_unpin(cx).is_pending());
 }
+
+#[test]
+fn completed_fuse_does_not_wake() {
+    let (local_waker, count) = new_count_waker();
+    let mut cx = panic_context();
+    let cx = &mut cx.with_waker(&local_waker);
+
+    let mut future = future::ready(1).fuse();
+    assert!(future.poll_unpin(cx).is_ready());
+    assert!(future.poll_unpin(cx).is_pending());
+    assert!(future.poll_unpin(cx).is_pending());
+    assert_eq!(count, 0);
+
+    let mut stream = stream::iter(1..=1).fuse();
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(1)));
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(None));
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(None));
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn fuse_stream() {
+    let stream = stream::iter(1..=2)
+        .then(|x| future::ready(x).pending_once())
+        .fuse();
+    pin_mut!(stream);
+
+    assert_stream_pending!(stream);
+    assert_stream_next!(stream, 1);
+    assert_stream_pending!(stream);
+    assert_stream_next!(stream, 2);
+    assert!(!stream.is_done());
+    assert_stream_done!(stream);
+    assert!(stream.is_done());
+
+    // Polling after the end keeps returning `None` without touching the
+    // inner stream.
+    assert_stream_done!(stream);
+    assert_stream_done!(stream);
+}
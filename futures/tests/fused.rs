@@ -0,0 +1,152 @@
+#![feature(pin, arbitrary_self_types, futures_api, async_await, await_macro)]
+
+use futures::channel::{mpsc, oneshot};
+use futures::executor::block_on;
+use futures::future::{self, FusedFuture, FutureExt};
+use futures::never::Never;
+use futures::select;
+use futures::stream::{self, FusedStream, FuturesUnordered, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+
+#[test]
+fn oneshot_receiver_terminates_with_value() {
+    let (tx, mut rx) = oneshot::channel::<i32>();
+    let cx = &mut noop_context();
+
+    assert_eq!(rx.poll_unpin(cx), Poll::Pending);
+    assert!(!rx.is_terminated());
+    tx.send(1).unwrap();
+    assert!(!rx.is_terminated());
+    assert_eq!(rx.poll_unpin(cx), Poll::Ready(Ok(1)));
+    assert!(rx.is_terminated());
+}
+
+#[test]
+fn oneshot_receiver_terminates_with_cancellation() {
+    let (tx, mut rx) = oneshot::channel::<i32>();
+    drop(tx);
+    assert!(!rx.is_terminated());
+    assert!(rx.try_recv().is_err());
+    assert!(rx.is_terminated());
+}
+
+#[test]
+fn mpsc_receiver_terminates_after_none() {
+    let (mut tx, mut rx) = mpsc::channel::<i32>(1);
+    let cx = &mut noop_context();
+
+    tx.try_send(1).unwrap();
+    drop(tx);
+    assert!(!rx.is_terminated());
+    assert_eq!(rx.poll_next_unpin(cx), Poll::Ready(Some(1)));
+    assert!(!rx.is_terminated());
+    assert_eq!(rx.poll_next_unpin(cx), Poll::Ready(None));
+    assert!(rx.is_terminated());
+
+    let (tx, mut rx) = mpsc::unbounded::<i32>();
+    assert_eq!(rx.poll_next_unpin(cx), Poll::Pending);
+    assert!(!rx.is_terminated());
+    drop(tx);
+    assert!(!rx.is_terminated());
+    assert_eq!(rx.poll_next_unpin(cx), Poll::Ready(None));
+    assert!(rx.is_terminated());
+}
+
+#[test]
+fn mpsc_receiver_terminates_after_close() {
+    let (mut tx, mut rx) = mpsc::channel::<i32>(1);
+    let cx = &mut noop_context();
+
+    tx.try_send(1).unwrap();
+    rx.close();
+    // Closing alone doesn't end the stream, the buffered message and the
+    // final `None` are still to be received.
+    assert!(!rx.is_terminated());
+    assert_eq!(rx.poll_next_unpin(cx), Poll::Ready(Some(1)));
+    assert!(!rx.is_terminated());
+    assert_eq!(rx.poll_next_unpin(cx), Poll::Ready(None));
+    assert!(rx.is_terminated());
+
+    let (_tx, mut rx) = mpsc::unbounded::<i32>();
+    rx.close();
+    assert!(!rx.is_terminated());
+    assert_eq!(rx.poll_next_unpin(cx), Poll::Ready(None));
+    assert!(rx.is_terminated());
+}
+
+#[test]
+fn futures_unordered_terminates_until_pushed() {
+    let mut set = FuturesUnordered::new();
+    let cx = &mut noop_context();
+
+    set.push(future::ready(1));
+    assert!(!set.is_terminated());
+    assert_eq!(set.poll_next_unpin(cx), Poll::Ready(Some(1)));
+    assert!(!set.is_terminated());
+    assert_eq!(set.poll_next_unpin(cx), Poll::Ready(None));
+    assert!(set.is_terminated());
+
+    set.push(future::ready(2));
+    assert!(!set.is_terminated());
+    assert_eq!(set.poll_next_unpin(cx), Poll::Ready(Some(2)));
+}
+
+#[test]
+fn stream_future_terminates_with_item() {
+    let mut future = stream::iter(vec![1, 2]).into_future();
+    assert!(!future.is_terminated());
+    let (item, _) = block_on(&mut future);
+    assert_eq!(item, Some(1));
+    assert!(future.is_terminated());
+}
+
+#[test]
+fn forward_terminates_when_sink_is_returned() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let stream = stream::once(rx.map(|x| Ok::<i32, Never>(x.unwrap())));
+    let mut forward = stream.forward(Vec::new());
+    let cx = &mut noop_context();
+
+    assert!(forward.poll_unpin(cx).is_pending());
+    assert!(!forward.is_terminated());
+    tx.send(1).unwrap();
+    match forward.poll_unpin(cx) {
+        Poll::Ready(Ok(vec)) => assert_eq!(vec, vec![1]),
+        _ => panic!("forward didn't complete"),
+    }
+    assert!(forward.is_terminated());
+}
+
+#[test]
+fn constructors() {
+    assert!(future::empty::<i32>().is_terminated());
+    assert!(stream::empty::<i32>().is_terminated());
+
+    let mut once = stream::once(future::ready(1));
+    let cx = &mut noop_context();
+    assert!(!once.is_terminated());
+    assert_eq!(once.poll_next_unpin(cx), Poll::Ready(Some(1)));
+    assert!(once.is_terminated());
+}
+
+#[test]
+fn select_skips_terminated_receivers() {
+    let (tx1, mut rx1) = oneshot::channel::<i32>();
+    let (tx2, mut rx2) = oneshot::channel::<i32>();
+    tx1.send(1).unwrap();
+    tx2.send(2).unwrap();
+
+    let total = block_on(async {
+        let mut total = 0;
+        loop {
+            select! {
+                x = rx1 => total += x.unwrap(),
+                x = rx2 => total += x.unwrap(),
+                complete => break,
+            }
+        }
+        total
+    });
+    assert_eq!(total, 3);
+}
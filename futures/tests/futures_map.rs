@@ -0,0 +1,70 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::stream::{FuturesMap, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+
+#[test]
+fn replace_under_same_key() {
+    let (tx1, rx1) = oneshot::channel::<i32>();
+    let (tx2, rx2) = oneshot::channel::<i32>();
+    let mut map = FuturesMap::new();
+
+    assert!(!map.insert(1, rx1));
+    assert!(map.insert(1, rx2));
+    assert_eq!(map.len(), 1);
+
+    // The replaced future was dropped.
+    assert!(tx1.is_canceled());
+
+    tx2.send(2).unwrap();
+    assert_eq!(block_on(map.next()), Some((1, Ok(2))));
+    assert!(map.is_empty());
+    assert_eq!(block_on(map.next()), None);
+}
+
+#[test]
+fn remove_while_pending() {
+    let (tx_a, rx_a) = oneshot::channel::<i32>();
+    let (tx_b, rx_b) = oneshot::channel::<i32>();
+    let (tx_c, rx_c) = oneshot::channel::<i32>();
+    let mut map = FuturesMap::new();
+    map.insert("a", rx_a);
+    map.insert("b", rx_b);
+    map.insert("c", rx_c);
+
+    let cx = &mut noop_context();
+    assert_eq!(map.poll_next_unpin(cx), Poll::Pending);
+
+    assert!(map.remove(&"c"));
+    assert!(tx_c.is_canceled());
+    assert!(!map.remove(&"c"));
+
+    // "a" is woken before it's removed, which must not make the set poll it
+    // or yield its output.
+    tx_a.send(1).unwrap();
+    assert!(map.remove(&"a"));
+    assert!(!map.contains(&"a"));
+    assert!(map.contains(&"b"));
+    assert_eq!(map.len(), 1);
+    assert_eq!(map.poll_next_unpin(cx), Poll::Pending);
+
+    tx_b.send(2).unwrap();
+    assert_eq!(map.poll_next_unpin(cx), Poll::Ready(Some(("b", Ok(2)))));
+    assert!(!map.contains(&"b"));
+    assert_eq!(map.poll_next_unpin(cx), Poll::Ready(None));
+}
+
+#[test]
+fn insert_after_completion() {
+    let mut map = FuturesMap::new();
+    map.insert(1, futures::future::ready("first"));
+    assert_eq!(block_on(map.next()), Some((1, "first")));
+
+    // The key is free again once its future completed.
+    assert!(!map.insert(1, futures::future::ready("second")));
+    assert_eq!(block_on(map.next()), Some((1, "second")));
+    assert_eq!(block_on(map.next()), None);
+}
@@ -0,0 +1,97 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on;
+use futures::io::{AllowStdIo, AsyncBufReadExt, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use futures::task::Poll;
+use futures_test::task::no_spawn_context;
+use std::io::{self, BufRead, Cursor, Read, Write};
+
+/// A blocking writer that is interrupted before every write and counts
+/// flushes.
+#[derive(Debug, Default)]
+struct InterruptedWriter {
+    data: Vec<u8>,
+    interrupt: bool,
+    flushes: usize,
+}
+
+impl Write for InterruptedWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.interrupt = !self.interrupt;
+        if self.interrupt {
+            return Err(io::Error::new(io::ErrorKind::Interrupted, "interrupted"));
+        }
+        self.data.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.flushes += 1;
+        Ok(())
+    }
+}
+
+#[test]
+fn copy_between_std_types() {
+    let data: Vec<u8> = (0..100_000).map(|i| i as u8).collect();
+    let mut reader = AllowStdIo::new(Cursor::new(data.clone()));
+    let mut writer = AllowStdIo::new(InterruptedWriter::default());
+
+    let copied = block_on(reader.copy_into(&mut writer)).unwrap();
+
+    assert_eq!(copied, data.len() as u64);
+    assert_eq!(writer.get_ref().data, data);
+    assert_eq!(writer.get_ref().flushes, 1);
+    assert_eq!(reader.get_ref().position(), data.len() as u64);
+}
+
+#[test]
+fn would_block_is_not_pending() {
+    struct WouldBlock;
+
+    impl Write for WouldBlock {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::WouldBlock.into())
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut writer = AllowStdIo::new(WouldBlock);
+    let res = writer.poll_write(&mut no_spawn_context(), b"x");
+    match res {
+        Poll::Ready(Err(e)) => assert_eq!(e.kind(), io::ErrorKind::WouldBlock),
+        _ => panic!("expected a WouldBlock error"),
+    }
+}
+
+#[test]
+fn buf_read() {
+    let mut reader = AllowStdIo::new(Cursor::new(&b"one\ntwo\n"[..]));
+    let mut line = String::new();
+
+    block_on(AsyncBufReadExt::read_line(&mut reader, &mut line)).unwrap();
+    assert_eq!(line, "one\n");
+    assert_eq!(BufRead::fill_buf(&mut reader).unwrap(), b"two\n");
+}
+
+#[test]
+fn std_passthrough() {
+    let mut rw = AllowStdIo::new(Cursor::new(Vec::new()));
+
+    Write::write_all(&mut rw, b"hello").unwrap();
+    block_on(AsyncWriteExt::write_all(&mut rw, b" world")).unwrap();
+    rw.get_mut().set_position(0);
+
+    let mut s = String::new();
+    Read::read_to_string(&mut rw, &mut s).unwrap();
+    assert_eq!(s, "hello world");
+
+    rw.get_mut().set_position(6);
+    let mut buf = [0; 5];
+    block_on(AsyncReadExt::read_exact(&mut rw, &mut buf)).unwrap();
+    assert_eq!(&buf, b"world");
+
+    assert_eq!(rw.into_inner().into_inner(), b"hello world");
+}
@@ -0,0 +1,143 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on;
+use futures::io::{AsyncBufReadExt, AsyncReadExt};
+use futures::stream::StreamExt;
+use futures_test::io::{AsyncReadTestExt, InterleavePending, MockReader};
+use std::io;
+use std::string::FromUtf8Error;
+
+/// A buffered reader that hands out its data in chunks of at most `chunk`
+/// bytes and is pending before every fill.
+fn chunked_buf_reader(data: &[u8], chunk: usize) -> InterleavePending<MockReader> {
+    MockReader::new(data).with_chunk_size(chunk).interleave_pending()
+}
+
+#[test]
+fn read_until_across_chunks() {
+    let mut reader = chunked_buf_reader(b"lorem ipsum dolor", 2);
+    let mut buf = Vec::new();
+
+    assert_eq!(block_on(reader.read_until(b' ', &mut buf)).unwrap(), 6);
+    assert_eq!(buf, b"lorem ");
+
+    assert_eq!(block_on(reader.read_until(b' ', &mut buf)).unwrap(), 6);
+    assert_eq!(buf, b"lorem ipsum ");
+
+    // No delimiter before EOF
+    assert_eq!(block_on(reader.read_until(b' ', &mut buf)).unwrap(), 5);
+    assert_eq!(buf, b"lorem ipsum dolor");
+
+    assert_eq!(block_on(reader.read_until(b' ', &mut buf)).unwrap(), 0);
+    assert_eq!(buf, b"lorem ipsum dolor");
+}
+
+#[test]
+fn read_until_does_not_consume_past_delimiter() {
+    let mut reader = chunked_buf_reader(b"a,bc", 4);
+    let mut buf = Vec::new();
+
+    assert_eq!(block_on(reader.read_until(b',', &mut buf)).unwrap(), 2);
+    assert_eq!(buf, b"a,");
+
+    let mut rest = [0; 4];
+    assert_eq!(block_on(reader.read(&mut rest)).unwrap(), 2);
+    assert_eq!(&rest[..2], b"bc");
+}
+
+#[test]
+fn read_line_across_chunks() {
+    let mut reader = chunked_buf_reader(b"hello\nworld\r\nend", 3);
+    let mut buf = String::new();
+
+    assert_eq!(block_on(reader.read_line(&mut buf)).unwrap(), 6);
+    assert_eq!(buf, "hello\n");
+    buf.clear();
+
+    assert_eq!(block_on(reader.read_line(&mut buf)).unwrap(), 7);
+    assert_eq!(buf, "world\r\n");
+    buf.clear();
+
+    assert_eq!(block_on(reader.read_line(&mut buf)).unwrap(), 3);
+    assert_eq!(buf, "end");
+    buf.clear();
+
+    assert_eq!(block_on(reader.read_line(&mut buf)).unwrap(), 0);
+    assert_eq!(buf, "");
+}
+
+#[test]
+fn read_line_appends_multibyte_characters_split_across_chunks() {
+    let mut reader = chunked_buf_reader("grüße\n".as_bytes(), 1);
+    let mut buf = String::from("> ");
+
+    assert_eq!(block_on(reader.read_line(&mut buf)).unwrap(), 8);
+    assert_eq!(buf, "> grüße\n");
+}
+
+#[test]
+fn read_line_invalid_utf8() {
+    let mut reader = chunked_buf_reader(b"ab\xffcd\nok\n", 2);
+    let mut buf = String::from("kept");
+
+    let err = block_on(reader.read_line(&mut buf)).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert_eq!(buf, "kept");
+
+    // The bytes of the invalid line are not lost.
+    let err = err.into_inner().unwrap().downcast::<FromUtf8Error>().unwrap();
+    assert_eq!(err.into_bytes(), b"ab\xffcd\n");
+
+    // The reader is positioned at the next line.
+    assert_eq!(block_on(reader.read_line(&mut buf)).unwrap(), 3);
+    assert_eq!(buf, "keptok\n");
+}
+
+#[test]
+fn lines_strips_line_endings() {
+    let reader = chunked_buf_reader(b"one\ntwo\r\n\nthree", 4);
+
+    let lines: Vec<String> = block_on(reader.lines().collect::<Vec<_>>())
+        .into_iter()
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(lines, vec!["one", "two", "", "three"]);
+}
+
+#[test]
+fn lines_ends_at_eof() {
+    let reader = chunked_buf_reader(b"a\nb\n", 1);
+
+    let lines: Vec<String> = block_on(reader.lines().collect::<Vec<_>>())
+        .into_iter()
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(lines, vec!["a", "b"]);
+
+    let reader = chunked_buf_reader(b"", 1);
+    assert!(block_on(reader.lines().collect::<Vec<_>>()).is_empty());
+}
+
+#[test]
+fn lines_continues_after_invalid_utf8() {
+    let reader = chunked_buf_reader(b"a\n\xff\nb", 2);
+
+    let lines = block_on(reader.lines().collect::<Vec<_>>());
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0].as_ref().unwrap(), "a");
+    assert_eq!(lines[1].as_ref().unwrap_err().kind(), io::ErrorKind::InvalidData);
+    assert_eq!(lines[2].as_ref().unwrap(), "b");
+}
+
+#[test]
+fn cursor_and_slice_are_buf_read() {
+    let mut cursor = io::Cursor::new(&b"x\ny"[..]);
+    let mut buf = String::new();
+    assert_eq!(block_on(cursor.read_line(&mut buf)).unwrap(), 2);
+    assert_eq!(buf, "x\n");
+
+    let mut slice: &[u8] = b"1;2";
+    let mut buf = Vec::new();
+    assert_eq!(block_on(slice.read_until(b';', &mut buf)).unwrap(), 2);
+    assert_eq!(slice, b"2");
+}
@@ -0,0 +1,102 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on;
+use futures::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, BufReader};
+use futures::task::Poll;
+use futures_test::io::MockReader;
+use futures_test::task::no_spawn_context;
+
+#[test]
+fn small_reads_are_coalesced() {
+    let data: Vec<u8> = (0..100).collect();
+    let mut reader = BufReader::with_capacity(32, MockReader::new(&data));
+
+    let mut out = Vec::new();
+    let mut buf = [0; 4];
+    loop {
+        let n = block_on(reader.read(&mut buf)).unwrap();
+        if n == 0 {
+            break;
+        }
+        out.extend_from_slice(&buf[..n]);
+    }
+
+    assert_eq!(out, data);
+    // 4 reads of 32 bytes or less, plus the one that returns EOF.
+    assert_eq!(reader.get_ref().reads(), 5);
+}
+
+#[test]
+fn large_reads_bypass_buffer() {
+    let data: Vec<u8> = (0..100).collect();
+    let mut reader = BufReader::with_capacity(8, MockReader::new(&data));
+
+    let mut small = [0; 2];
+    block_on(reader.read_exact(&mut small)).unwrap();
+    assert_eq!(small, [0, 1]);
+    assert_eq!(reader.get_ref().reads(), 1);
+
+    // The buffered bytes are returned first.
+    let mut large = [0; 16];
+    assert_eq!(block_on(reader.read(&mut large)).unwrap(), 6);
+    assert_eq!(&large[..6], &[2, 3, 4, 5, 6, 7]);
+    assert_eq!(reader.get_ref().reads(), 1);
+
+    // With an empty buffer, the read goes straight to the inner reader.
+    assert_eq!(block_on(reader.read(&mut large)).unwrap(), 16);
+    assert_eq!(large[0], 8);
+    assert_eq!(reader.get_ref().reads(), 2);
+    assert!(reader.buffer().is_empty());
+}
+
+fn fill_buf(reader: &mut impl AsyncBufRead) -> Vec<u8> {
+    match reader.poll_fill_buf(&mut no_spawn_context()) {
+        Poll::Ready(Ok(buf)) => buf.to_vec(),
+        Poll::Ready(Err(e)) => panic!("fill_buf failed: {}", e),
+        Poll::Pending => panic!("fill_buf is pending"),
+    }
+}
+
+#[test]
+fn fill_buf_and_consume() {
+    let mut reader = BufReader::with_capacity(4, MockReader::new(b"abcdef"));
+
+    assert_eq!(fill_buf(&mut reader), b"abcd");
+    // Filling a non-empty buffer doesn't touch the inner reader.
+    assert_eq!(fill_buf(&mut reader), b"abcd");
+    assert_eq!(reader.get_ref().reads(), 1);
+
+    reader.consume(3);
+    assert_eq!(reader.buffer(), b"d");
+    reader.consume(1);
+    assert_eq!(fill_buf(&mut reader), b"ef");
+    reader.consume(2);
+    assert_eq!(fill_buf(&mut reader), b"");
+    assert_eq!(reader.get_ref().reads(), 3);
+}
+
+#[test]
+fn read_line_through_buf_reader() {
+    let data = b"first line\nsecond line\n";
+    let mut reader = BufReader::with_capacity(64, MockReader::new(data));
+    let mut line = String::new();
+
+    block_on(reader.read_line(&mut line)).unwrap();
+    assert_eq!(line, "first line\n");
+    line.clear();
+    block_on(reader.read_line(&mut line)).unwrap();
+    assert_eq!(line, "second line\n");
+
+    // Both lines came out of a single read.
+    assert_eq!(reader.get_ref().reads(), 1);
+}
+
+#[test]
+fn into_inner_returns_reader() {
+    let mut reader = BufReader::new(MockReader::new(b"xyz"));
+    assert_eq!(fill_buf(&mut reader), b"xyz");
+
+    let inner = reader.into_inner();
+    assert_eq!(inner.reads(), 1);
+    assert!(inner.remaining().is_empty());
+}
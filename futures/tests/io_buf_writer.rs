@@ -0,0 +1,79 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on;
+use futures::io::{AsyncWriteExt, BufWriter, ErrorKind};
+use futures_test::io::MockWriter;
+
+#[test]
+fn small_writes_are_coalesced() {
+    let mut writer = BufWriter::with_capacity(16, MockWriter::new());
+
+    for i in 0..40u8 {
+        block_on(writer.write_all(&[i])).unwrap();
+    }
+    // Only full buffers have been written out so far.
+    assert_eq!(writer.get_ref().writes(), 2);
+    assert_eq!(writer.get_ref().written().len(), 32);
+    assert_eq!(writer.buffer().len(), 8);
+
+    block_on(writer.flush()).unwrap();
+    assert_eq!(writer.get_ref().writes(), 3);
+    assert_eq!(writer.get_ref().flushes(), 1);
+    assert_eq!(writer.get_ref().written(), &(0..40).collect::<Vec<u8>>()[..]);
+}
+
+#[test]
+fn large_writes_bypass_buffer() {
+    let mut writer = BufWriter::with_capacity(8, MockWriter::new());
+
+    block_on(writer.write_all(b"ab")).unwrap();
+    assert_eq!(writer.get_ref().writes(), 0);
+
+    // The buffered bytes are written out first, then the large write goes
+    // straight to the inner writer.
+    block_on(writer.write_all(b"0123456789")).unwrap();
+    assert_eq!(writer.get_ref().writes(), 2);
+    assert_eq!(writer.get_ref().written(), b"ab0123456789");
+    assert!(writer.buffer().is_empty());
+}
+
+#[test]
+fn close_flushes_buffer_first() {
+    let mut writer = BufWriter::new(MockWriter::new());
+
+    block_on(writer.write_all(b"hello")).unwrap();
+    assert!(writer.get_ref().written().is_empty());
+
+    block_on(writer.close()).unwrap();
+    assert_eq!(writer.get_ref().written(), b"hello");
+    assert_eq!(writer.get_ref().writes(), 1);
+    assert!(writer.get_ref().is_closed());
+}
+
+#[test]
+fn into_inner_flushes_buffer() {
+    let mut writer = BufWriter::new(MockWriter::new());
+
+    block_on(writer.write_all(b"hello ")).unwrap();
+    block_on(writer.write_all(b"world")).unwrap();
+
+    let inner = block_on(writer.into_inner()).unwrap();
+    assert_eq!(inner.written(), b"hello world");
+    assert_eq!(inner.writes(), 1);
+}
+
+#[test]
+fn into_inner_error_returns_writer() {
+    let mut writer = BufWriter::new(MockWriter::new().fail_on_write(1));
+    block_on(writer.write_all(b"kept")).unwrap();
+
+    let err = block_on(writer.into_inner()).unwrap_err();
+    assert_eq!(err.error().kind(), ErrorKind::Other);
+
+    // The buffered data wasn't lost.
+    let mut writer = err.into_inner();
+    assert_eq!(writer.buffer(), b"kept");
+
+    let inner = block_on(writer.into_inner()).unwrap();
+    assert_eq!(inner.written(), b"kept");
+}
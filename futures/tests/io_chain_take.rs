@@ -0,0 +1,119 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on;
+use futures::io::{AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncReadExt};
+use futures::task::Poll;
+use futures_test::io::{AsyncReadTestExt, InterleavePending, MockReader};
+use futures_test::task::no_spawn_context;
+
+/// A socket-like reader that is pending before every read and returns at
+/// most `chunk` bytes per read.
+fn mock_socket(data: &[u8], chunk: usize) -> InterleavePending<MockReader> {
+    MockReader::new(data).with_chunk_size(chunk).interleave_pending()
+}
+
+fn read_all<R: AsyncRead>(reader: &mut R, buf_len: usize) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut buf = vec![0; buf_len];
+    loop {
+        let n = block_on(reader.read(&mut buf)).unwrap();
+        if n == 0 {
+            return out;
+        }
+        out.extend_from_slice(&buf[..n]);
+    }
+}
+
+#[test]
+fn chain_then_take() {
+    let prefix: &[u8] = b"HEAD";
+    let socket = mock_socket(b"0123456789abcdef", 3);
+
+    let mut reader = prefix.chain(socket).take(10);
+    assert_eq!(read_all(&mut reader, 5), b"HEAD012345");
+    assert_eq!(reader.limit(), 0);
+
+    // The rest of the socket is still there.
+    let (_, socket) = reader.into_inner().into_inner();
+    assert_eq!(socket.get_ref().remaining(), b"6789abcdef");
+}
+
+#[test]
+fn chain_first_eof_at_buffer_boundary() {
+    // The prefix exactly fills the first read, the EOF of the prefix is only
+    // observed on the second read.
+    let prefix: &[u8] = b"abcd";
+    let mut reader = prefix.chain(mock_socket(b"efgh", 4));
+
+    let mut buf = [0; 4];
+    assert_eq!(block_on(reader.read(&mut buf)).unwrap(), 4);
+    assert_eq!(&buf, b"abcd");
+    assert_eq!(block_on(reader.read(&mut buf)).unwrap(), 4);
+    assert_eq!(&buf, b"efgh");
+    assert_eq!(block_on(reader.read(&mut buf)).unwrap(), 0);
+}
+
+#[test]
+fn chain_empty_read_does_not_skip_first() {
+    let prefix: &[u8] = b"ab";
+    let mut reader = prefix.chain(mock_socket(b"cd", 4));
+
+    assert_eq!(block_on(reader.read(&mut [])).unwrap(), 0);
+    assert_eq!(read_all(&mut reader, 1), b"abcd");
+}
+
+#[test]
+fn chain_get_ref_and_mut() {
+    let mut reader = (&b"ab"[..]).chain(&b"cd"[..]);
+    assert_eq!(reader.get_ref(), (&&b"ab"[..], &&b"cd"[..]));
+    *reader.get_mut().1 = &b"xy"[..];
+    assert_eq!(read_all(&mut reader, 8), b"abxy");
+}
+
+#[test]
+fn take_short_reads() {
+    let mut reader = mock_socket(&[7; 100], 3).take(10);
+
+    // Reads of 3, 3 and 3 bytes, then just the 1 byte left within the limit.
+    assert_eq!(read_all(&mut reader, 8), [7; 10]);
+    assert_eq!(reader.get_ref().get_ref().reads(), 4);
+    assert_eq!(reader.get_ref().get_ref().remaining().len(), 90);
+
+    // At the limit the inner reader isn't touched anymore.
+    let mut buf = [0; 4];
+    assert_eq!(block_on(reader.read(&mut buf)).unwrap(), 0);
+    assert_eq!(reader.get_ref().get_ref().reads(), 4);
+
+    reader.set_limit(2);
+    assert_eq!(read_all(&mut reader, 8), [7; 2]);
+}
+
+#[test]
+fn take_inner_eof_before_limit() {
+    let mut reader = mock_socket(b"abc", 2).take(10);
+    assert_eq!(read_all(&mut reader, 8), b"abc");
+    assert_eq!(reader.limit(), 7);
+}
+
+#[test]
+fn buf_read_through_chain_and_take() {
+    let first: &[u8] = b"one\ntw";
+    let second: &[u8] = b"o\nthree\n";
+    let mut reader = first.chain(second).take(12);
+
+    let mut line = String::new();
+    block_on(reader.read_line(&mut line)).unwrap();
+    assert_eq!(line, "one\n");
+    line.clear();
+    block_on(reader.read_line(&mut line)).unwrap();
+    assert_eq!(line, "two\n");
+    line.clear();
+    // The limit cuts the last line short.
+    block_on(reader.read_line(&mut line)).unwrap();
+    assert_eq!(line, "thre");
+
+    match reader.poll_fill_buf(&mut no_spawn_context()) {
+        Poll::Ready(Ok(buf)) => assert!(buf.is_empty()),
+        _ => panic!("fill_buf should be ready"),
+    }
+}
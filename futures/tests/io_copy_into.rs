@@ -0,0 +1,85 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on;
+use futures::future::FutureExt;
+use futures::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, BufReader};
+use futures::task::Poll;
+use futures_test::io::{
+    AsyncReadTestExt, AsyncWriteTestExt, InterleavePending, MockReader, MockWriter,
+};
+use futures_test::task::no_spawn_context;
+
+/// A reader that yields `len` bytes of a repeating pattern, at most `chunk`
+/// bytes per read, and is pending before every read.
+fn pattern_reader(len: usize, chunk: usize) -> InterleavePending<MockReader> {
+    let data: Vec<u8> = (0..len).map(pattern).collect();
+    MockReader::new(&data).with_chunk_size(chunk).interleave_pending()
+}
+
+fn pattern(i: usize) -> u8 {
+    (i % 251) as u8
+}
+
+/// A writer that accepts at most `chunk` bytes per write and is pending
+/// before every call.
+fn small_writer(chunk: usize) -> InterleavePending<MockWriter> {
+    MockWriter::new().with_chunk_size(chunk).interleave_pending_write()
+}
+
+#[test]
+fn copy_multi_megabyte_through_small_writes() {
+    const LEN: usize = 3 * 1024 * 1024 + 17;
+
+    let mut reader = pattern_reader(LEN, 10_000);
+    let mut writer = small_writer(7);
+
+    let copied = block_on(reader.copy_into(&mut writer)).unwrap();
+
+    assert_eq!(copied, LEN as u64);
+    let writer = writer.get_ref();
+    assert_eq!(writer.flushes(), 1);
+    assert!(!writer.is_closed());
+    assert_eq!(writer.written().len(), LEN);
+    assert!(writer.written().iter().enumerate().all(|(i, &byte)| byte == pattern(i)));
+}
+
+#[test]
+fn copy_buf_multi_megabyte_through_small_writes() {
+    const LEN: usize = 3 * 1024 * 1024 + 17;
+
+    let reader = pattern_reader(LEN, 10_000);
+    let mut reader = BufReader::new(reader);
+    let mut writer = small_writer(7);
+
+    let copied = block_on(reader.copy_buf_into(&mut writer)).unwrap();
+
+    assert_eq!(copied, LEN as u64);
+    let writer = writer.get_ref();
+    assert_eq!(writer.flushes(), 1);
+    assert!(!writer.is_closed());
+    assert_eq!(writer.written().len(), LEN);
+    assert!(writer.written().iter().enumerate().all(|(i, &byte)| byte == pattern(i)));
+}
+
+#[test]
+fn copy_buf_only_consumes_written_bytes() {
+    let mut reader: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    let mut writer = small_writer(3);
+
+    {
+        // The second poll writes 3 bytes, the call after that is pending.
+        let mut copy = reader.copy_buf_into(&mut writer);
+        assert!(copy.poll_unpin(&mut no_spawn_context()).is_pending());
+        assert!(copy.poll_unpin(&mut no_spawn_context()).is_pending());
+    }
+    assert_eq!(writer.get_ref().written(), [1, 2, 3]);
+    assert_eq!(reader, [4, 5, 6, 7, 8, 9, 10]);
+
+    let copied = block_on(reader.copy_buf_into(&mut writer)).unwrap();
+    assert_eq!(copied, 7);
+    assert_eq!(writer.get_ref().written(), [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    match reader.poll_fill_buf(&mut no_spawn_context()) {
+        Poll::Ready(Ok(buf)) => assert!(buf.is_empty()),
+        _ => panic!("fill_buf should be ready"),
+    }
+}
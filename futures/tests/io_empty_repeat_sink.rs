@@ -0,0 +1,68 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on;
+use futures::io::{
+    self, AsyncBufRead, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt,
+    IoVec,
+};
+use futures::task::Poll;
+use futures_test::task::no_spawn_context;
+use std::marker::Unpin;
+
+fn assert_unpin<T: Unpin>(_: &T) {}
+
+#[test]
+fn empty_is_always_at_eof() {
+    let mut empty = io::empty();
+    assert_unpin(&empty);
+    let cx = &mut no_spawn_context();
+
+    let mut buf = [1; 4];
+    assert_eq!(empty.poll_read(cx, &mut buf).map(Result::unwrap), Poll::Ready(0));
+    assert_eq!(buf, [1; 4]);
+    match empty.poll_fill_buf(cx) {
+        Poll::Ready(Ok(buf)) => assert!(buf.is_empty()),
+        _ => panic!("fill_buf should be ready"),
+    }
+    empty.consume(0);
+
+    let mut out = Vec::new();
+    assert_eq!(block_on(empty.read_to_end(&mut out)).unwrap(), 0);
+    assert_eq!(format!("{:?}", empty), "Empty { .. }");
+}
+
+#[test]
+fn repeat_fills_every_buffer() {
+    let mut repeat = io::repeat(7);
+    assert_unpin(&repeat);
+    let cx = &mut no_spawn_context();
+
+    let mut buf = [0; 1000];
+    assert_eq!(repeat.poll_read(cx, &mut buf).map(Result::unwrap), Poll::Ready(1000));
+    assert!(buf.iter().all(|&b| b == 7));
+
+    let (mut a, mut b) = ([0; 2], [0; 3]);
+    {
+        let mut vec: [&mut IoVec; 2] = [(&mut a[..]).into(), (&mut b[..]).into()];
+        assert_eq!(repeat.poll_vectored_read(cx, &mut vec).map(Result::unwrap), Poll::Ready(5));
+    }
+    assert_eq!((a, b), ([7; 2], [7; 3]));
+    assert_eq!(format!("{:?}", repeat), "Repeat { .. }");
+}
+
+#[test]
+fn sink_accepts_everything() {
+    let mut sink = io::sink();
+    assert_unpin(&sink);
+    let cx = &mut no_spawn_context();
+
+    assert_eq!(sink.poll_write(cx, &[0; 100]).map(Result::unwrap), Poll::Ready(100));
+    let (a, b) = ([0; 2], [0; 3]);
+    let vec: [&IoVec; 2] = [(&a[..]).into(), (&b[..]).into()];
+    assert_eq!(sink.poll_vectored_write(cx, &vec).map(Result::unwrap), Poll::Ready(5));
+    assert_eq!(sink.poll_flush(cx).map(Result::unwrap), Poll::Ready(()));
+
+    block_on(sink.write_all(&[1; 10_000])).unwrap();
+    block_on(sink.close()).unwrap();
+    assert_eq!(format!("{:?}", sink), "Sink { .. }");
+}
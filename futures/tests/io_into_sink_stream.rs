@@ -0,0 +1,96 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on;
+use futures::io::{AsyncReadExt, AsyncWriteExt};
+use futures::sink::SinkExt;
+use futures::stream::{self, StreamExt};
+use futures_test::io::{
+    AsyncReadTestExt, AsyncWriteTestExt, InterleavePending, MockReader, MockWriter,
+};
+use std::io;
+
+/// A writer that is pending before every write and accepts at most `chunk`
+/// bytes per write.
+fn slow_writer(chunk: usize) -> InterleavePending<MockWriter> {
+    MockWriter::new().with_chunk_size(chunk).interleave_pending_write()
+}
+
+#[test]
+fn forward_stream_into_writer() {
+    let byte_stream = stream::iter(vec![
+        Ok(b"hello".to_vec()),
+        Ok(Vec::new()),
+        Ok(b", world".to_vec()),
+    ]);
+    let writer = slow_writer(3);
+
+    let sink = block_on(byte_stream.forward(writer.into_sink())).unwrap();
+
+    let writer = sink.into_inner();
+    assert_eq!(writer.get_ref().written(), b"hello, world");
+    assert!(writer.get_ref().is_closed());
+}
+
+#[test]
+fn forward_stops_at_error() {
+    let byte_stream = stream::iter(vec![
+        Ok(b"ab".to_vec()),
+        Err(io::Error::new(io::ErrorKind::Other, "oops")),
+        Ok(b"cd".to_vec()),
+    ]);
+    let mut writer = slow_writer(1);
+
+    let err = block_on(byte_stream.forward((&mut writer).into_sink())).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::Other);
+    // Nothing after the error makes it into the writer.
+    assert!(!writer.get_ref().written().ends_with(b"cd"));
+    assert!(!writer.get_ref().is_closed());
+}
+
+#[test]
+fn sink_writes_each_item_fully() {
+    let mut sink = slow_writer(2).into_sink();
+
+    block_on(sink.send("first;")).unwrap();
+    block_on(sink.send("second")).unwrap();
+    assert_eq!(sink.into_inner().get_ref().written(), b"first;second");
+}
+
+#[test]
+fn sink_zero_write_is_an_error() {
+    let mut sink = slow_writer(0).into_sink();
+
+    let err = block_on(sink.send(vec![1, 2, 3])).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+}
+
+#[test]
+fn stream_yields_chunks_until_eof() {
+    let reader = &b"0123456789"[..];
+    let chunks: Vec<Vec<u8>> = block_on(reader.into_stream(4).map(Result::unwrap).collect());
+    assert_eq!(chunks, [b"0123".to_vec(), b"4567".to_vec(), b"89".to_vec()]);
+}
+
+#[test]
+fn stream_passes_errors_through() {
+    // The second read fails.
+    let reader = MockReader::new(b"abcdefg")
+        .with_chunk_size(3)
+        .fail_on_read(2)
+        .interleave_pending();
+
+    let items = block_on(reader.into_stream(8).collect::<Vec<_>>());
+    let items: Vec<_> = items.into_iter().map(|r| r.map_err(|e| e.kind())).collect();
+    assert_eq!(items, [
+        Ok(b"abc".to_vec()),
+        Err(io::ErrorKind::Other),
+        Ok(b"def".to_vec()),
+        Ok(b"g".to_vec()),
+    ]);
+}
+
+#[test]
+#[should_panic(expected = "chunk size must be non-zero")]
+fn stream_zero_chunk_size_panics() {
+    let _ = (&b""[..]).into_stream(0);
+}
@@ -1,7 +1,17 @@
 #![feature(pin, arbitrary_self_types, futures_api)]
 
 use futures::executor::block_on;
+use futures::future::FutureExt;
 use futures::io::AsyncReadExt;
+use futures_test::io::{AsyncReadTestExt, InterleavePending, MockReader};
+use futures_test::task::no_spawn_context;
+use std::io;
+
+/// A reader that returns at most `chunk` bytes per read and is pending before
+/// every read.
+fn chunked_reader(data: &[u8], chunk: usize) -> InterleavePending<MockReader> {
+    MockReader::new(data).with_chunk_size(chunk).interleave_pending()
+}
 
 #[test]
 fn read_exact() {
@@ -17,3 +27,84 @@ fn read_exact() {
     assert!(res.is_err());
     assert_eq!(reader.len(), 0);
 }
+
+#[test]
+fn read_exact_chunked() {
+    let mut reader = chunked_reader(&(0..10).collect::<Vec<u8>>(), 3);
+    let mut out = [0u8; 8];
+
+    block_on(reader.read_exact(&mut out)).unwrap();
+    assert_eq!(out, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+    // The reader is still usable afterwards.
+    let mut rest = [0u8; 2];
+    block_on(reader.read_exact(&mut rest)).unwrap();
+    assert_eq!(rest, [8, 9]);
+}
+
+#[test]
+fn read_exact_eof_reports_bytes_read() {
+    let mut reader = chunked_reader(&[1, 2, 3, 4, 5], 2);
+    let mut out = [0u8; 8];
+
+    let err = block_on(reader.read_exact(&mut out)).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    assert!(err.to_string().contains("5 bytes"));
+    assert_eq!(&out[..5], [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn read_exact_empty_buf() {
+    let mut reader = chunked_reader(&[], 2);
+    block_on(reader.read_exact(&mut [])).unwrap();
+}
+
+#[test]
+fn read_exact_dropped_keeps_read_bytes() {
+    let mut reader = chunked_reader(&[1, 2, 3, 4, 5], 2);
+    let mut out = [0u8; 5];
+    let cx = &mut no_spawn_context();
+
+    {
+        let mut fut = reader.read_exact(&mut out);
+        assert!(fut.poll_unpin(cx).is_pending());
+        assert!(fut.poll_unpin(cx).is_pending());
+    }
+    assert_eq!(out, [1, 2, 0, 0, 0]);
+
+    block_on(reader.read_exact(&mut out[2..])).unwrap();
+    assert_eq!(out, [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn read_to_end_chunked() {
+    let mut reader = chunked_reader(&(0..100).collect::<Vec<u8>>(), 7);
+    let mut out = vec![255];
+
+    let n = block_on(reader.read_to_end(&mut out)).unwrap();
+    assert_eq!(n, 100);
+    assert_eq!(out.len(), 101);
+    assert_eq!(out[0], 255);
+    assert_eq!(&out[1..], &(0..100).collect::<Vec<u8>>()[..]);
+
+    // Reading again at EOF appends nothing.
+    assert_eq!(block_on(reader.read_to_end(&mut out)).unwrap(), 0);
+    assert_eq!(out.len(), 101);
+}
+
+#[test]
+fn read_to_end_dropped_keeps_read_bytes() {
+    let mut reader = chunked_reader(&[1, 2, 3, 4, 5], 2);
+    let mut out = Vec::new();
+    let cx = &mut no_spawn_context();
+
+    {
+        let mut fut = reader.read_to_end(&mut out);
+        assert!(fut.poll_unpin(cx).is_pending());
+        assert!(fut.poll_unpin(cx).is_pending());
+    }
+    assert_eq!(out, [1, 2]);
+
+    assert_eq!(block_on(reader.read_to_end(&mut out)).unwrap(), 3);
+    assert_eq!(out, [1, 2, 3, 4, 5]);
+}
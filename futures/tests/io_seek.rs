@@ -0,0 +1,138 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on;
+use futures::io::{
+    AsyncBufRead, AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, BufReader,
+    SeekFrom,
+};
+use futures::task::{self, Poll};
+use futures_test::task::no_spawn_context;
+use std::io::{self, Cursor};
+
+/// An in-memory reader that is pending before every read and seek, and counts
+/// the seeks that went through.
+struct PendingCursor {
+    inner: Cursor<Vec<u8>>,
+    pending: bool,
+    seeks: usize,
+}
+
+impl PendingCursor {
+    fn new(data: Vec<u8>) -> PendingCursor {
+        PendingCursor { inner: Cursor::new(data), pending: false, seeks: 0 }
+    }
+
+    fn poll_pending(&mut self, cx: &mut task::Context) -> Poll<()> {
+        self.pending = !self.pending;
+        if self.pending {
+            cx.waker().wake();
+            Poll::Pending
+        } else {
+            Poll::Ready(())
+        }
+    }
+}
+
+impl AsyncRead for PendingCursor {
+    fn poll_read(&mut self, cx: &mut task::Context, buf: &mut [u8])
+        -> Poll<io::Result<usize>>
+    {
+        if self.poll_pending(cx).is_pending() {
+            return Poll::Pending;
+        }
+        self.inner.poll_read(cx, buf)
+    }
+}
+
+impl AsyncSeek for PendingCursor {
+    fn poll_seek(&mut self, cx: &mut task::Context, pos: SeekFrom)
+        -> Poll<io::Result<u64>>
+    {
+        if self.poll_pending(cx).is_pending() {
+            return Poll::Pending;
+        }
+        self.seeks += 1;
+        self.inner.poll_seek(cx, pos)
+    }
+}
+
+fn read_byte<R: AsyncRead>(reader: &mut R) -> u8 {
+    let mut buf = [0];
+    block_on(reader.read_exact(&mut buf)).unwrap();
+    buf[0]
+}
+
+#[test]
+fn seek_cursor() {
+    let mut cursor = Cursor::new((0..10).collect::<Vec<u8>>());
+
+    assert_eq!(block_on(cursor.seek(SeekFrom::Start(3))).unwrap(), 3);
+    assert_eq!(read_byte(&mut cursor), 3);
+    assert_eq!(block_on(cursor.seek(SeekFrom::Current(2))).unwrap(), 6);
+    assert_eq!(read_byte(&mut cursor), 6);
+    assert_eq!(block_on(cursor.seek(SeekFrom::End(-1))).unwrap(), 9);
+    assert_eq!(read_byte(&mut cursor), 9);
+    assert!(block_on(cursor.seek(SeekFrom::Current(-20))).is_err());
+}
+
+#[test]
+fn seek_buf_reader_back_and_forth() {
+    let data: Vec<u8> = (0..100).collect();
+    let mut reader = BufReader::with_capacity(8, PendingCursor::new(data));
+
+    assert_eq!(read_byte(&mut reader), 0);
+    // The inner reader is at 8, but relative seeks are relative to the
+    // logical position.
+    assert_eq!(block_on(reader.seek(SeekFrom::Current(2))).unwrap(), 3);
+    assert_eq!(read_byte(&mut reader), 3);
+    assert_eq!(read_byte(&mut reader), 4);
+
+    assert_eq!(block_on(reader.seek(SeekFrom::Current(-5))).unwrap(), 0);
+    assert_eq!(read_byte(&mut reader), 0);
+
+    assert_eq!(block_on(reader.seek(SeekFrom::Start(50))).unwrap(), 50);
+    assert_eq!(read_byte(&mut reader), 50);
+
+    assert_eq!(block_on(reader.seek(SeekFrom::End(-10))).unwrap(), 90);
+    let mut rest = Vec::new();
+    block_on(reader.read_to_end(&mut rest)).unwrap();
+    assert_eq!(rest, (90..100).collect::<Vec<u8>>());
+
+    // Every seek was pending once and then went through exactly once.
+    assert_eq!(reader.get_ref().seeks, 4);
+}
+
+#[test]
+fn seek_discards_buffer() {
+    let data: Vec<u8> = (0..20).collect();
+    let mut reader = BufReader::with_capacity(8, PendingCursor::new(data));
+
+    let cx = &mut no_spawn_context();
+    while reader.poll_fill_buf(cx).is_pending() {}
+    assert_eq!(reader.buffer(), &[0, 1, 2, 3, 4, 5, 6, 7]);
+
+    assert_eq!(block_on(reader.seek(SeekFrom::Current(0))).unwrap(), 0);
+    assert!(reader.buffer().is_empty());
+    // The inner reader is at the logical position after the seek.
+    let inner = reader.into_inner();
+    assert_eq!(inner.inner.position(), 0);
+}
+
+#[test]
+fn pending_seek_keeps_buffer() {
+    let data: Vec<u8> = (0..20).collect();
+    let mut reader = BufReader::with_capacity(8, PendingCursor::new(data));
+    assert_eq!(read_byte(&mut reader), 0);
+
+    let cx = &mut no_spawn_context();
+    assert!(reader.poll_seek(cx, SeekFrom::Current(1)).is_pending());
+    assert_eq!(reader.buffer().len(), 7);
+
+    // Retrying with the same position seeks relative to the position before
+    // the pending seek.
+    match reader.poll_seek(cx, SeekFrom::Current(1)) {
+        Poll::Ready(Ok(pos)) => assert_eq!(pos, 2),
+        _ => panic!("seek did not complete"),
+    }
+    assert_eq!(read_byte(&mut reader), 2);
+}
@@ -0,0 +1,168 @@
+#![feature(async_await, await_macro, pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::mpsc;
+use futures::executor::{block_on, ThreadPool};
+use futures::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use futures::stream::StreamExt;
+use futures::task::{self, Poll, SpawnExt, Waker};
+use std::collections::VecDeque;
+use std::io;
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Default)]
+struct Pipe {
+    buf: VecDeque<u8>,
+    closed: bool,
+    waker: Option<Waker>,
+}
+
+/// One end of an in-memory connection, reads from one pipe and writes into
+/// the other.
+#[derive(Debug)]
+struct Duplex {
+    read: Arc<Mutex<Pipe>>,
+    write: Arc<Mutex<Pipe>>,
+}
+
+fn duplex() -> (Duplex, Duplex) {
+    let a = Arc::new(Mutex::new(Pipe::default()));
+    let b = Arc::new(Mutex::new(Pipe::default()));
+    (
+        Duplex { read: a.clone(), write: b.clone() },
+        Duplex { read: b, write: a },
+    )
+}
+
+impl AsyncRead for Duplex {
+    fn poll_read(&mut self, cx: &mut task::Context, buf: &mut [u8])
+        -> Poll<io::Result<usize>>
+    {
+        let mut pipe = self.read.lock().unwrap();
+        if pipe.buf.is_empty() {
+            if pipe.closed {
+                return Poll::Ready(Ok(0));
+            }
+            pipe.waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+        let n = buf.len().min(pipe.buf.len());
+        for (dst, src) in buf.iter_mut().zip(pipe.buf.drain(..n)) {
+            *dst = src;
+        }
+        Poll::Ready(Ok(n))
+    }
+}
+
+impl AsyncWrite for Duplex {
+    fn poll_write(&mut self, _: &mut task::Context, buf: &[u8])
+        -> Poll<io::Result<usize>>
+    {
+        let mut pipe = self.write.lock().unwrap();
+        // Small writes, so that the halves contend for the lock.
+        let n = buf.len().min(7);
+        pipe.buf.extend(&buf[..n]);
+        if let Some(waker) = pipe.waker.take() {
+            waker.wake();
+        }
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(&mut self, _: &mut task::Context) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(&mut self, _: &mut task::Context) -> Poll<io::Result<()>> {
+        let mut pipe = self.write.lock().unwrap();
+        pipe.closed = true;
+        if let Some(waker) = pipe.waker.take() {
+            waker.wake();
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[test]
+fn concurrent_echo() {
+    let data: Vec<u8> = (0..10_000).map(|i| i as u8).collect();
+    let mut pool = ThreadPool::new().unwrap();
+    let (client, server) = duplex();
+
+    // The server echoes everything back, reading and writing on different
+    // tasks through the two halves of its end.
+    let (mut server_read, mut server_write) = server.split();
+    let (tx, rx) = mpsc::unbounded::<Vec<u8>>();
+    pool.spawn(async move {
+        let mut buf = [0; 64];
+        loop {
+            let n = await!(server_read.read(&mut buf)).unwrap();
+            if n == 0 {
+                break;
+            }
+            tx.unbounded_send(buf[..n].to_vec()).unwrap();
+        }
+    }).unwrap();
+    let echo = pool.spawn_with_handle(async move {
+        let mut rx = rx;
+        while let Some(chunk) = await!(rx.next()) {
+            await!(server_write.write_all(&chunk)).unwrap();
+        }
+        await!(server_write.close()).unwrap();
+    }).unwrap();
+
+    let (mut client_read, mut client_write) = client.split();
+    let written = {
+        let data = data.clone();
+        pool.spawn_with_handle(async move {
+            await!(client_write.write_all(&data)).unwrap();
+            await!(client_write.close()).unwrap();
+            client_write
+        }).unwrap()
+    };
+    let read = pool.spawn_with_handle(async move {
+        let mut out = Vec::new();
+        await!(client_read.read_to_end(&mut out)).unwrap();
+        (client_read, out)
+    }).unwrap();
+
+    let client_write = block_on(written);
+    let (client_read, out) = block_on(read);
+    block_on(echo);
+    assert_eq!(out, data);
+    assert!(client_read.reunite(client_write).is_ok());
+}
+
+#[test]
+fn reunite() {
+    let (a, _b) = duplex();
+    let (read, write) = a.split();
+
+    let a = read.reunite(write).unwrap();
+    let (read, write) = a.split();
+    assert!(write.reunite(read).is_ok());
+}
+
+#[test]
+fn reunite_mismatch() {
+    let (a, b) = duplex();
+    let (a_read, a_write) = a.split();
+    let (b_read, b_write) = b.split();
+
+    let err = a_read.reunite(b_write).unwrap_err();
+    let (a_read, b_write) = (err.0, err.1);
+    let err = b_read.reunite(a_write).unwrap_err();
+    let (b_read, a_write) = (err.0, err.1);
+
+    // The halves are still usable after a failed reunite.
+    assert!(a_read.reunite(a_write).is_ok());
+    assert!(b_write.reunite(b_read).is_ok());
+}
+
+#[test]
+fn halves_are_send() {
+    fn assert_send<T: Send>(_: &T) {}
+
+    let (a, _b) = duplex();
+    let (read, write) = a.split();
+    assert_send(&read);
+    assert_send(&write);
+}
@@ -0,0 +1,68 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on;
+use futures::future::FutureExt;
+use futures::io::AsyncWriteExt;
+use futures_test::io::{AsyncWriteTestExt, InterleavePending, MockWriter};
+use futures_test::task::no_spawn_context;
+use std::io;
+
+/// A writer that accepts at most `chunk` bytes per write and is pending
+/// before every operation.
+fn chunked_writer(chunk: usize) -> InterleavePending<MockWriter> {
+    MockWriter::new().with_chunk_size(chunk).interleave_pending_write()
+}
+
+#[test]
+fn write_all_partial_writes() {
+    let mut writer = chunked_writer(3);
+    let data: Vec<u8> = (0..20).collect();
+
+    block_on(writer.write_all(&data)).unwrap();
+    assert_eq!(writer.get_ref().written(), &data[..]);
+
+    // The writer is still usable afterwards.
+    block_on(writer.write_all(&[20, 21])).unwrap();
+    assert_eq!(writer.get_ref().written(), &(0..22).collect::<Vec<u8>>()[..]);
+}
+
+#[test]
+fn write_all_does_not_resend_bytes() {
+    let mut writer = chunked_writer(3);
+    let data = [1, 2, 3, 4, 5, 6, 7];
+    let cx = &mut no_spawn_context();
+
+    {
+        let mut fut = writer.write_all(&data);
+        assert!(fut.poll_unpin(cx).is_pending());
+        assert!(fut.poll_unpin(cx).is_pending());
+        assert!(fut.poll_unpin(cx).is_pending());
+        assert!(fut.poll_unpin(cx).is_ready());
+    }
+    assert_eq!(writer.get_ref().written(), data);
+}
+
+#[test]
+fn write_all_write_zero() {
+    let mut writer = chunked_writer(0);
+
+    let err = block_on(writer.write_all(&[1, 2])).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+
+    // An empty buffer never calls into the writer.
+    block_on(writer.write_all(&[])).unwrap();
+}
+
+#[test]
+fn flush_and_close() {
+    let mut writer = chunked_writer(3);
+
+    block_on(writer.write_all(&[1, 2, 3, 4])).unwrap();
+    block_on(writer.flush()).unwrap();
+    assert_eq!(writer.get_ref().flushes(), 1);
+    assert!(!writer.get_ref().is_closed());
+
+    block_on(writer.close()).unwrap();
+    assert!(writer.get_ref().is_closed());
+    assert_eq!(writer.get_ref().written(), [1, 2, 3, 4]);
+}
@@ -0,0 +1,87 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on;
+use futures::io::{AsyncWrite, AsyncWriteExt, BufWriter, IoVec};
+use futures_test::io::{AsyncWriteTestExt, InterleavePending, MockWriter};
+use std::io;
+
+/// A writer that accepts a different, small number of bytes on every call,
+/// spread across as many buffers as needed, and is pending every other call.
+fn trickling_writer(limits: Vec<usize>) -> InterleavePending<MockWriter> {
+    MockWriter::new().with_write_limits(limits).interleave_pending_write()
+}
+
+fn iovecs(bufs: &[&'static [u8]]) -> Vec<&'static IoVec> {
+    bufs.iter().map(|buf| IoVec::from_bytes(buf).unwrap()).collect()
+}
+
+#[test]
+fn partial_writes_across_buffer_boundaries() {
+    let bufs = iovecs(&[b"header:", b"a", b"payload-bytes", b"xy", b";"]);
+    let expected = b"header:apayload-bytesxy;";
+
+    for budgets in vec![vec![1], vec![2, 3], vec![5, 1, 8], vec![7], vec![100]] {
+        let mut writer = trickling_writer(budgets.clone());
+        block_on(writer.write_vectored_all(&bufs)).unwrap();
+        assert_eq!(writer.get_ref().written(), &expected[..], "budgets: {:?}", budgets);
+    }
+}
+
+#[test]
+fn single_call_when_writer_accepts_everything() {
+    let bufs = iovecs(&[b"abc", b"def"]);
+    let mut writer = trickling_writer(vec![usize::max_value()]);
+
+    block_on(writer.write_vectored_all(&bufs)).unwrap();
+    assert_eq!(writer.get_ref().written(), b"abcdef");
+    assert_eq!(writer.get_ref().writes(), 1);
+}
+
+#[test]
+fn empty_list_is_a_noop() {
+    let mut writer = trickling_writer(vec![1]);
+    block_on(writer.write_vectored_all(&[])).unwrap();
+    assert_eq!(writer.get_ref().writes(), 0);
+}
+
+#[test]
+fn zero_write_is_an_error() {
+    let bufs = iovecs(&[b"abc"]);
+    let mut writer = trickling_writer(vec![2, 0]);
+
+    let err = block_on(writer.write_vectored_all(&bufs)).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+    assert_eq!(writer.get_ref().written(), b"ab");
+}
+
+#[test]
+fn through_buf_writer() {
+    let bufs = iovecs(&[b"0123", b"4567", b"89"]);
+    let mut writer = BufWriter::with_capacity(4, trickling_writer(vec![3]));
+
+    // The buffer is too small for the whole list, so it is written to the
+    // inner writer directly.
+    block_on(writer.write_vectored_all(&bufs)).unwrap();
+    block_on(writer.flush()).unwrap();
+    assert_eq!(writer.get_ref().get_ref().written(), b"0123456789");
+}
+
+#[test]
+fn through_mut_ref_and_box() {
+    fn write<W: AsyncWrite>(mut writer: W, bufs: &[&IoVec]) {
+        block_on(writer.write_vectored_all(bufs)).unwrap();
+    }
+
+    let bufs = iovecs(&[b"ab", b"cd"]);
+
+    let mut writer = trickling_writer(vec![3]);
+    write(&mut writer, &bufs);
+    assert_eq!(writer.get_ref().written(), b"abcd");
+    assert_eq!(writer.get_ref().writes(), 2);
+
+    let mut writer = Box::new(trickling_writer(vec![3]));
+    write(&mut *writer, &bufs);
+    write(&mut writer, &bufs);
+    assert_eq!(writer.get_ref().written(), b"abcdabcd");
+    assert_eq!(writer.get_ref().writes(), 4);
+}
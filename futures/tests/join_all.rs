@@ -0,0 +1,44 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future::{self, join_all, Future, FutureExt, JoinAll};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+use pin_utils::pin_mut;
+
+#[test]
+fn join_all_collects_outputs_in_order() {
+    let (tx1, rx1) = oneshot::channel::<i32>();
+    let (tx2, rx2) = oneshot::channel::<i32>();
+    let mut join = join_all(vec![rx1, rx2]);
+    let cx = &mut noop_context();
+
+    assert_eq!(join.poll_unpin(cx), Poll::Pending);
+    tx2.send(2).unwrap();
+    assert_eq!(join.poll_unpin(cx), Poll::Pending);
+    tx1.send(1).unwrap();
+    assert_eq!(join.poll_unpin(cx), Poll::Ready(vec![Ok(1), Ok(2)]));
+}
+
+#[test]
+fn join_all_empty() {
+    let join: JoinAll<future::Ready<i32>> = Vec::new().into_iter().collect();
+    assert_eq!(block_on(join), Vec::<i32>::new());
+}
+
+#[test]
+fn maybe_done_polled_after_output_taken() {
+    let future = future::maybe_done(future::ready(5));
+    pin_mut!(future);
+    let cx = &mut noop_context();
+
+    assert_eq!(future.reborrow().output_mut(), None);
+    assert_eq!(future.reborrow().poll(cx), Poll::Ready(()));
+    *future.reborrow().output_mut().unwrap() += 1;
+    assert_eq!(future.reborrow().take_output(), Some(6));
+
+    assert_eq!(future.reborrow().poll(cx), Poll::Ready(()));
+    assert_eq!(future.reborrow().output_mut(), None);
+    assert_eq!(future.reborrow().take_output(), None);
+}
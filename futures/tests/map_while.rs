@@ -0,0 +1,67 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::task::panic_context;
+use std::rc::Rc;
+
+#[test]
+fn map_while_first_item_none() {
+    let stream = stream::iter(vec!["x", "1", "2"])
+        .map_while(|s| s.parse::<i32>().ok());
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), Vec::<i32>::new());
+}
+
+#[test]
+fn map_while_never_none() {
+    let stream = stream::iter(1..=3).map_while(|x| Some(x * 2));
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![2, 4, 6]);
+}
+
+#[test]
+fn map_while_drops_stream_after_none() {
+    let resource = Rc::new(());
+    let held = resource.clone();
+    let mut polls = 0;
+    let inner = stream::poll_fn(move |_| {
+        let _held = &held;
+        polls += 1;
+        assert!(polls <= 2, "inner stream polled after termination");
+        Poll::Ready(Some(polls))
+    });
+
+    let mut stream = inner.map_while(|x| if x < 2 { Some(x) } else { None });
+    let cx = &mut panic_context();
+
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(1)));
+    assert_eq!(Rc::strong_count(&resource), 2);
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(None));
+    assert_eq!(Rc::strong_count(&resource), 1);
+
+    assert!(stream.get_ref().is_none());
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(None));
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(None));
+}
+
+#[test]
+fn map_while_drops_stream_after_it_ends() {
+    let resource = Rc::new(());
+    let held = resource.clone();
+    let mut ended = false;
+    let inner = stream::poll_fn(move |_| {
+        let _held = &held;
+        assert!(!ended, "inner stream polled after termination");
+        ended = true;
+        Poll::Ready(None::<i32>)
+    });
+
+    let mut stream = inner.map_while(Some);
+    let cx = &mut panic_context();
+
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(None));
+    assert_eq!(Rc::strong_count(&resource), 1);
+
+    assert!(stream.get_ref().is_none());
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(None));
+}
@@ -0,0 +1,52 @@
+#![feature(pin, arbitrary_self_types, futures_api, async_await, await_macro)]
+
+use futures::channel::mpsc::{self, SendError};
+use futures::executor::block_on;
+use futures::future::{self, FutureExt, TryFutureExt};
+use futures::never::Never;
+use futures::sink::{self, SinkExt};
+use futures::stream::{self, StreamExt, TryStreamExt};
+use std::io;
+
+#[test]
+fn infallible_stream_forwarded_into_fallible_sink() {
+    let (tx, rx) = mpsc::unbounded();
+    let stream = stream::iter(vec![1, 2, 3])
+        .map(Ok::<i32, Never>)
+        .err_into::<SendError>();
+
+    assert!(block_on(stream.forward(tx)).is_ok());
+    assert_eq!(block_on(rx.collect::<Vec<_>>()), vec![1, 2, 3]);
+}
+
+#[test]
+fn infallible_stream_forwarded_into_vec() {
+    let stream = stream::iter(vec![1, 2]).map(Ok::<i32, Never>);
+    let vec = block_on(stream.forward(Vec::new())).unwrap_or_else(Never::into_any);
+    assert_eq!(vec, vec![1, 2]);
+}
+
+#[test]
+fn never_error_composes_with_question_mark() {
+    let result = block_on(async {
+        let x = await!(future::ready(1).never_error())?;
+        let y = await!(future::ready(2).never_error().err_into::<io::Error>())?;
+        Ok::<i32, io::Error>(x + y)
+    });
+    assert_eq!(result.unwrap(), 3);
+}
+
+#[test]
+fn drain_never_fails() {
+    let mut drain = sink::drain();
+    let result: Result<(), Never> = block_on(drain.send(1)).map(|_| ());
+    assert!(result.is_ok());
+}
+
+#[test]
+fn unwrap_infallible_future_and_stream() {
+    assert_eq!(block_on(future::ready(5).never_error().unwrap_infallible()), 5);
+
+    let stream = stream::iter(vec![Ok::<i32, Never>(1), Ok(2)]);
+    assert_eq!(block_on(stream.unwrap_infallible().collect::<Vec<_>>()), vec![1, 2]);
+}
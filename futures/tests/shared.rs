@@ -3,6 +3,8 @@
 use futures::channel::oneshot;
 use futures::executor::{block_on, LocalPool};
 use futures::future::{self, FutureExt, LocalFutureObj};
+use futures::task::Poll;
+use futures_test::task::noop_context;
 use std::cell::RefCell;
 use std::rc::Rc;
 use std::thread;
@@ -40,6 +42,42 @@ fn many_threads() {
     send_shared_oneshot_and_wait_on_multiple_threads(1000);
 }
 
+#[test]
+fn clones_created_and_dropped_while_completing() {
+    for _ in 0..50 {
+        let (tx, rx) = oneshot::channel::<i32>();
+        let f = rx.shared();
+
+        let join_handles = (0..8).map(|i| {
+            let f = f.clone();
+            thread::spawn(move || {
+                if i % 2 == 0 {
+                    // Keep adding and removing waker slots until the output
+                    // is available.
+                    let mut cx = noop_context();
+                    loop {
+                        let mut clone = f.clone();
+                        if let Poll::Ready(output) = clone.poll_unpin(&mut cx) {
+                            assert_eq!(output.unwrap(), 6);
+                            break;
+                        }
+                    }
+                }
+                // Waiting for a wakeup hangs if it got lost in the race with
+                // the completion.
+                assert_eq!(block_on(f).unwrap(), 6);
+            })
+        }).collect::<Vec<_>>();
+
+        tx.send(6).unwrap();
+
+        for join_handle in join_handles {
+            join_handle.join().unwrap();
+        }
+        assert_eq!(block_on(f).unwrap(), 6);
+    }
+}
+
 /* ToDo: This requires FutureExt::select to be implemented
 #[test]
 fn drop_on_one_task_ok() {
@@ -0,0 +1,66 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::future;
+use futures::pin_mut;
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+
+#[test]
+fn one_to_one_combinators_forward_size_hint() {
+    let st = || stream::iter(vec![1, 2, 3]);
+    assert_eq!(st().map(|x| x + 1).size_hint(), (3, Some(3)));
+    assert_eq!(st().inspect(|_| {}).size_hint(), (3, Some(3)));
+    assert_eq!(st().fuse().size_hint(), (3, Some(3)));
+    assert_eq!(st().then(future::ready).size_hint(), (3, Some(3)));
+}
+
+#[test]
+fn fuse_size_hint_after_end() {
+    let mut st = stream::iter(vec![1]).fuse();
+    let cx = &mut noop_context();
+    assert_eq!(st.poll_next_unpin(cx), Poll::Ready(Some(1)));
+    assert_eq!(st.poll_next_unpin(cx), Poll::Ready(None));
+    assert_eq!(st.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn filtering_combinators_lower_bound_is_zero() {
+    let st = || stream::iter(vec![1, 2, 3]);
+    assert_eq!(st().filter(|_| future::ready(true)).size_hint(), (0, Some(3)));
+    assert_eq!(st().filter_map(|x| future::ready(Some(x))).size_hint(), (0, Some(3)));
+    assert_eq!(st().take_while(|_| future::ready(true)).size_hint(), (0, Some(3)));
+    assert_eq!(st().skip_while(|_| future::ready(true)).size_hint(), (0, Some(3)));
+    assert_eq!(st().map_while(Some).size_hint(), (0, Some(3)));
+}
+
+#[test]
+fn take_and_skip_size_hint() {
+    let st = || stream::iter(vec![1, 2, 3]);
+    assert_eq!(st().take(2).size_hint(), (2, Some(2)));
+    assert_eq!(st().take(5).size_hint(), (3, Some(3)));
+    assert_eq!(st().skip(2).size_hint(), (1, Some(1)));
+    assert_eq!(st().skip(5).size_hint(), (0, Some(0)));
+    assert_eq!(stream::repeat(1).take(2).size_hint(), (2, Some(2)));
+}
+
+#[test]
+fn chain_zip_and_peekable_size_hint() {
+    let cx = &mut noop_context();
+    let st = || stream::iter(vec![1, 2, 3]);
+    assert_eq!(st().chain(stream::iter(vec![4])).size_hint(), (4, Some(4)));
+    assert_eq!(st().zip(stream::iter(vec![4])).size_hint(), (1, Some(1)));
+    assert_eq!(st().zip(stream::repeat(4)).size_hint(), (3, Some(3)));
+
+    let peekable = st().peekable();
+    pin_mut!(peekable);
+    assert_eq!(peekable.peek(cx), Poll::Ready(Some(&1)));
+    assert_eq!(peekable.size_hint(), (3, Some(3)));
+}
+
+#[test]
+fn constructors_size_hint() {
+    assert_eq!(stream::empty::<()>().size_hint(), (0, Some(0)));
+    assert_eq!(stream::once(future::ready(1)).size_hint(), (1, Some(1)));
+    assert_eq!(stream::repeat(1).size_hint(), (usize::max_value(), None));
+}
@@ -0,0 +1,45 @@
+#![feature(pin, arbitrary_self_types, futures_api, async_await)]
+
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future::FutureExt;
+use futures::task::{Poll, SpawnExt};
+use futures_test::task::{no_spawn_context, RecordSpawner};
+
+#[test]
+fn spawn_with_handle_through_record_spawner() {
+    let mut recorder = RecordSpawner::new();
+    let (tx, rx) = oneshot::channel::<i32>();
+
+    let mut handle = recorder
+        .spawn_with_handle(async move { await!(rx).unwrap() * 2 })
+        .unwrap();
+    assert_eq!(recorder.spawned().len(), 1);
+
+    // Nothing runs the spawned future yet, so the handle can't complete.
+    assert_eq!(handle.poll_unpin(&mut no_spawn_context()), Poll::Pending);
+
+    tx.send(21).unwrap();
+    for future in recorder.take_spawned() {
+        block_on(future);
+    }
+    assert!(recorder.spawned().is_empty());
+
+    assert_eq!(block_on(handle), 42);
+}
+
+#[test]
+fn spawn_from_context_of_record_spawner() {
+    let mut recorder = RecordSpawner::new();
+
+    let handle = {
+        let cx = &mut recorder.context();
+        cx.spawner().spawn_with_handle(async { 1 }).unwrap()
+    };
+
+    assert_eq!(recorder.spawned().len(), 1);
+    for future in recorder.take_spawned() {
+        block_on(future);
+    }
+    assert_eq!(block_on(handle), 1);
+}
@@ -0,0 +1,77 @@
+#![feature(pin, arbitrary_self_types, futures_api, async_await, await_macro)]
+
+use futures::executor::block_on;
+use futures::pin_mut;
+use futures::stream::{self, Stream, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+use std::boxed::PinBox;
+
+/// A stream which isn't `Unpin`, since the futures of `async` blocks aren't.
+fn countdown(from: u32) -> impl Stream<Item = u32> {
+    stream::unfold(from, |n| async move {
+        if n > 0 {
+            Some((n, n - 1))
+        } else {
+            None
+        }
+    })
+}
+
+#[test]
+fn next_on_stack_pinned_stream() {
+    let stream = countdown(3);
+    pin_mut!(stream);
+
+    assert_eq!(block_on(stream.next()), Some(3));
+    assert_eq!(block_on(stream.next()), Some(2));
+    assert_eq!(block_on(stream.next()), Some(1));
+    assert_eq!(block_on(stream.next()), None);
+}
+
+#[test]
+fn next_on_boxed_stream() {
+    let mut stream = PinBox::new(countdown(2));
+
+    assert_eq!(block_on(stream.next()), Some(2));
+    assert_eq!(block_on(stream.next()), Some(1));
+    assert_eq!(block_on(stream.next()), None);
+}
+
+#[test]
+fn next_in_async_loop() {
+    let output = block_on(async {
+        let stream = countdown(4);
+        pin_mut!(stream);
+
+        let mut items = Vec::new();
+        while let Some(item) = await!(stream.next()) {
+            items.push(item);
+        }
+        items
+    });
+    assert_eq!(output, vec![4, 3, 2, 1]);
+}
+
+#[test]
+fn poll_next_unpin_on_pinned_stream() {
+    let stream = countdown(1);
+    pin_mut!(stream);
+
+    let cx = &mut noop_context();
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(1)));
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(None));
+}
+
+#[test]
+fn into_future_on_pinned_stream() {
+    let stream = countdown(2);
+    pin_mut!(stream);
+
+    // The `PinMut` is moved into the future, and returned along with the
+    // item.
+    let (item, stream) = block_on(stream.into_future());
+    assert_eq!(item, Some(2));
+    let (item, _) = block_on(stream.into_future());
+    assert_eq!(item, Some(1));
+}
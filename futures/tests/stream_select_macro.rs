@@ -0,0 +1,64 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::mpsc;
+use futures::executor::{block_on, block_on_stream};
+use futures::stream::{self, StreamExt};
+use futures::stream_select;
+
+#[derive(Debug, PartialEq)]
+enum Tagged {
+    Number(u32),
+    Name(&'static str),
+}
+
+#[test]
+fn merges_in_order_of_readiness() {
+    let (mut tx, mut rx) = mpsc::channel::<u32>(10);
+    let mut names = stream::iter(vec!["a", "b"]);
+
+    {
+        let merged = stream_select! {
+            Tagged::Number = &mut rx,
+            Tagged::Name = &mut names,
+        };
+        let mut merged = block_on_stream(merged);
+
+        // Nothing was sent yet, so only the names are ready.
+        assert_eq!(merged.next(), Some(Tagged::Name("a")));
+        assert_eq!(merged.next(), Some(Tagged::Name("b")));
+
+        tx.try_send(1).unwrap();
+        tx.try_send(2).unwrap();
+        assert_eq!(merged.next(), Some(Tagged::Number(1)));
+        assert_eq!(merged.next(), Some(Tagged::Number(2)));
+
+        // The merged stream only ends once all of the sources ended.
+        drop(tx);
+        assert_eq!(merged.next(), None);
+        assert_eq!(merged.next(), None);
+    }
+
+    // The sources are still around.
+    assert_eq!(block_on(rx.next()), None);
+    assert_eq!(block_on(names.next()), None);
+}
+
+#[test]
+fn polls_fairly() {
+    let merged = stream_select! {
+        Ok = stream::iter(0..100),
+        Err = stream::iter(0..100),
+    };
+    let items: Vec<Result<i32, i32>> = block_on(merged.collect());
+    assert_eq!(items.len(), 200);
+
+    // Both sources are always ready, each of them wins about half the time.
+    let oks = items[..100].iter().filter(|item| item.is_ok()).count();
+    assert!(oks > 0 && oks < 100, "{} of the first 100 items are from the first source", oks);
+
+    // The order within each source is kept.
+    let oks: Vec<i32> = items.iter().filter_map(|item| item.ok()).collect();
+    let errs: Vec<i32> = items.iter().filter_map(|item| item.err()).collect();
+    assert_eq!(oks, (0..100).collect::<Vec<_>>());
+    assert_eq!(errs, (0..100).collect::<Vec<_>>());
+}
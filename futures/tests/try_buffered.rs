@@ -0,0 +1,67 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::channel::oneshot::{self, Canceled};
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+
+#[test]
+fn try_buffered_keeps_order() {
+    let (tx_a, rx_a) = oneshot::channel::<i32>();
+    let (tx_b, rx_b) = oneshot::channel::<i32>();
+    let (tx_c, rx_c) = oneshot::channel::<i32>();
+
+    let stream = stream::iter(vec![Ok(rx_a), Ok(rx_b), Ok(rx_c)]);
+    let mut buffered = stream.try_buffered(2);
+    let cx = &mut noop_context();
+
+    assert_eq!(buffered.poll_next_unpin(cx), Poll::Pending);
+    tx_b.send(2).unwrap();
+    tx_c.send(3).unwrap();
+    assert_eq!(buffered.poll_next_unpin(cx), Poll::Pending);
+    tx_a.send(1).unwrap();
+
+    let items: Vec<_> = block_on(buffered.collect());
+    assert_eq!(items, vec![Ok(1), Ok(2), Ok(3)]);
+}
+
+#[test]
+fn try_buffered_error_waits_its_turn() {
+    let (tx_a, rx_a) = oneshot::channel::<i32>();
+    let (tx_b, rx_b) = oneshot::channel::<i32>();
+    let (tx_c, rx_c) = oneshot::channel::<i32>();
+
+    let stream = stream::iter(vec![Ok(rx_a), Ok(rx_b), Ok(rx_c)]);
+    let mut buffered = stream.try_buffered(3);
+    let cx = &mut noop_context();
+
+    // The second future fails while the first one is still running
+    drop(tx_b);
+    assert_eq!(buffered.poll_next_unpin(cx), Poll::Pending);
+    assert!(!tx_c.is_canceled());
+
+    tx_a.send(1).unwrap();
+    assert_eq!(buffered.poll_next_unpin(cx), Poll::Ready(Some(Ok(1))));
+    assert_eq!(buffered.poll_next_unpin(cx), Poll::Ready(Some(Err(Canceled))));
+
+    // The error ends the stream and drops the futures still in progress
+    assert!(tx_c.is_canceled());
+    assert_eq!(buffered.poll_next_unpin(cx), Poll::Ready(None));
+}
+
+#[test]
+fn try_buffered_stream_error_waits_its_turn() {
+    let (tx_a, rx_a) = oneshot::channel::<i32>();
+    let (_tx_c, rx_c) = oneshot::channel::<i32>();
+
+    let stream = stream::iter(vec![Ok(rx_a), Err(Canceled), Ok(rx_c)]);
+    let mut buffered = stream.try_buffered(3);
+    let cx = &mut noop_context();
+
+    assert_eq!(buffered.poll_next_unpin(cx), Poll::Pending);
+    tx_a.send(1).unwrap();
+
+    let items: Vec<_> = block_on(buffered.collect());
+    assert_eq!(items, vec![Ok(1), Err(Canceled)]);
+}
@@ -0,0 +1,37 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::future::{self, Future};
+use futures::task::{noop_waker_context, noop_waker_ref, panic_waker_context, Poll};
+use pin_utils::pin_mut;
+use std::thread;
+
+#[test]
+fn noop_waker_ref_is_shared_between_threads() {
+    let waker = noop_waker_ref();
+    let handle = thread::spawn(move || {
+        noop_waker_ref().wake();
+        noop_waker_ref() as *const _ as usize
+    });
+    waker.wake();
+    waker.clone().wake();
+    assert_eq!(handle.join().unwrap(), waker as *const _ as usize);
+}
+
+#[test]
+fn noop_waker_context_polls_futures() {
+    let future = future::empty::<()>();
+    pin_mut!(future);
+    let cx = &mut noop_waker_context();
+    assert_eq!(future.reborrow().poll(cx), Poll::Pending);
+    cx.waker().wake();
+}
+
+#[test]
+#[should_panic(expected = "should not be woken")]
+fn panic_waker_context_panics_when_woken() {
+    let future = future::ready(1);
+    pin_mut!(future);
+    let cx = &mut panic_waker_context();
+    assert_eq!(future.poll(cx), Poll::Ready(1));
+    cx.waker().wake();
+}
@@ -0,0 +1,101 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+use futures::future::{self, Future};
+use futures::pin_mut;
+use futures::stream::{self, Stream, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+use std::boxed::PinBox;
+use std::panic::AssertUnwindSafe;
+
+fn assert_future<F: Future>(_: &F) {}
+fn assert_stream<S: Stream>(_: &S) {}
+
+fn poll_once<F: Future>(future: F) -> Poll<F::Output> {
+    pin_mut!(future);
+    future.poll(&mut noop_context())
+}
+
+fn next_item<S: Stream>(stream: S) -> Poll<Option<S::Item>> {
+    pin_mut!(stream);
+    stream.poll_next(&mut noop_context())
+}
+
+#[test]
+fn future_wrappers_are_futures() {
+    let mut unpin = future::ready(1);
+    assert_future(&&mut unpin);
+    assert_future(&Box::new(future::ready(1)));
+    assert_future(&PinBox::new(future::ready(1)));
+    assert_future(&AssertUnwindSafe(future::ready(1)));
+
+    let pinned = future::lazy(|_| 1);
+    pin_mut!(pinned);
+    assert_future(&pinned);
+    assert_future(&pinned.reborrow());
+}
+
+#[test]
+fn stream_wrappers_are_streams() {
+    let mut unpin = stream::iter(vec![1]);
+    assert_stream(&&mut unpin);
+    assert_stream(&Box::new(stream::iter(vec![1])));
+    assert_stream(&PinBox::new(stream::iter(vec![1])));
+    assert_stream(&AssertUnwindSafe(stream::iter(vec![1])));
+
+    let pinned = stream::iter(vec![1]);
+    pin_mut!(pinned);
+    assert_stream(&pinned);
+    assert_stream(&pinned.reborrow());
+}
+
+#[test]
+fn poll_future_through_two_layers() {
+    let mut boxed = PinBox::new(future::ready(1));
+    assert_eq!(poll_once(&mut boxed), Poll::Ready(1));
+
+    let mut unpin = future::ready(2);
+    assert_eq!(poll_once(Box::new(&mut unpin)), Poll::Ready(2));
+
+    let pinned = future::ready(3);
+    pin_mut!(pinned);
+    assert_eq!(poll_once(AssertUnwindSafe(pinned.reborrow())), Poll::Ready(3));
+}
+
+#[test]
+fn poll_stream_through_two_layers() {
+    let mut boxed = PinBox::new(stream::iter(vec![1, 2]));
+    assert_eq!(next_item(&mut boxed), Poll::Ready(Some(1)));
+    assert_eq!(next_item(&mut boxed), Poll::Ready(Some(2)));
+    assert_eq!(next_item(&mut boxed), Poll::Ready(None));
+
+    let mut unpin = stream::iter(vec![3]);
+    assert_eq!(next_item(Box::new(&mut unpin)), Poll::Ready(Some(3)));
+    assert_eq!(next_item(Box::new(&mut unpin)), Poll::Ready(None));
+
+    let pinned = stream::iter(vec![4]);
+    pin_mut!(pinned);
+    assert_eq!(
+        next_item(AssertUnwindSafe(pinned.reborrow())),
+        Poll::Ready(Some(4)),
+    );
+}
+
+#[test]
+fn size_hint_through_wrappers() {
+    let mut unpin = stream::iter(vec![1, 2, 3]);
+    assert_eq!((&mut unpin).size_hint(), (3, Some(3)));
+    assert_eq!(Box::new(&mut unpin).size_hint(), (3, Some(3)));
+    assert_eq!(AssertUnwindSafe(&mut unpin).size_hint(), (3, Some(3)));
+
+    let mut boxed = PinBox::new(stream::iter(vec![1, 2]));
+    assert_eq!((&mut boxed).size_hint(), (2, Some(2)));
+    assert_eq!(boxed.as_pin_mut().size_hint(), (2, Some(2)));
+
+    assert_eq!(next_item(&mut boxed), Poll::Ready(Some(1)));
+    assert_eq!(boxed.size_hint(), (1, Some(1)));
+
+    // Streams which don't know their length fall back to the default.
+    let unknown = stream::poll_fn(|_| Poll::Ready(None::<()>));
+    assert_eq!(unknown.map(|x| x).size_hint(), (0, None));
+}